@@ -0,0 +1,66 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use refined_type::rule::{AlphaDigitRule, AlphabetRule, DigitRule, EmailStringRule, Regex, Rule};
+
+const EMAIL_PATTERN: &str =
+    r"^[a-zA-Z0-9_.+-]+@([a-zA-Z0-9][a-zA-Z0-9-]*[a-zA-Z0-9]*\.)+[a-zA-Z]{2,}$";
+
+fn emails() -> Vec<String> {
+    (0..1000).map(|i| format!("user{i}@example.com")).collect()
+}
+
+/// Validates in the way `declare_regex_rule!` used to, compiling the pattern on every call
+fn validate_uncached(target: &str) -> bool {
+    Regex::new(EMAIL_PATTERN)
+        .expect("invalid regex pattern")
+        .is_match(target)
+}
+
+fn bench_email(c: &mut Criterion) {
+    let emails = emails();
+    let mut group = c.benchmark_group("email");
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            for email in &emails {
+                black_box(validate_uncached(email));
+            }
+        })
+    });
+    group.bench_function("EmailRule", |b| {
+        b.iter(|| {
+            for email in &emails {
+                black_box(EmailStringRule::validate(email.clone()).is_ok());
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_string_rules(c: &mut Criterion) {
+    let targets = (0..1000).map(|i| format!("abc{i}")).collect::<Vec<_>>();
+    let mut group = c.benchmark_group("string");
+    group.bench_function("AlphabetRule", |b| {
+        b.iter(|| {
+            for target in &targets {
+                black_box(AlphabetRule::<String>::validate(target.clone()).is_ok());
+            }
+        })
+    });
+    group.bench_function("DigitRule", |b| {
+        b.iter(|| {
+            for target in &targets {
+                black_box(DigitRule::<String>::validate(target.clone()).is_ok());
+            }
+        })
+    });
+    group.bench_function("AlphaDigitRule", |b| {
+        b.iter(|| {
+            for target in &targets {
+                black_box(AlphaDigitRule::<String>::validate(target.clone()).is_ok());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_email, bench_string_rules);
+criterion_main!(benches);
@@ -0,0 +1,432 @@
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Expr, Fields, Ident, Lit, Member,
+    Token, Type, UnOp,
+};
+
+/// Derives a `validate` constructor for a struct whose fields are `Refined`
+///
+/// The constructor takes the raw value of every field, validates each of them with its `Rule` and then runs the
+/// struct-level rules declared by `#[refined(rule = ...)]`. Every failing field is reported in the `ErrorTree`
+/// with its name as the location.
+///
+/// Attributes:
+/// - `#[refined(rule = RULE)]` on the struct: a `Rule<Item = Self>` checked after every field has been validated
+/// - `#[refined(deserialize)]` on the struct: implements `Deserialize` that enforces the struct-level rules too
+///
+/// `#[derive(Deserialize)]` would validate each field but skip the struct-level rules,
+/// so a struct with `#[refined(rule = ...)]` that implements `Deserialize` without `#[refined(deserialize)]`
+/// fails to compile. Use `#[refined(deserialize)]` instead of deriving `Deserialize`.
+/// - `#[refined(skip)]` on a field: the field is not `Refined` and is taken as it is
+#[proc_macro_derive(Refined, attributes(refined, serde))]
+pub fn derive_refined(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Creates `Refined` from a literal, rejecting a literal that does not satisfy the rule at compile time
+///
+/// `refined!(TYPE, LITERAL)` accepts an integer or a string literal. The rule of `TYPE` must implement `ConstRule`.
+#[proc_macro]
+pub fn refined(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as RefinedLiteral);
+    expand_refined(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct RefinedLiteral {
+    ty: Type,
+    literal: Expr,
+}
+
+impl Parse for RefinedLiteral {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let literal = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { ty, literal })
+    }
+}
+
+fn expand_refined(input: RefinedLiteral) -> syn::Result<TokenStream2> {
+    let RefinedLiteral { ty, literal } = input;
+    let (negative, lit) = match &literal {
+        Expr::Lit(expr) => (false, &expr.lit),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => match &*unary.expr {
+            Expr::Lit(expr) if matches!(expr.lit, Lit::Int(_)) => (true, &expr.lit),
+            _ => return Err(unsupported_literal(&literal)),
+        },
+        _ => return Err(unsupported_literal(&literal)),
+    };
+    let (marker, value) = match lit {
+        Lit::Int(int) => {
+            let abs = int.base10_parse::<u128>()?;
+            let negative = negative && abs != 0;
+            (
+                quote!(::refined_type::rule::IntLiteral<#negative, #abs>),
+                quote!(#literal),
+            )
+        }
+        Lit::Str(str) => {
+            let chars = str.value().chars().count();
+            (
+                quote!(::refined_type::rule::StrLiteral<#chars>),
+                quote!(::core::convert::From::from(#literal)),
+            )
+        }
+        _ => return Err(unsupported_literal(&literal)),
+    };
+    let message = format!(
+        "`{}` does not satisfy the rule of `{}`",
+        if negative {
+            format!("-{}", quote!(#lit))
+        } else {
+            quote!(#lit).to_string()
+        },
+        quote!(#ty)
+            .to_string()
+            .replace(" <", "<")
+            .replace("< ", "<")
+            .replace(" >", ">")
+            .replace(" ,", ",")
+    );
+    Ok(quote! {
+        {
+            const _: () = ::core::assert!(
+                <#ty as ::refined_type::__private::ConstRefined<#marker>>::VALID,
+                #message
+            );
+            <#ty as ::refined_type::__private::ConstRefined<#marker>>::from_literal(#value)
+        }
+    })
+}
+
+fn unsupported_literal(literal: &Expr) -> Error {
+    Error::new_spanned(
+        literal,
+        "`refined!` only accepts an integer or a string literal",
+    )
+}
+
+struct ContainerOptions {
+    rules: Vec<Type>,
+    deserialize: bool,
+}
+
+struct FieldInfo {
+    member: Member,
+    binding: Ident,
+    ty: Type,
+    skip: bool,
+    serde_attrs: Vec<Attribute>,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`#[derive(Refined)]` does not support generic structs",
+        ));
+    }
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`#[derive(Refined)]` can only be applied to structs",
+            ))
+        }
+    };
+    let named = match &data.fields {
+        Fields::Named(_) => true,
+        Fields::Unnamed(_) => false,
+        Fields::Unit => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`#[derive(Refined)]` requires a struct with fields",
+            ))
+        }
+    };
+
+    let options = container_options(&input.attrs)?;
+    let fields = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let (member, binding) = match &field.ident {
+                Some(ident) => (Member::Named(ident.clone()), ident.clone()),
+                None => (
+                    Member::Unnamed(index.into()),
+                    format_ident!("field_{}", index),
+                ),
+            };
+            Ok(FieldInfo {
+                member,
+                binding,
+                ty: field.ty.clone(),
+                skip: field_skipped(&field.attrs)?,
+                serde_attrs: serde_attrs(&field.attrs),
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let validate = expand_validate(&input.ident, named, &fields, &options.rules);
+    let deserialize = if options.deserialize {
+        expand_deserialize(&input, named, &fields)
+    } else if options.rules.is_empty() {
+        quote!()
+    } else {
+        expand_refuse_deserialize(&input.ident)
+    };
+    Ok(quote! {
+        #validate
+        #deserialize
+    })
+}
+
+fn container_options(attrs: &[Attribute]) -> syn::Result<ContainerOptions> {
+    let mut options = ContainerOptions {
+        rules: Vec::new(),
+        deserialize: false,
+    };
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("refined")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rule") {
+                options.rules.push(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("deserialize") {
+                options.deserialize = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `rule = ...` or `deserialize`"))
+            }
+        })?;
+    }
+    Ok(options)
+}
+
+fn field_skipped(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut skip = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("refined")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip`"))
+            }
+        })?;
+    }
+    Ok(skip)
+}
+
+fn serde_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .cloned()
+        .collect()
+}
+
+fn expand_validate(
+    name: &Ident,
+    named: bool,
+    fields: &[FieldInfo],
+    rules: &[Type],
+) -> TokenStream2 {
+    let name_str = name.to_string();
+    let bindings = fields
+        .iter()
+        .map(|field| &field.binding)
+        .collect::<Vec<_>>();
+    let item_types = fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+            if field.skip {
+                quote!(#ty)
+            } else {
+                quote!(<#ty as ::refined_type::__private::Field>::Item)
+            }
+        })
+        .collect::<Vec<_>>();
+    let refines = fields.iter().map(|field| {
+        let ty = &field.ty;
+        let binding = &field.binding;
+        if field.skip {
+            quote!(::core::result::Result::<#ty, ::refined_type::result::Error<#ty>>::Ok(#binding))
+        } else {
+            quote!(<#ty as ::refined_type::__private::Field>::refine(#binding))
+        }
+    });
+    let into_items = fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+            let binding = &field.binding;
+            if field.skip {
+                quote!(#binding)
+            } else {
+                quote!(<#ty as ::refined_type::__private::Field>::into_item(#binding))
+            }
+        })
+        .collect::<Vec<_>>();
+    let locations = fields.iter().map(|field| match &field.member {
+        Member::Named(ident) => {
+            let key = ident.to_string();
+            quote!(::refined_type::result::Location::Key(#key.to_string()))
+        }
+        Member::Unnamed(index) => {
+            let index = index.index as usize;
+            quote!(::refined_type::result::Location::Index(#index))
+        }
+    });
+    let construct = if named {
+        quote!(Self { #(#bindings),* })
+    } else {
+        quote!(Self(#(#bindings),*))
+    };
+    let validate_rules_body = if rules.is_empty() {
+        quote!(::core::result::Result::Ok(value))
+    } else {
+        quote! {
+            let mut __children = ::std::vec::Vec::new();
+            #(
+                let value = match <#rules as ::refined_type::rule::Rule>::validate(value) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(__error) => {
+                        let (value, __tree) = __error.into_parts();
+                        __children.push(__tree);
+                        value
+                    }
+                };
+            )*
+            if __children.is_empty() {
+                ::core::result::Result::Ok(value)
+            } else {
+                let __tree = ::refined_type::__private::struct_error_tree(#name_str, __children);
+                ::core::result::Result::Err(::refined_type::result::Error::from_tree(value, __tree))
+            }
+        }
+    };
+
+    quote! {
+        impl #name {
+            /// Creates a new instance if every field satisfies its rule and the struct satisfies the struct-level rules
+            #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+            pub fn validate(
+                #(#bindings: #item_types),*
+            ) -> ::core::result::Result<Self, ::refined_type::result::Error<(#(#item_types,)*)>> {
+                #(let #bindings = #refines;)*
+                match (#(#bindings,)*) {
+                    (#(::core::result::Result::Ok(#bindings),)*) => {
+                        Self::validate_rules(#construct).map_err(|__error| {
+                            let (__value, __tree) = __error.into_parts();
+                            let #construct = __value;
+                            ::refined_type::result::Error::from_tree((#(#into_items,)*), __tree)
+                        })
+                    }
+                    (#(#bindings,)*) => {
+                        let mut __children = ::std::vec::Vec::new();
+                        #(
+                            let #bindings = match #bindings {
+                                ::core::result::Result::Ok(#bindings) => #into_items,
+                                ::core::result::Result::Err(__error) => {
+                                    let (__value, __tree) = __error.into_parts();
+                                    __children.push(__tree.with_location(#locations));
+                                    __value
+                                }
+                            };
+                        )*
+                        let __tree = ::refined_type::__private::struct_error_tree(#name_str, __children);
+                        ::core::result::Result::Err(::refined_type::result::Error::from_tree((#(#bindings,)*), __tree))
+                    }
+                }
+            }
+
+            /// Checks the struct-level rules declared by `#[refined(rule = ...)]`
+            pub fn validate_rules(value: Self) -> ::refined_type::Result<Self> {
+                #validate_rules_body
+            }
+        }
+    }
+}
+
+fn expand_deserialize(input: &DeriveInput, named: bool, fields: &[FieldInfo]) -> TokenStream2 {
+    let name = &input.ident;
+    let name_str = name.to_string();
+    let fields_name = format_ident!("__Refined{}", name);
+    let container_attrs = serde_attrs(&input.attrs);
+    let rename = if container_attrs.iter().any(renames) {
+        quote!()
+    } else {
+        quote!(#[serde(rename = #name_str)])
+    };
+    let members = fields.iter().map(|field| &field.member).collect::<Vec<_>>();
+    let field_defs = fields.iter().map(|field| {
+        let attrs = &field.serde_attrs;
+        let ty = &field.ty;
+        match &field.member {
+            Member::Named(ident) => quote!(#(#attrs)* #ident: #ty),
+            Member::Unnamed(_) => quote!(#(#attrs)* #ty),
+        }
+    });
+    let definition = if named {
+        quote!(struct #fields_name { #(#field_defs),* })
+    } else {
+        quote!(struct #fields_name(#(#field_defs),*);)
+    };
+    let construct = if named {
+        quote!(#name { #(#members: __fields.#members),* })
+    } else {
+        quote!(#name(#(__fields.#members),*))
+    };
+
+    quote! {
+        const _: () = {
+            #[derive(::refined_type::__private::serde::Deserialize)]
+            #[serde(crate = "::refined_type::__private::serde")]
+            #rename
+            #(#container_attrs)*
+            #definition
+
+            impl<'de> ::refined_type::__private::serde::Deserialize<'de> for #name {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: ::refined_type::__private::serde::Deserializer<'de>,
+                {
+                    use ::refined_type::__private::serde::de::Error;
+                    let __fields = <#fields_name as ::refined_type::__private::serde::Deserialize>::deserialize(deserializer)?;
+                    #name::validate_rules(#construct).map_err(|e| Error::custom(e.to_string()))
+                }
+            }
+        };
+    }
+}
+
+/// Fails the compilation if the struct implements `Deserialize` elsewhere, skipping its struct-level rules
+fn expand_refuse_deserialize(name: &Ident) -> TokenStream2 {
+    quote! {
+        const _: () = {
+            let _ = <#name as ::refined_type::__private::RefuseDeserialize<_>>::refuse;
+        };
+    }
+}
+
+fn renames(attr: &Attribute) -> bool {
+    let Ok(list) = attr.meta.require_list() else {
+        return false;
+    };
+    list.tokens
+        .clone()
+        .into_iter()
+        .any(|token| matches!(token, TokenTree::Ident(ident) if ident == "rename"))
+}
@@ -0,0 +1,76 @@
+use crate::result::{Error, ErrorTree};
+use crate::rule::Rule;
+use crate::Refined;
+
+/// A field of a struct deriving `Refined`, i.e. a value that is built from a raw item by validating it
+pub trait Field: Sized {
+    type Item;
+
+    fn refine(item: Self::Item) -> Result<Self, Error<Self::Item>>;
+
+    fn into_item(self) -> Self::Item;
+}
+
+impl<RULE, T> Field for Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+{
+    type Item = T;
+
+    fn refine(item: Self::Item) -> Result<Self, Error<Self::Item>> {
+        Refined::new(item)
+    }
+
+    fn into_item(self) -> Self::Item {
+        self.into_value()
+    }
+}
+
+/// Builds the error of a struct deriving `Refined` from the errors of its fields or of its struct-level rules
+pub fn struct_error_tree(name: &str, children: Vec<ErrorTree>) -> ErrorTree {
+    let message = children
+        .iter()
+        .map(|child| match child.location() {
+            Some(location) => format!("{location}: {child}"),
+            None => child.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    ErrorTree::new(message)
+        .with_rule(name)
+        .with_children(children)
+}
+
+/// Fails the compilation of a struct deriving `Refined` with struct-level rules that implements `Deserialize`
+/// without `#[refined(deserialize)]`, since such an implementation skips the struct-level rules
+///
+/// `RefuseDeserialize<_>` is ambiguous for a type implementing `Deserialize`, so the derived code
+/// naming `refuse` does not compile for it.
+/// ```compile_fail
+/// use refined_type::rule::{NonEmptyString, Rule};
+/// use refined_type::Refined;
+/// use serde::Deserialize;
+///
+/// #[derive(Refined, Deserialize)]
+/// #[refined(rule = Anything)]
+/// struct Name {
+///     first: NonEmptyString,
+/// }
+///
+/// struct Anything;
+///
+/// impl Rule for Anything {
+///     type Item = Name;
+///
+///     fn validate(target: Self::Item) -> refined_type::Result<Self::Item> {
+///         Ok(target)
+///     }
+/// }
+/// ```
+pub trait RefuseDeserialize<A> {
+    fn refuse() {}
+}
+
+impl<T> RefuseDeserialize<()> for T {}
+
+impl<T: for<'de> serde::Deserialize<'de>> RefuseDeserialize<u8> for T {}
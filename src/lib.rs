@@ -1,9 +1,27 @@
 pub use paste;
 
-pub use refined::Refined;
+pub use refined::{Integer, Refined, RefinedRef, RefinedSlice, RefinedStr};
+pub use refined_type_derive::{refined, Refined};
 
+mod derive;
 mod refined;
 pub mod result;
 pub mod rule;
+pub mod stream;
+#[cfg(feature = "proptest")]
+pub mod testing;
 
 pub use result::Result;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::derive::{struct_error_tree, Field, RefuseDeserialize};
+    pub use crate::rule::const_rule::ConstRefined;
+    pub use crate::rule::define::{
+        evaluate_predicate, uses_value, DebugOf, NoDebug, PredicateOutcome,
+    };
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+    pub use serde;
+    pub use serde_json;
+}
@@ -1,8 +1,26 @@
+mod arithmetic;
+#[cfg(feature = "schemars")]
+mod json_schema;
+mod refined_ref;
+mod refined_slice;
+mod refined_str;
+#[cfg(feature = "proptest")]
+mod strategy;
+
+pub use arithmetic::Integer;
+pub use refined_ref::RefinedRef;
+pub use refined_slice::RefinedSlice;
+pub use refined_str::RefinedStr;
+
 use crate::result::Error;
-use crate::rule::Rule;
+use crate::rule::{Rule, RuleDescription};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
 
 /// Refined is a versatile type in ensuring that `T` satisfies the conditions of `RULE` (predicate type)
 /// # Example
@@ -16,7 +34,9 @@ use std::fmt::{Debug, Display, Formatter};
 /// let empty_string_result = Refined::<NonEmptyStringRule>::new("".to_string());
 /// assert!(empty_string_result.is_err())
 /// ```
-#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
+///
+/// The standard traits such as `Clone`, `Hash` and `Ord` are implemented whenever `RULE::Item` implements them,
+/// and `Refined` derefs to `RULE::Item`, so it can be used wherever a reference to the value is expected.
 pub struct Refined<RULE>
 where
     RULE: Rule,
@@ -80,10 +100,7 @@ where
     /// assert!(empty_string.is_err());
     /// ```
     pub fn new(value: T) -> Result<Self, Error<T>> {
-        let value = RULE::validate(value).map_err(|e| {
-            let message = e.to_string();
-            Error::new(e.into_value(), message)
-        })?;
+        let value = RULE::validate(value)?;
         Ok(Self { value })
     }
 
@@ -201,6 +218,48 @@ where
     }
 }
 
+impl<RULE> Refined<RULE>
+where
+    RULE: RuleDescription,
+{
+    /// Returns a human-readable description of the conditions that the value satisfies
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use refined_type::rule::NonEmptyString;
+    ///
+    /// assert_eq!(NonEmptyString::description(), "not empty");
+    /// ```
+    pub fn description() -> String {
+        RULE::description()
+    }
+
+    /// Returns the JSON Schema of the serialized value, annotated with the `description` of the rule
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use refined_type::rule::NonEmptyString;
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(
+    ///     NonEmptyString::json_schema(),
+    ///     json!({ "type": "string", "minLength": 1, "description": "not empty" })
+    /// );
+    /// ```
+    pub fn json_schema() -> serde_json::Value {
+        let mut schema = RULE::json_schema();
+        if let serde_json::Value::Object(map) = &mut schema {
+            map.insert(
+                "description".to_string(),
+                serde_json::Value::String(RULE::description()),
+            );
+        }
+        schema
+    }
+}
+
 macro_rules! impl_try_from {
     ($t: ty) => {
         impl<RULE: Rule<Item = $t>> TryFrom<$t> for Refined<RULE> {
@@ -269,6 +328,143 @@ where
     }
 }
 
+impl<RULE, T> Debug for Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Refined")
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<RULE, T> Clone for Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<RULE, T> Copy for Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+    T: Copy,
+{
+}
+
+impl<RULE, T> PartialEq for Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<RULE, T> Eq for Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+    T: Eq,
+{
+}
+
+impl<RULE, T> PartialOrd for Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<RULE, T> Ord for Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+/// Hashes the same as `RULE::Item`, so that the `Borrow` implementations can be used to look up a hash map
+impl<RULE, T> Hash for Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<RULE, T> Deref for Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<RULE, U> AsRef<U> for Refined<RULE>
+where
+    RULE: Rule,
+    RULE::Item: AsRef<U>,
+    U: ?Sized,
+{
+    fn as_ref(&self) -> &U {
+        self.value.as_ref()
+    }
+}
+
+impl<RULE> Borrow<str> for Refined<RULE>
+where
+    RULE: Rule<Item = String>,
+{
+    fn borrow(&self) -> &str {
+        &self.value
+    }
+}
+
+impl<RULE> Borrow<String> for Refined<RULE>
+where
+    RULE: Rule<Item = String>,
+{
+    fn borrow(&self) -> &String {
+        &self.value
+    }
+}
+
+impl<RULE, T> Borrow<[T]> for Refined<RULE>
+where
+    RULE: Rule<Item = Vec<T>>,
+{
+    fn borrow(&self) -> &[T] {
+        &self.value
+    }
+}
+
+impl<RULE, T> Borrow<Vec<T>> for Refined<RULE>
+where
+    RULE: Rule<Item = Vec<T>>,
+{
+    fn borrow(&self) -> &Vec<T> {
+        &self.value
+    }
+}
+
 #[cfg(test)]
 mod test {
     use serde::{Deserialize, Serialize};
@@ -280,7 +476,7 @@ mod test {
     use crate::rule::{
         EqualI128, EqualI16, EqualI32, EqualI64, EqualI8, EqualIsize, EqualU128, EqualU16,
         EqualU32, EqualU64, EqualU8, EqualUsize, NonEmptyHashMap, NonEmptyHashSet, NonEmptyString,
-        NonEmptyStringRule, NonEmptyVec, NonEmptyVecDeque,
+        NonEmptyStringRule, NonEmptyVec, NonEmptyVecDeque, RangeU8, Rule,
     };
 
     #[test]
@@ -517,6 +713,26 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_new_err_tree_json() {
+        let err = RangeU8::<1, 10>::new(10).unwrap_err();
+
+        let actual = json!(err.tree());
+        let expected = json! {{
+            "rule": "And",
+            "actual": "10",
+            "message": "the value must be less than 10, but received 10",
+            "children": [{
+                "rule": "LessRuleU8",
+                "expected": "less than 10",
+                "actual": "10",
+                "message": "the value must be less than 10, but received 10"
+            }]
+        }};
+        assert_eq!(actual, expected);
+        assert_eq!(err.into_value(), 10);
+    }
+
     #[test]
     fn test_mutate() -> anyhow::Result<()> {
         let value = NonEmptyString::try_from("h")?
@@ -527,4 +743,102 @@ mod test {
         assert_eq!(value.into_value(), "hello");
         Ok(())
     }
+
+    #[test]
+    fn test_refined_hash_map_key_lookup_by_str() -> anyhow::Result<()> {
+        let mut scores = HashMap::new();
+        scores.insert(NonEmptyString::new("john".to_string())?, 1);
+        scores.insert(NonEmptyString::new("taro".to_string())?, 2);
+
+        assert_eq!(scores.get("john"), Some(&1));
+        let key = String::from("taro");
+        assert_eq!(scores.get(&key), Some(&2));
+        assert_eq!(scores.get("hanako"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_refined_hash_set_of_vec_lookup_by_slice() -> anyhow::Result<()> {
+        let mut set = HashSet::new();
+        set.insert(NonEmptyVec::new(vec![1, 2])?);
+        assert!(set.contains([1, 2].as_slice()));
+        assert!(!set.contains([2, 1].as_slice()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_refined_clone_copy() -> anyhow::Result<()> {
+        let name = NonEmptyString::new("john".to_string())?;
+        let cloned = name.clone();
+        assert_eq!(name, cloned);
+
+        let level = RangeU8::<1, 10>::new(5)?;
+        let copied = level;
+        assert_eq!(level.into_value(), copied.into_value());
+        Ok(())
+    }
+
+    #[test]
+    fn test_refined_deref_as_ref() -> anyhow::Result<()> {
+        fn takes_str(value: &str) -> usize {
+            value.len()
+        }
+        fn takes_as_ref(value: impl AsRef<[u8]>) -> usize {
+            value.as_ref().len()
+        }
+
+        let name = NonEmptyString::new("john".to_string())?;
+        assert_eq!(takes_str(&name), 4);
+        assert_eq!(takes_as_ref(&name), 4);
+        assert!(name.starts_with("jo"));
+
+        let level = RangeU8::<1, 10>::new(5)?;
+        assert_eq!(*level + 1, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_refined_traits_do_not_require_rule_traits() -> anyhow::Result<()> {
+        struct ShortRule;
+
+        impl Rule for ShortRule {
+            type Item = String;
+
+            fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+                if target.len() < 5 {
+                    Ok(target)
+                } else {
+                    Err(Error::new(target, "too long"))
+                }
+            }
+        }
+
+        let short = Refined::<ShortRule>::new("abc".to_string())?;
+        assert_eq!(short.clone(), short);
+        assert!(short < Refined::<ShortRule>::new("abd".to_string())?);
+        assert_eq!(format!("{short:?}"), "Refined { value: \"abc\" }");
+        Ok(())
+    }
+
+    #[test]
+    fn test_refined_json_schema_of_fields() {
+        let properties = json!({
+            "name": NonEmptyString::json_schema(),
+            "friends": NonEmptyVec::<String>::json_schema(),
+            "age": RangeU8::<18, 100>::json_schema(),
+        });
+        assert_eq!(
+            properties,
+            json!({
+                "name": { "type": "string", "minLength": 1, "description": "not empty" },
+                "friends": { "type": "array", "minItems": 1, "description": "not empty" },
+                "age": {
+                    "type": "integer",
+                    "minimum": 18,
+                    "exclusiveMaximum": 100,
+                    "description": "(equal to 18 or greater than 18) and less than 100"
+                }
+            })
+        );
+    }
 }
@@ -0,0 +1,270 @@
+use crate::result::{Error, ErrorTree};
+use crate::rule::Rule;
+use crate::Refined;
+use std::fmt::Display;
+
+/// An integer type supporting the checked and saturating arithmetic of `Refined`
+pub trait Integer: Copy + Display {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($t: ty) => {
+        impl Integer for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$t>::saturating_add(self, rhs)
+            }
+
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$t>::saturating_sub(self, rhs)
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$t>::saturating_mul(self, rhs)
+            }
+        }
+    };
+    ($($ts: ty), +) => {
+        $(impl_integer!($ts);)+
+    };
+}
+
+impl_integer![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+impl<RULE, T> Refined<RULE>
+where
+    RULE: Rule<Item = T>,
+    T: Integer,
+{
+    /// Adds `rhs` and validates the sum.
+    ///
+    /// On overflow, the error holds the original value. Otherwise, the error of the rule holds the sum.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use refined_type::rule::RangeU8;
+    ///
+    /// let quota = RangeU8::<0, 100>::new(90).unwrap();
+    /// assert_eq!(quota.checked_add(5).unwrap().into_value(), 95);
+    ///
+    /// let quota = RangeU8::<0, 100>::new(90).unwrap();
+    /// assert_eq!(quota.checked_add(10).unwrap_err().into_value(), 100);
+    ///
+    /// let quota = RangeU8::<0, 100>::new(90).unwrap();
+    /// assert_eq!(quota.checked_add(200).unwrap_err().into_value(), 90);
+    /// ```
+    pub fn checked_add(self, rhs: T) -> Result<Self, Error<T>> {
+        self.checked(rhs, T::checked_add, "add")
+    }
+
+    /// Subtracts `rhs` and validates the difference.
+    ///
+    /// On overflow, the error holds the original value. Otherwise, the error of the rule holds the difference.
+    pub fn checked_sub(self, rhs: T) -> Result<Self, Error<T>> {
+        self.checked(rhs, T::checked_sub, "subtract")
+    }
+
+    /// Multiplies by `rhs` and validates the product.
+    ///
+    /// On overflow, the error holds the original value. Otherwise, the error of the rule holds the product.
+    pub fn checked_mul(self, rhs: T) -> Result<Self, Error<T>> {
+        self.checked(rhs, T::checked_mul, "multiply")
+    }
+
+    /// Adds `rhs`, saturating at the numeric bounds, and validates the sum.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use refined_type::rule::GreaterU8;
+    ///
+    /// let count = GreaterU8::<0>::new(250).unwrap();
+    /// assert_eq!(count.saturating_add(10).unwrap().into_value(), 255);
+    /// ```
+    pub fn saturating_add(self, rhs: T) -> Result<Self, Error<T>> {
+        Refined::new(self.into_value().saturating_add(rhs))
+    }
+
+    /// Subtracts `rhs`, saturating at the numeric bounds, and validates the difference.
+    pub fn saturating_sub(self, rhs: T) -> Result<Self, Error<T>> {
+        Refined::new(self.into_value().saturating_sub(rhs))
+    }
+
+    /// Multiplies by `rhs`, saturating at the numeric bounds, and validates the product.
+    pub fn saturating_mul(self, rhs: T) -> Result<Self, Error<T>> {
+        Refined::new(self.into_value().saturating_mul(rhs))
+    }
+
+    fn checked<F>(self, rhs: T, op: F, name: &str) -> Result<Self, Error<T>>
+    where
+        F: FnOnce(T, T) -> Option<T>,
+    {
+        let value = self.into_value();
+        match op(value, rhs) {
+            Some(result) => Refined::new(result),
+            None => {
+                let tree =
+                    ErrorTree::new(format!("attempt to {name} {value} and {rhs} with overflow"))
+                        .with_actual(value.to_string());
+                Err(Error::from_tree(value, tree))
+            }
+        }
+    }
+}
+
+/// Implements an operator whose result provably satisfies the rule of `$output`
+///
+/// The operator always panics on overflow, even in a release build where the operator of `$t` wraps around.
+macro_rules! impl_refined_op {
+    ($op: ident, $method: ident, $checked: ident, $lhs: ty, $rhs: ty, $output: ty, $t: ty) => {
+        impl std::ops::$op<$rhs> for $lhs {
+            type Output = $output;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                let value = <$t>::$checked(self.into_value(), rhs.into_value()).expect(concat!(
+                    "attempt to ",
+                    stringify!($method),
+                    " with overflow"
+                ));
+                Refined::new_unchecked(value)
+            }
+        }
+    };
+}
+
+macro_rules! impl_parity_ops {
+    ($t: ty) => {
+        $crate::paste::item! {
+            // even ± even, odd ± odd, even * even and even * odd are even
+            impl_refined_op!(Add, add, checked_add, crate::rule::[<Even $t:upper>], crate::rule::[<Even $t:upper>], crate::rule::[<Even $t:upper>], $t);
+            impl_refined_op!(Sub, sub, checked_sub, crate::rule::[<Even $t:upper>], crate::rule::[<Even $t:upper>], crate::rule::[<Even $t:upper>], $t);
+            impl_refined_op!(Mul, mul, checked_mul, crate::rule::[<Even $t:upper>], crate::rule::[<Even $t:upper>], crate::rule::[<Even $t:upper>], $t);
+            impl_refined_op!(Add, add, checked_add, crate::rule::[<Odd $t:upper>], crate::rule::[<Odd $t:upper>], crate::rule::[<Even $t:upper>], $t);
+            impl_refined_op!(Sub, sub, checked_sub, crate::rule::[<Odd $t:upper>], crate::rule::[<Odd $t:upper>], crate::rule::[<Even $t:upper>], $t);
+            impl_refined_op!(Mul, mul, checked_mul, crate::rule::[<Even $t:upper>], crate::rule::[<Odd $t:upper>], crate::rule::[<Even $t:upper>], $t);
+            impl_refined_op!(Mul, mul, checked_mul, crate::rule::[<Odd $t:upper>], crate::rule::[<Even $t:upper>], crate::rule::[<Even $t:upper>], $t);
+
+            // even ± odd and odd ± even are odd, odd * odd is odd
+            impl_refined_op!(Add, add, checked_add, crate::rule::[<Even $t:upper>], crate::rule::[<Odd $t:upper>], crate::rule::[<Odd $t:upper>], $t);
+            impl_refined_op!(Sub, sub, checked_sub, crate::rule::[<Even $t:upper>], crate::rule::[<Odd $t:upper>], crate::rule::[<Odd $t:upper>], $t);
+            impl_refined_op!(Add, add, checked_add, crate::rule::[<Odd $t:upper>], crate::rule::[<Even $t:upper>], crate::rule::[<Odd $t:upper>], $t);
+            impl_refined_op!(Sub, sub, checked_sub, crate::rule::[<Odd $t:upper>], crate::rule::[<Even $t:upper>], crate::rule::[<Odd $t:upper>], $t);
+            impl_refined_op!(Mul, mul, checked_mul, crate::rule::[<Odd $t:upper>], crate::rule::[<Odd $t:upper>], crate::rule::[<Odd $t:upper>], $t);
+        }
+    };
+    ($($ts: ty), +) => {
+        $(impl_parity_ops!($ts);)+
+    };
+}
+
+impl_parity_ops![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{
+        EvenI32, EvenU8, GreaterEqualI8, GreaterI64, GreaterU8, MinMaxU8, OddI32, RangeI32,
+    };
+
+    #[test]
+    fn test_checked_add() {
+        let value = RangeI32::<-10, 10>::new(5).unwrap();
+        assert_eq!(value.checked_add(-10).unwrap().into_value(), -5);
+
+        let value = RangeI32::<-10, 10>::new(5).unwrap();
+        let error = value.checked_add(5).unwrap_err();
+        assert_eq!(error.tree().children()[0].rule(), Some("LessRuleI32"));
+        assert_eq!(error.into_value(), 10);
+    }
+
+    #[test]
+    fn test_checked_overflow() {
+        let value = MinMaxU8::<0, 255>::new(200).unwrap();
+        let error = value.checked_mul(2).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "attempt to multiply 200 and 2 with overflow"
+        );
+        assert_eq!(error.into_value(), 200);
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        let value = GreaterEqualI8::<0>::new(3).unwrap();
+        assert_eq!(value.checked_sub(3).unwrap().into_value(), 0);
+
+        let value = GreaterEqualI8::<0>::new(3).unwrap();
+        assert_eq!(value.checked_sub(4).unwrap_err().into_value(), -1);
+    }
+
+    #[test]
+    fn test_saturating() {
+        let value = MinMaxU8::<0, 255>::new(200).unwrap();
+        assert_eq!(value.saturating_mul(2).unwrap().into_value(), 255);
+
+        let value = MinMaxU8::<1, 255>::new(1).unwrap();
+        assert_eq!(value.saturating_sub(2).unwrap_err().into_value(), 0);
+    }
+
+    #[test]
+    fn test_positive_checked() {
+        let sum = GreaterI64::<0>::new(2).unwrap().checked_add(3).unwrap();
+        assert_eq!(sum.into_value(), 5);
+
+        let error = GreaterU8::<0>::new(128)
+            .unwrap()
+            .checked_add(128)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "attempt to add 128 and 128 with overflow"
+        );
+
+        let sum = GreaterU8::<0>::new(128)
+            .unwrap()
+            .saturating_add(128)
+            .unwrap();
+        assert_eq!(sum.into_value(), 255);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to mul with overflow")]
+    fn test_parity_mul_overflow() {
+        let _ = EvenU8::new(128).unwrap() * EvenU8::new(2).unwrap();
+    }
+
+    #[test]
+    fn test_parity_ops() {
+        let even: EvenI32 = EvenI32::new(2).unwrap() - EvenI32::new(6).unwrap();
+        assert_eq!(even.into_value(), -4);
+
+        let even: EvenI32 = OddI32::new(-3).unwrap() + OddI32::new(5).unwrap();
+        assert_eq!(even.into_value(), 2);
+
+        let odd: OddI32 = EvenI32::new(4).unwrap() - OddI32::new(7).unwrap();
+        assert_eq!(odd.into_value(), -3);
+
+        let odd: OddI32 = OddI32::new(-3).unwrap() * OddI32::new(5).unwrap();
+        assert_eq!(odd.into_value(), -15);
+
+        let even = EvenU8::new(4).unwrap() * EvenU8::new(6).unwrap();
+        assert_eq!(even.into_value(), 24);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::rule::{description, RuleDescription};
+use crate::Refined;
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// The schema of `Refined<RULE>` is the schema of `RULE::Item` narrowed by `RuleDescription::json_schema`
+/// # Example
+/// ```rust
+/// use refined_type::rule::MinMaxU8;
+/// use schemars::schema_for;
+/// use serde_json::json;
+///
+/// let schema = schema_for!(MinMaxU8<18, 80>);
+/// assert_eq!(schema.get("minimum"), Some(&json!(18)));
+/// assert_eq!(schema.get("maximum"), Some(&json!(80)));
+/// ```
+impl<RULE> JsonSchema for Refined<RULE>
+where
+    RULE: RuleDescription,
+    RULE::Item: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Refined_{}", RULE::Item::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        std::any::type_name::<Self>().into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let item = generator.subschema_for::<RULE::Item>().to_value();
+        match description::all_of(item, Refined::<RULE>::json_schema()) {
+            Value::Object(map) => Schema::from(map),
+            _ => unreachable!("`all_of` always returns an object"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{
+        EmailStringRule, ForAllVec, MinMaxU8, NonEmptyString, NonEmptyStringRule, NonEmptyVec,
+        RuleDescription,
+    };
+    use schemars::{schema_for, JsonSchema};
+    use serde::Serialize;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_schema_of_number() {
+        let schema = schema_for!(MinMaxU8<18, 80>);
+        assert_eq!(
+            schema.as_value(),
+            &json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "Refined_uint8",
+                "type": "integer",
+                "format": "uint8",
+                "minimum": 18,
+                "maximum": 80,
+                "description": "(equal to 18 or greater than 18) and (equal to 80 or less than 80)"
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_schema_of_struct_fields() {
+        #[derive(Serialize, JsonSchema)]
+        struct Human {
+            name: NonEmptyString,
+            email: crate::Refined<EmailStringRule>,
+            friends: NonEmptyVec<String>,
+            tags: ForAllVec<NonEmptyStringRule>,
+        }
+
+        let schema = schema_for!(Human);
+        let properties = &schema.as_value()["properties"];
+        assert_eq!(
+            properties["name"],
+            json!({ "type": "string", "minLength": 1, "description": "not empty" })
+        );
+        assert_eq!(
+            properties["email"]["pattern"],
+            EmailStringRule::json_schema()["pattern"]
+        );
+        assert_eq!(
+            properties["friends"],
+            json!({
+                "type": "array",
+                "items": { "type": "string" },
+                "minItems": 1,
+                "description": "not empty"
+            })
+        );
+        assert_eq!(
+            properties["tags"]["items"],
+            json!({ "type": "string", "minLength": 1 })
+        );
+    }
+}
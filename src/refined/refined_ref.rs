@@ -0,0 +1,275 @@
+use crate::result::Error;
+use crate::rule::RuleRef;
+use crate::Refined;
+use serde::{Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// `RefinedRef` is the borrowed counterpart of `Refined`, which ensures that the referenced value satisfies `RULE`
+///
+/// The value is checked in place by `RuleRef::validate_ref`, so validating a field of a borrowed struct
+/// or an element of a buffer neither moves nor clones it.
+/// # Example
+/// ```rust
+/// use refined_type::rule::NonEmptyStringRule;
+/// use refined_type::RefinedRef;
+///
+/// let names = vec!["john".to_string(), "".to_string()];
+///
+/// let name = RefinedRef::<NonEmptyStringRule>::new(&names[0]).unwrap();
+/// assert_eq!(name.value(), "john");
+///
+/// assert!(RefinedRef::<NonEmptyStringRule>::new(&names[1]).is_err());
+/// ```
+pub struct RefinedRef<'a, RULE>
+where
+    RULE: RuleRef,
+{
+    value: &'a RULE::Item,
+}
+
+impl<'a, RULE, T> RefinedRef<'a, RULE>
+where
+    RULE: RuleRef<Item = T>,
+{
+    /// Creates a new `RefinedRef` instance if the referenced value satisfies the rule.
+    ///
+    /// On failure, the error holds the reference that was passed in.
+    pub fn new(value: &'a T) -> Result<Self, Error<&'a T>> {
+        match RULE::validate_ref(value) {
+            Ok(()) => Ok(Self { value }),
+            Err(tree) => Err(Error::from_tree(value, *tree)),
+        }
+    }
+
+    /// Returns the reference to the value, with the lifetime of the borrowed value
+    pub fn value(&self) -> &'a T {
+        self.value
+    }
+
+    /// Clones the value into an owned `Refined` without validating it again
+    /// # Example
+    /// ```rust
+    /// use refined_type::rule::NonEmptyStringRule;
+    /// use refined_type::RefinedRef;
+    ///
+    /// let name = "john".to_string();
+    /// let name = RefinedRef::<NonEmptyStringRule>::new(&name).unwrap();
+    /// assert_eq!(name.to_refined().into_value(), "john");
+    /// ```
+    pub fn to_refined(&self) -> Refined<RULE>
+    where
+        T: Clone,
+    {
+        Refined::new_unchecked(self.value.clone())
+    }
+}
+
+impl<RULE, T> Refined<RULE>
+where
+    RULE: RuleRef<Item = T>,
+{
+    /// Borrows the value as a `RefinedRef`, which is already known to satisfy the rule
+    pub fn as_refined_ref(&self) -> RefinedRef<'_, RULE> {
+        RefinedRef {
+            value: self.value(),
+        }
+    }
+}
+
+impl<RULE> Clone for RefinedRef<'_, RULE>
+where
+    RULE: RuleRef,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<RULE> Copy for RefinedRef<'_, RULE> where RULE: RuleRef {}
+
+impl<RULE, T> Debug for RefinedRef<'_, RULE>
+where
+    RULE: RuleRef<Item = T>,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefinedRef")
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<RULE, T> PartialEq for RefinedRef<'_, RULE>
+where
+    RULE: RuleRef<Item = T>,
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<RULE, T> Eq for RefinedRef<'_, RULE>
+where
+    RULE: RuleRef<Item = T>,
+    T: Eq,
+{
+}
+
+impl<RULE, T> PartialOrd for RefinedRef<'_, RULE>
+where
+    RULE: RuleRef<Item = T>,
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(other.value)
+    }
+}
+
+impl<RULE, T> Ord for RefinedRef<'_, RULE>
+where
+    RULE: RuleRef<Item = T>,
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(other.value)
+    }
+}
+
+impl<RULE, T> Hash for RefinedRef<'_, RULE>
+where
+    RULE: RuleRef<Item = T>,
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<RULE, T> Deref for RefinedRef<'_, RULE>
+where
+    RULE: RuleRef<Item = T>,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<RULE, T> Display for RefinedRef<'_, RULE>
+where
+    RULE: RuleRef<Item = T>,
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<RULE, T> Serialize for RefinedRef<'_, RULE>
+where
+    RULE: RuleRef<Item = T>,
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::result::Error;
+    use crate::result::Location;
+    use crate::rule::{
+        CountGreaterVecRule, EmailRule, ExistsVecRule, ForAllVecRule, IndexRuleVec,
+        LengthGreaterVecRule, NonEmptyString, NonEmptyStringRule, NonEmptyVecRule, RangeRuleU8,
+        ReverseRule,
+    };
+    use crate::{And, RefinedRef};
+    use serde_json::json;
+
+    struct Request {
+        name: String,
+        email: String,
+        tags: Vec<String>,
+        level: u8,
+    }
+
+    #[test]
+    fn test_refined_ref_borrowed_fields() {
+        let request = Request {
+            name: "john".to_string(),
+            email: "john@example.com".to_string(),
+            tags: vec!["admin".to_string()],
+            level: 3,
+        };
+
+        let name = RefinedRef::<NonEmptyStringRule>::new(&request.name).unwrap();
+        let email =
+            RefinedRef::<And![NonEmptyStringRule, EmailRule<String>]>::new(&request.email).unwrap();
+        let tags = RefinedRef::<NonEmptyVecRule<String>>::new(&request.tags).unwrap();
+        let level = RefinedRef::<RangeRuleU8<1, 10>>::new(&request.level).unwrap();
+
+        assert_eq!(name.value(), "john");
+        assert_eq!(email.len(), 16);
+        assert_eq!(tags.value(), &vec!["admin".to_string()]);
+        assert_eq!(*level, 3);
+    }
+
+    #[test]
+    fn test_refined_ref_err_keeps_reference() {
+        let tags = vec!["a".to_string()];
+        let error = RefinedRef::<LengthGreaterVecRule<1, String>>::new(&tags).unwrap_err();
+        assert_eq!(error.to_string(), "target length is not greater than 1");
+        assert!(std::ptr::eq(error.into_value(), &tags));
+    }
+
+    #[test]
+    fn test_refined_ref_matches_owned_validation() {
+        let empty = String::new();
+        let borrowed = RefinedRef::<NonEmptyStringRule>::new(&empty).unwrap_err();
+        let owned = NonEmptyString::new(empty.clone()).unwrap_err();
+        assert_eq!(borrowed.tree(), owned.tree());
+    }
+
+    #[test]
+    fn test_refined_ref_round_trip() -> Result<(), Error<String>> {
+        let name = NonEmptyString::new("john".to_string())?;
+        let borrowed = name.as_refined_ref();
+        let copied = borrowed;
+        assert_eq!(borrowed.to_refined(), name);
+        assert_eq!(copied.to_string(), "john");
+        assert_eq!(json!(borrowed), json!("john"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_refined_ref_collection_rules() {
+        let tags = vec!["admin".to_string(), "".to_string()];
+
+        assert!(RefinedRef::<ExistsVecRule<NonEmptyStringRule>>::new(&tags).is_ok());
+        assert!(RefinedRef::<IndexRuleVec<0, NonEmptyStringRule>>::new(&tags).is_ok());
+        assert!(RefinedRef::<ReverseRule<IndexRuleVec<1, NonEmptyStringRule>>>::new(&tags).is_ok());
+        assert!(RefinedRef::<
+            And![NonEmptyVecRule<String>, CountGreaterVecRule<1, NonEmptyStringRule>],
+        >::new(&tags)
+        .is_err());
+
+        let error = RefinedRef::<ForAllVecRule<NonEmptyStringRule>>::new(&tags).unwrap_err();
+        let owned =
+            crate::Refined::<ForAllVecRule<NonEmptyStringRule>>::new(tags.clone()).unwrap_err();
+        assert_eq!(error.tree(), owned.tree());
+        assert_eq!(
+            error.tree().children()[0].location(),
+            Some(&Location::Index(1))
+        );
+        assert!(std::ptr::eq(error.into_value(), &tags));
+    }
+}
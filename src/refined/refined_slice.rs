@@ -0,0 +1,200 @@
+use crate::result::Error;
+use crate::rule::{Rule, RuleBorrow};
+use crate::Refined;
+use serde::{Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+/// `RefinedSlice` is an unsized `[T]` that satisfies `RULE`, used behind a reference like `&[T]`
+///
+/// It has the same layout as `[T]`, so `&RefinedSlice<T, RULE>` is obtained from a `&[T]` after validation
+/// without copying, and derefs to `&[T]`.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{NonEmptyVec, NonEmptyVecRule};
+/// use refined_type::RefinedSlice;
+///
+/// fn first(values: &RefinedSlice<u8, NonEmptyVecRule<u8>>) -> u8 {
+///     values[0]
+/// }
+///
+/// let values = [1, 2, 3];
+/// let slice = RefinedSlice::<u8, NonEmptyVecRule<u8>>::new(&values[1..]).unwrap();
+/// assert_eq!(first(slice), 2);
+///
+/// let owned = NonEmptyVec::new(vec![4, 5]).unwrap();
+/// assert_eq!(first(owned.as_refined_slice()), 4);
+///
+/// assert!(RefinedSlice::<u8, NonEmptyVecRule<u8>>::new(&values[3..]).is_err());
+/// ```
+#[repr(transparent)]
+pub struct RefinedSlice<T, RULE> {
+    _rule: PhantomData<RULE>,
+    value: [T],
+}
+
+impl<T, RULE> RefinedSlice<T, RULE>
+where
+    RULE: RuleBorrow<[T]>,
+{
+    /// Creates a new `&RefinedSlice` if the provided `&[T]` satisfies the rule.
+    pub fn new(value: &[T]) -> Result<&Self, Error<&[T]>> {
+        match RULE::validate_borrow(value) {
+            Ok(()) => Ok(Self::new_unchecked(value)),
+            Err(tree) => Err(Error::from_tree(value, *tree)),
+        }
+    }
+
+    pub(crate) fn new_unchecked(value: &[T]) -> &Self {
+        // SAFETY: `RefinedSlice` is `repr(transparent)` over `[T]`, so both references have the same layout
+        unsafe { &*(value as *const [T] as *const Self) }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.value
+    }
+
+    /// Copies the value into an owned `Refined` without validating it again
+    pub fn to_refined(&self) -> Refined<RULE>
+    where
+        RULE::Item: for<'a> From<&'a [T]>,
+    {
+        Refined::new_unchecked(RULE::Item::from(&self.value))
+    }
+}
+
+impl<T, RULE> Refined<RULE>
+where
+    RULE: Rule<Item = Vec<T>> + RuleBorrow<[T]>,
+{
+    /// Borrows the value as a `&RefinedSlice`, which is already known to satisfy the rule
+    pub fn as_refined_slice(&self) -> &RefinedSlice<T, RULE> {
+        RefinedSlice::new_unchecked(self.value())
+    }
+}
+
+impl<T, RULE> Deref for RefinedSlice<T, RULE> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, RULE> AsRef<[T]> for RefinedSlice<T, RULE> {
+    fn as_ref(&self) -> &[T] {
+        &self.value
+    }
+}
+
+impl<'a, T, RULE> IntoIterator for &'a RefinedSlice<T, RULE> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.value.iter()
+    }
+}
+
+impl<T: Debug, RULE> Debug for RefinedSlice<T, RULE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.value, f)
+    }
+}
+
+impl<T: PartialEq, RULE> PartialEq for RefinedSlice<T, RULE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, RULE> Eq for RefinedSlice<T, RULE> {}
+
+impl<T: PartialOrd, RULE> PartialOrd for RefinedSlice<T, RULE> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord, RULE> Ord for RefinedSlice<T, RULE> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash, RULE> Hash for RefinedSlice<T, RULE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<T: Serialize, RULE> Serialize for RefinedSlice<T, RULE> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::result::Error;
+    use crate::rule::{
+        EvenRuleI32, ForAllVecRule, IndexRuleVec, LengthEqualVecRule, NonEmptyVec, NonEmptyVecRule,
+    };
+    use crate::RefinedSlice;
+
+    type Pair = RefinedSlice<i32, LengthEqualVecRule<2, i32>>;
+
+    #[test]
+    fn test_refined_slice_chunks() {
+        let values = [1, 2, 3, 4, 5];
+        let pairs = values
+            .chunks(2)
+            .filter_map(|chunk| Pair::new(chunk).ok())
+            .map(|pair| pair.iter().sum::<i32>())
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, vec![3, 7]);
+    }
+
+    #[test]
+    fn test_refined_slice_error() {
+        let values = [1, 2, 3];
+        let error = Pair::new(&values).unwrap_err();
+        assert_eq!(error.to_string(), "target length is not equal to 2");
+        assert_eq!(error.into_value(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_refined_slice_round_trip() -> Result<(), Error<Vec<i32>>> {
+        let owned = NonEmptyVec::new(vec![1, 2])?;
+        let borrowed = owned.as_refined_slice();
+        assert_eq!(format!("{borrowed:?}"), "[1, 2]");
+        assert_eq!(
+            borrowed,
+            RefinedSlice::<i32, NonEmptyVecRule<i32>>::new(&[1, 2]).unwrap()
+        );
+        assert_eq!(borrowed.to_refined(), owned);
+        Ok(())
+    }
+
+    #[test]
+    fn test_refined_slice_collection_rules() {
+        let values = [2, 4, 6, 3];
+        let even = values
+            .chunks(2)
+            .filter_map(|chunk| RefinedSlice::<i32, ForAllVecRule<EvenRuleI32>>::new(chunk).ok())
+            .count();
+        assert_eq!(even, 1);
+
+        let error = RefinedSlice::<i32, IndexRuleVec<3, EvenRuleI32>>::new(&values).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the item at index 3 does not satisfy the condition"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::result::Error;
+use crate::rule::{Rule, RuleBorrow};
+use crate::Refined;
+use serde::{Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+/// `RefinedStr` is an unsized `str` that satisfies `RULE`, used behind a reference like `&str`
+///
+/// It has the same layout as `str`, so `&RefinedStr<RULE>` is obtained from a `&str` after validation
+/// without copying, and derefs to `&str`.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{NonEmptyString, NonEmptyStringRule};
+/// use refined_type::RefinedStr;
+///
+/// fn greet(name: &RefinedStr<NonEmptyStringRule>) -> String {
+///     format!("Hello, {}!", name.to_uppercase())
+/// }
+///
+/// let name = RefinedStr::<NonEmptyStringRule>::new("john").unwrap();
+/// assert_eq!(greet(name), "Hello, JOHN!");
+///
+/// let owned = NonEmptyString::new("taro".to_string()).unwrap();
+/// assert_eq!(greet(owned.as_refined_str()), "Hello, TARO!");
+///
+/// assert!(RefinedStr::<NonEmptyStringRule>::new("").is_err());
+/// ```
+#[repr(transparent)]
+pub struct RefinedStr<RULE> {
+    _rule: PhantomData<RULE>,
+    value: str,
+}
+
+impl<RULE> RefinedStr<RULE>
+where
+    RULE: RuleBorrow<str>,
+{
+    /// Creates a new `&RefinedStr` if the provided `&str` satisfies the rule.
+    pub fn new(value: &str) -> Result<&Self, Error<&str>> {
+        match RULE::validate_borrow(value) {
+            Ok(()) => Ok(Self::new_unchecked(value)),
+            Err(tree) => Err(Error::from_tree(value, *tree)),
+        }
+    }
+
+    pub(crate) fn new_unchecked(value: &str) -> &Self {
+        // SAFETY: `RefinedStr` is `repr(transparent)` over `str`, so both references have the same layout
+        unsafe { &*(value as *const str as *const Self) }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Copies the value into an owned `Refined` without validating it again
+    pub fn to_refined(&self) -> Refined<RULE>
+    where
+        RULE::Item: for<'a> From<&'a str>,
+    {
+        Refined::new_unchecked(RULE::Item::from(&self.value))
+    }
+}
+
+impl<RULE> Refined<RULE>
+where
+    RULE: Rule<Item = String> + RuleBorrow<str>,
+{
+    /// Borrows the value as a `&RefinedStr`, which is already known to satisfy the rule
+    pub fn as_refined_str(&self) -> &RefinedStr<RULE> {
+        RefinedStr::new_unchecked(self.value())
+    }
+}
+
+impl<RULE> Deref for RefinedStr<RULE> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<RULE> AsRef<str> for RefinedStr<RULE> {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl<RULE> Debug for RefinedStr<RULE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.value, f)
+    }
+}
+
+impl<RULE> Display for RefinedStr<RULE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl<RULE> PartialEq for RefinedStr<RULE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<RULE> Eq for RefinedStr<RULE> {}
+
+impl<RULE> PartialOrd for RefinedStr<RULE> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<RULE> Ord for RefinedStr<RULE> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<RULE> Hash for RefinedStr<RULE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<RULE> Serialize for RefinedStr<RULE> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::result::Error;
+    use crate::rule::{
+        AlphabetRule, EmailRule, LengthLessStringRule, NonEmptyString, NonEmptyStringRule,
+    };
+    use crate::{And, RefinedStr};
+    use serde_json::json;
+
+    type Username = And![
+        NonEmptyStringRule,
+        LengthLessStringRule<8>,
+        AlphabetRule<String>
+    ];
+
+    fn parse_fields(line: &str) -> Vec<Result<&RefinedStr<Username>, Error<&str>>> {
+        line.split(',').map(RefinedStr::<Username>::new).collect()
+    }
+
+    #[test]
+    fn test_refined_str_from_slices() {
+        let fields = parse_fields("john,,taro1,abcdefghij");
+        assert_eq!(
+            fields[0].as_ref().map(|name| name.as_str()).ok(),
+            Some("john")
+        );
+        assert!(fields[1..].iter().all(Result::is_err));
+    }
+
+    #[test]
+    fn test_refined_str_error() {
+        let error = RefinedStr::<EmailRule<String>>::new("example.com").unwrap_err();
+        assert_eq!(error.tree().rule(), Some("EmailRule"));
+        assert_eq!(error.into_value(), "example.com");
+    }
+
+    #[test]
+    fn test_refined_str_deref() -> Result<(), Error<&'static str>> {
+        let name = RefinedStr::<NonEmptyStringRule>::new("john")?;
+        assert_eq!(name.len(), 4);
+        assert!(name.starts_with("jo"));
+        assert_eq!(format!("{name} {name:?}"), "john \"john\"");
+        assert_eq!(json!(name), json!("john"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_refined_str_round_trip() -> Result<(), Error<String>> {
+        let owned = NonEmptyString::new("john".to_string())?;
+        let borrowed: &RefinedStr<NonEmptyStringRule> = owned.as_refined_str();
+        assert_eq!(borrowed, RefinedStr::new("john").unwrap());
+        assert_eq!(borrowed.to_refined(), owned);
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::rule::RuleStrategy;
+use crate::Refined;
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+/// `Refined<RULE>` generates the values of `RuleStrategy::strategy`, so every value satisfies `RULE`
+/// # Example
+/// ```rust
+/// use proptest::prelude::*;
+/// use refined_type::rule::{ForAllVec, NonEmptyStringRule};
+///
+/// proptest!(|(tags in any::<ForAllVec<NonEmptyStringRule>>())| {
+///     prop_assert!(tags.iter().all(|tag| !tag.is_empty()));
+/// });
+/// ```
+impl<RULE> Arbitrary for Refined<RULE>
+where
+    RULE: RuleStrategy,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        RULE::strategy().prop_map(Refined::new_unchecked).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{LengthMinMax, NonEmptyString, RangeU8};
+    use proptest::prelude::*;
+    use serde::Serialize;
+
+    #[derive(Debug, Serialize)]
+    struct Human {
+        name: NonEmptyString,
+        age: RangeU8<18, 80>,
+        friends: LengthMinMax<1, 3, Vec<String>>,
+    }
+
+    fn human() -> impl Strategy<Value = Human> {
+        (
+            any::<NonEmptyString>(),
+            any::<RangeU8<18, 80>>(),
+            any::<LengthMinMax<1, 3, Vec<String>>>(),
+        )
+            .prop_map(|(name, age, friends)| Human { name, age, friends })
+    }
+
+    proptest! {
+        #[test]
+        fn test_arbitrary_refined_fields(human in human()) {
+            prop_assert!(!human.name.is_empty());
+            prop_assert!((18..80).contains(human.age.value()));
+            prop_assert!((1..=3).contains(&human.friends.len()));
+        }
+
+        #[test]
+        fn test_arbitrary_refined_round_trip(name in any::<NonEmptyString>()) {
+            let json = serde_json::to_string(&name).unwrap();
+            prop_assert_eq!(serde_json::from_str::<NonEmptyString>(&json).unwrap(), name);
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_refined_revalidates() {
+        let mut runner = proptest::test_runner::TestRunner::default();
+        for _ in 0..100 {
+            let age = any::<RangeU8<18, 80>>()
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            assert!(RangeU8::<18, 80>::new(age.into_value()).is_ok());
+        }
+    }
+}
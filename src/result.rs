@@ -1,36 +1,62 @@
+mod error_tree;
+
+pub(crate) use error_tree::{rule_name, rule_type_name};
+pub use error_tree::{ErrorTree, Location};
+
 use std::fmt::{Debug, Display, Formatter};
 
 /// A type alias for a `Result` to use in the `Refined` module
 pub type Result<T> = std::result::Result<T, Error<T>>;
 
 /// A type indicating a failure to convert to `Refined`
+///
+/// Besides the rejected value, it carries an `ErrorTree` describing which rule failed and why.
 #[derive(Debug)]
 pub struct Error<T> {
     value: T,
-    message: String,
+    tree: Box<ErrorTree>,
 }
 
 impl<T> Error<T> {
     pub fn new(value: T, message: impl Into<String>) -> Self {
         Self {
             value,
-            message: message.into(),
+            tree: Box::new(ErrorTree::new(message)),
+        }
+    }
+
+    pub fn from_tree(value: T, tree: ErrorTree) -> Self {
+        Self {
+            value,
+            tree: Box::new(tree),
         }
     }
 
+    pub fn tree(&self) -> &ErrorTree {
+        &self.tree
+    }
+
+    pub fn into_tree(self) -> ErrorTree {
+        *self.tree
+    }
+
     pub fn into_value(self) -> T {
         self.value
     }
+
+    pub fn into_parts(self) -> (T, ErrorTree) {
+        (self.value, *self.tree)
+    }
 }
 
 impl<T: Debug> std::error::Error for Error<T> {
     fn description(&self) -> &str {
-        &self.message
+        self.tree.message()
     }
 }
 
 impl<T> Display for Error<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
+        write!(f, "{}", self.tree)
     }
 }
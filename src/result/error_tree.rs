@@ -0,0 +1,204 @@
+use regex::Regex;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+
+/// The position of a nested `ErrorTree` relative to its parent
+///
+/// Serialized as a bare number or string so that a list of locations reads like a JSON path.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Location {
+    /// The index of an element in a collection
+    Index(usize),
+    /// The key of an entry or the name of a field
+    Key(String),
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Location::Index(index) => write!(f, "{index}"),
+            Location::Key(key) => write!(f, "{key}"),
+        }
+    }
+}
+
+/// A structured, machine-readable description of why a value does not satisfy a `Rule`
+///
+/// Composite rules such as `And` and `Or` attach the errors of their sub-rules as `children`,
+/// so the whole tree can be serialized into an error payload without parsing the message.
+///
+/// # Example
+/// ```rust
+/// use refined_type::rule::{LessRuleU8, Rule};
+///
+/// let error = LessRuleU8::<10>::validate(11).unwrap_err();
+/// let tree = error.tree();
+///
+/// assert_eq!(tree.rule(), Some("LessRuleU8"));
+/// assert_eq!(tree.expected(), Some("less than 10"));
+/// assert_eq!(tree.actual(), Some("11"));
+/// assert_eq!(tree.message(), "the value must be less than 10, but received 11");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorTree {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual: Option<String>,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    children: Vec<ErrorTree>,
+}
+
+impl ErrorTree {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            rule: None,
+            expected: None,
+            actual: None,
+            message: message.into(),
+            location: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the rule that was violated
+    ///
+    /// The name is that of the rule type without its parameters, e.g. `LessRuleU8` or `Not`,
+    /// and the parameters show in `expected` instead.
+    pub fn with_rule(mut self, rule: impl Into<String>) -> Self {
+        self.rule = Some(rule.into());
+        self
+    }
+
+    /// Sets a description of the constraint the value was expected to satisfy
+    pub fn with_expected(mut self, expected: impl Into<String>) -> Self {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    /// Sets a rendering of the value that was received
+    pub fn with_actual(mut self, actual: impl Into<String>) -> Self {
+        self.actual = Some(actual.into());
+        self
+    }
+
+    /// Sets the position of this error relative to its parent
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Appends the errors of the sub-rules that caused this error
+    pub fn with_children(mut self, children: impl IntoIterator<Item = ErrorTree>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    pub fn rule(&self) -> Option<&str> {
+        self.rule.as_deref()
+    }
+
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+
+    pub fn actual(&self) -> Option<&str> {
+        self.actual.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    pub fn children(&self) -> &[ErrorTree] {
+        &self.children
+    }
+
+    pub fn into_children(self) -> Vec<ErrorTree> {
+        self.children
+    }
+}
+
+impl Display for ErrorTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Returns the name of the rule type `T` without its parameters, as `ErrorTree::with_rule` expects,
+/// e.g. `Not` for `refined_type::rule::composer::not::Not<...>`
+pub(crate) fn rule_type_name<T: ?Sized>() -> String {
+    let name = rule_name::<T>();
+    match name.split_once('<') {
+        Some((name, _)) => name.to_string(),
+        None => name,
+    }
+}
+
+/// Returns the name of `T` with every module path stripped,
+/// e.g. `Not<EmptyRule<String>>` instead of `refined_type::rule::composer::not::Not<...>`
+pub(crate) fn rule_name<T: ?Sized>() -> String {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = REGEX
+        .get_or_init(|| Regex::new(r"\b(?:[a-z_][a-z0-9_]*::)+").expect("invalid regex pattern"));
+    regex
+        .replace_all(std::any::type_name::<T>(), "")
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::result::{rule_name, rule_type_name, ErrorTree, Location};
+    use crate::rule::composer::Not;
+    use crate::rule::EmptyRule;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_error_tree() {
+        let tree = ErrorTree::new("[a || b]")
+            .with_rule("Or")
+            .with_children(vec![
+                ErrorTree::new("a")
+                    .with_rule("A")
+                    .with_location(Location::Index(0)),
+                ErrorTree::new("b")
+                    .with_rule("B")
+                    .with_expected("b")
+                    .with_actual("1")
+                    .with_location(Location::Key("name".to_string())),
+            ]);
+
+        let actual = json!(tree);
+        let expected = json! {{
+            "rule": "Or",
+            "message": "[a || b]",
+            "children": [
+                { "rule": "A", "message": "a", "location": 0 },
+                { "rule": "B", "expected": "b", "actual": "1", "message": "b", "location": "name" }
+            ]
+        }};
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_rule_name() {
+        assert_eq!(
+            rule_name::<Not<EmptyRule<String>>>(),
+            "Not<EmptyRule<String>>"
+        );
+        assert_eq!(rule_name::<EmptyRule<&str>>(), "EmptyRule<&str>");
+        assert_eq!(rule_type_name::<Not<EmptyRule<String>>>(), "Not");
+        assert_eq!(rule_type_name::<u8>(), "u8");
+    }
+}
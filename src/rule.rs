@@ -1,25 +1,93 @@
-use crate::result::Error;
+use crate::result::{Error, ErrorTree};
 pub use collection::*;
+pub use const_rule::{ConstRule, IntLiteral, StrLiteral};
+pub use description::{JsonType, JsonTyped, RuleDescription};
 pub use empty::*;
 pub use length::*;
 pub use non_empty::*;
 pub use number::*;
+use std::borrow::Borrow;
+#[cfg(feature = "proptest")]
+pub use strategy::{filtered_strategy, regex_strategy, ArbitraryWithin, Bounds, RuleStrategy};
 pub use string::*;
+pub use transform::*;
 
 mod collection;
 pub mod composer;
+pub(crate) mod const_rule;
+pub(crate) mod define;
+pub(crate) mod description;
 mod empty;
 mod length;
 mod non_empty;
 mod number;
+#[cfg(feature = "proptest")]
+pub(crate) mod strategy;
 mod string;
+mod transform;
 
 /// This is a `trait` that specifies the conditions a type `T` should satisfy
 pub trait Rule {
     type Item;
+
+    /// Whether `validate` returns a value other than the one it was given, as `Normalized` does
+    ///
+    /// A composer would pass such a value out without checking it against its other rules,
+    /// so the composers and the collection rules refuse a normalizing rule at compile time.
+    #[doc(hidden)]
+    const NORMALIZES: bool = false;
+
+    /// Whether the `ErrorTree` of a violation lists each violated rule as a child, as `AndAll` does
+    ///
+    /// An enclosing `AndAll` lifts those children into its own list instead of nesting the tree.
+    #[doc(hidden)]
+    const LISTS_VIOLATIONS: bool = false;
+
     fn validate(target: Self::Item) -> crate::Result<Self::Item>;
 }
 
+/// Fails the compilation when `RULE` normalizes the value, which only the outermost rule may do
+pub(crate) const fn refuse_normalized<RULE: Rule>() {
+    assert!(
+        !RULE::NORMALIZES,
+        "`Normalized` must be the outermost rule, not wrapped by a composer or a collection rule"
+    );
+}
+
+/// This is a `trait` that checks the conditions of a `Rule` on a borrowed value
+///
+/// Unlike `Rule::validate`, it does not take ownership of the value,
+/// so a value can be validated in place, e.g. by `RefinedRef`, without cloning it.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{NonEmptyStringRule, RuleRef};
+///
+/// let name = String::from("john");
+/// assert!(NonEmptyStringRule::validate_ref(&name).is_ok());
+///
+/// let tree = NonEmptyStringRule::validate_ref(&String::new()).unwrap_err();
+/// assert_eq!(tree.rule(), Some("Not"));
+/// ```
+pub trait RuleRef: Rule {
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>>;
+}
+
+/// This is a `trait` that checks the conditions of a `Rule` on `B`, a borrowed form of its `Item`
+///
+/// For example, `NonEmptyStringRule` checks a `str` and `NonEmptyVecRule<T>` checks a `[T]`,
+/// which is how `RefinedStr` and `RefinedSlice` are validated.
+/// An implementation must accept exactly the values whose owned form `Rule::validate` accepts.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{NonEmptyStringRule, RuleBorrow};
+///
+/// assert!(NonEmptyStringRule::validate_borrow("john").is_ok());
+/// assert!(NonEmptyStringRule::validate_borrow("").is_err());
+/// ```
+pub trait RuleBorrow<B: ?Sized>: Rule {
+    fn validate_borrow(target: &B) -> Result<(), Box<ErrorTree>>;
+}
+
 /// This is a `Rule` that always returns `Ok`
 pub struct Valid<T> {
     _phantom: std::marker::PhantomData<T>,
@@ -32,6 +100,32 @@ impl<T> Rule for Valid<T> {
     }
 }
 
+impl<T> RuleRef for Valid<T> {
+    fn validate_ref(_target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        Ok(())
+    }
+}
+
+impl<T: Borrow<B>, B: ?Sized> RuleBorrow<B> for Valid<T> {
+    fn validate_borrow(_target: &B) -> Result<(), Box<ErrorTree>> {
+        Ok(())
+    }
+}
+
+impl<T, LITERAL> ConstRule<LITERAL> for Valid<T> {
+    const VALID: bool = true;
+}
+
+impl<T> RuleDescription for Valid<T> {
+    fn description() -> String {
+        "anything".to_string()
+    }
+
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({})
+    }
+}
+
 /// This is a `Rule` that always returns `Err`
 pub struct Invalid<T> {
     _phantom: std::marker::PhantomData<T>,
@@ -40,6 +134,35 @@ pub struct Invalid<T> {
 impl<T> Rule for Invalid<T> {
     type Item = T;
     fn validate(target: Self::Item) -> crate::Result<Self::Item> {
-        Err(Error::new(target, "Invalid"))
+        match Self::validate_ref(&target) {
+            Ok(()) => Ok(target),
+            Err(tree) => Err(Error::from_tree(target, *tree)),
+        }
+    }
+}
+
+impl<T> RuleRef for Invalid<T> {
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        <Self as RuleBorrow<T>>::validate_borrow(target)
+    }
+}
+
+impl<T: Borrow<B>, B: ?Sized> RuleBorrow<B> for Invalid<T> {
+    fn validate_borrow(_target: &B) -> Result<(), Box<ErrorTree>> {
+        Err(Box::new(ErrorTree::new("Invalid").with_rule("Invalid")))
+    }
+}
+
+impl<T, LITERAL> ConstRule<LITERAL> for Invalid<T> {
+    const VALID: bool = false;
+}
+
+impl<T> RuleDescription for Invalid<T> {
+    fn description() -> String {
+        "nothing".to_string()
+    }
+
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({ "not": {} })
     }
 }
@@ -1,4 +1,5 @@
 mod count;
+mod distinct;
 mod exists;
 mod for_all;
 mod head;
@@ -6,12 +7,16 @@ mod index;
 mod init;
 mod iterable;
 mod last;
+mod map;
 mod nothing;
 mod reverse;
 mod skip;
+mod sorted;
 mod tail;
+mod windows;
 
 pub use count::*;
+pub use distinct::*;
 pub use exists::*;
 pub use for_all::*;
 pub use head::*;
@@ -19,7 +24,21 @@ pub use index::*;
 pub use init::*;
 pub use iterable::*;
 pub use last::*;
+pub use map::*;
 pub use nothing::*;
 pub use reverse::*;
 pub use skip::*;
+pub use sorted::*;
 pub use tail::*;
+pub use windows::*;
+
+#[cfg(test)]
+mod fixture {
+    crate::define_rule![
+        #[allow(dead_code)]
+        pub(crate) Uppercase,
+        char,
+        |c| c.is_uppercase(),
+        "{value} is not uppercase"
+    ];
+}
@@ -9,3 +9,111 @@ pub use grater_equal::*;
 pub use greater::*;
 pub use less::*;
 pub use less_equal::*;
+
+use crate::result::ErrorTree;
+use crate::rule::IterableItems;
+use crate::rule::{ItemPosition, Rule, RuleRef};
+
+/// The items of a collection validated by `RULE`, split into those that satisfy it and those that do not
+///
+/// A count rule reports the items on the side that caused the violation,
+/// e.g. the satisfying items when too many satisfy `RULE`.
+#[derive(Default)]
+pub(crate) struct CountedItems {
+    pub(crate) satisfied: Vec<ErrorTree>,
+    pub(crate) unsatisfied: Vec<ErrorTree>,
+}
+
+impl CountedItems {
+    pub(crate) fn count(&self) -> usize {
+        self.satisfied.len()
+    }
+
+    fn record(&mut self, position: ItemPosition<'_>, result: Result<(), ErrorTree>) {
+        match result {
+            Ok(()) => self.satisfied.push(position.locate(ErrorTree::new(format!(
+                "{} satisfies the condition",
+                position.describe()
+            )))),
+            Err(tree) => self.unsatisfied.push(position.locate(tree)),
+        }
+    }
+
+    /// Validates every item of `target` by `RULE`, keeping the validated items
+    pub(crate) fn validate<RULE, ITERABLE>(target: ITERABLE) -> (ITERABLE, Self)
+    where
+        RULE: Rule,
+        ITERABLE: IterableItems<Item = RULE::Item>,
+    {
+        const { crate::rule::refuse_normalized::<RULE>() };
+        let mut counted = Self::default();
+        let target = target.map_items(|position, item| match RULE::validate(item) {
+            Ok(item) => {
+                counted.record(position, Ok(()));
+                item
+            }
+            Err(e) => {
+                let (item, tree) = e.into_parts();
+                counted.record(position, Err(tree));
+                item
+            }
+        });
+        (target, counted)
+    }
+
+    /// Validates the items given by `visit` by `RULE::validate_ref`
+    pub(crate) fn visit<RULE: RuleRef>(
+        visit: impl FnOnce(&mut dyn FnMut(ItemPosition<'_>, &RULE::Item)),
+    ) -> Self {
+        let mut counted = Self::default();
+        visit(&mut |position, item| {
+            counted.record(position, RULE::validate_ref(item).map_err(|tree| *tree))
+        });
+        counted
+    }
+}
+
+/// Implements `RuleRef` for a count rule and `RuleBorrow` for its `Vec` and `String` forms,
+/// given a function turning the `CountedItems` into the `ErrorTree` of a violation
+macro_rules! impl_count_rule_ref {
+    ($rule:ident, $violation:ident) => {
+        impl<const N: usize, RULE, ITERABLE> $crate::rule::RuleRef for $rule<N, RULE, ITERABLE>
+        where
+            RULE: $crate::rule::RuleRef,
+            ITERABLE: $crate::rule::IterableItems<Item = RULE::Item>,
+        {
+            fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                let counted = CountedItems::visit::<RULE>(|f| target.visit_items(f));
+                $violation::<N>(counted).map_or(Ok(()), |tree| Err(Box::new(tree)))
+            }
+        }
+
+        impl<const N: usize, RULE, T> $crate::rule::RuleBorrow<[T]> for $rule<N, RULE, Vec<T>>
+        where
+            RULE: $crate::rule::RuleRef<Item = T>,
+        {
+            fn validate_borrow(target: &[T]) -> Result<(), Box<$crate::result::ErrorTree>> {
+                let counted = CountedItems::visit::<RULE>(|f| {
+                    for (i, item) in target.iter().enumerate() {
+                        f($crate::rule::ItemPosition::sequence(i), item);
+                    }
+                });
+                $violation::<N>(counted).map_or(Ok(()), |tree| Err(Box::new(tree)))
+            }
+        }
+
+        impl<const N: usize, RULE> $crate::rule::RuleBorrow<str> for $rule<N, RULE, String>
+        where
+            RULE: $crate::rule::RuleRef<Item = char>,
+        {
+            fn validate_borrow(target: &str) -> Result<(), Box<$crate::result::ErrorTree>> {
+                use $crate::rule::IterableItems;
+
+                let counted = CountedItems::visit::<RULE>(|f| target.visit_items(f));
+                $violation::<N>(counted).map_or(Ok(()), |tree| Err(Box::new(tree)))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_count_rule_ref;
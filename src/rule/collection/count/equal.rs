@@ -1,6 +1,9 @@
-use crate::result::Error;
-use crate::rule::{Iterable, Rule};
+use crate::result::{Error, ErrorTree};
+use crate::rule::collection::count::{impl_count_rule_ref, CountedItems};
+use crate::rule::IterableItems;
+use crate::rule::{description, Iterable, Rule, RuleDescription};
 use crate::Refined;
+use serde_json::{json, Value};
 use std::collections::VecDeque;
 
 /// A type that holds a value satisfying the `CountEqualRule`
@@ -25,6 +28,9 @@ pub type CountEqualString<const N: usize, RULE> = Refined<CountEqualStringRule<N
 pub type CountEqualStr<'a, const N: usize, RULE> = Refined<CountEqualStrRule<'a, N, RULE>>;
 
 /// Rule where the count of items that satisfy the condition is equal to `N`.
+///
+/// When validation fails, the errors of the items that do not satisfy the condition are
+/// reported as children of the `ErrorTree`, located by their index.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CountEqualRule<const N: usize, RULE: Rule, ITERABLE: Iterable>
 where
@@ -35,36 +41,43 @@ where
 
 impl<const N: usize, ITERABLE, RULE> Rule for CountEqualRule<N, RULE, ITERABLE>
 where
-    ITERABLE: Iterable<Item = RULE::Item> + FromIterator<ITERABLE::Item>,
+    ITERABLE: IterableItems<Item = RULE::Item>,
     RULE: Rule,
 {
     type Item = ITERABLE;
     fn validate(target: Self::Item) -> crate::Result<Self::Item> {
-        let mut count = 0;
-        let mut deque = VecDeque::new();
-        for item in target.into_iterator() {
-            match RULE::validate(item) {
-                Ok(item) => {
-                    deque.push_back(item);
-                    count += 1
-                }
-                Err(e) => {
-                    deque.push_back(e.into_value());
-                }
-            }
-        }
-        let target = ITERABLE::from_iter(deque);
-        if count == N {
-            Ok(target)
-        } else {
-            Err(Error::new(
-                target,
-                format!("count is not equal to {}, actual count is {}", N, count),
-            ))
+        let (target, counted) = CountedItems::validate::<RULE, _>(target);
+        match violation::<N>(counted) {
+            None => Ok(target),
+            Some(tree) => Err(Error::from_tree(target, tree)),
         }
     }
 }
 
+impl_count_rule_ref!(CountEqualRule, violation);
+
+/// Returns the `ErrorTree` of the violation unless the count of the satisfying items is equal to `N`
+fn violation<const N: usize>(counted: CountedItems) -> Option<ErrorTree> {
+    let count = counted.count();
+    if count == N {
+        return None;
+    }
+    Some(
+        ErrorTree::new(format!(
+            "count is not equal to {}, actual count is {}",
+            N, count
+        ))
+        .with_rule("CountEqualRule")
+        .with_expected(format!("count equal to {N}"))
+        .with_actual(count.to_string())
+        .with_children(if count > N {
+            counted.satisfied
+        } else {
+            counted.unsatisfied
+        }),
+    )
+}
+
 /// Rule where the count of items in the `Vec` that satisfy the condition is equal to `N`.
 pub type CountEqualVecRule<const N: usize, RULE> =
     CountEqualRule<N, RULE, Vec<<RULE as Rule>::Item>>;
@@ -87,10 +100,36 @@ pub type CountEqualStringRule<const N: usize, RULE> = CountEqualRule<N, RULE, St
 /// Rule where the count of items in the `&'a str` that satisfy the condition is equal to `N`.
 pub type CountEqualStrRule<'a, const N: usize, RULE> = CountEqualRule<N, RULE, &'a str>;
 
+macro_rules! impl_rule_description {
+    ($($sequence: ident),+) => {$(
+        impl<const N: usize, RULE, T> RuleDescription for CountEqualRule<N, RULE, $sequence<T>>
+        where
+            RULE: RuleDescription<Item = T>,
+        {
+            const OPERATOR: Option<&'static str> = Some("for");
+
+            fn description() -> String {
+                format!("for exactly {N} items, {}", description::operand::<RULE>("for"))
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "array", "contains": RULE::json_schema(), "minContains": N, "maxContains": N })
+            }
+        }
+    )+};
+}
+
+impl_rule_description!(Vec, VecDeque);
+
 #[cfg(test)]
 mod tests {
-    use crate::result::Error;
+    use crate::result::{Error, Location};
+    use crate::rule::Rule;
+    use crate::rule::{
+        CountEqualHashMap, CountEqualHashMapRule, CountEqualHashSet, CountEqualHashSetRule,
+    };
     use crate::rule::{CountEqualVec, NonEmptyStringRule};
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn count_equal_1() -> Result<(), Error<Vec<String>>> {
@@ -99,4 +138,62 @@ mod tests {
         assert_eq!(count_equal.into_value(), value);
         Ok(())
     }
+
+    #[test]
+    fn count_equal_reports_every_failing_item() {
+        let value = vec!["".to_string(), "hello".to_string(), "".to_string()];
+        let err = CountEqualVec::<2, NonEmptyStringRule>::new(value).unwrap_err();
+        let tree = err.tree();
+        assert_eq!(tree.expected(), Some("count equal to 2"));
+        assert_eq!(tree.actual(), Some("1"));
+        let locations = tree
+            .children()
+            .iter()
+            .map(|child| child.location().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![Some(Location::Index(0)), Some(Location::Index(2))]
+        );
+    }
+
+    #[test]
+    fn count_equal_hash_set() -> anyhow::Result<()> {
+        let value = HashSet::from(["a".to_string(), "".to_string()]);
+        let refined = CountEqualHashSet::<1, NonEmptyStringRule, String>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashSet::from(["a".to_string(), "b".to_string()]);
+        assert!(CountEqualHashSetRule::<1, NonEmptyStringRule, String>::validate(value).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn count_equal_hash_map() -> anyhow::Result<()> {
+        let value = HashMap::from([(1, "a".to_string()), (2, "".to_string())]);
+        let refined = CountEqualHashMap::<1, NonEmptyStringRule, i32>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashMap::from([(1, "a".to_string()), (2, "b".to_string())]);
+        let err = CountEqualHashMapRule::<1, NonEmptyStringRule, i32>::validate(value.clone())
+            .unwrap_err();
+        assert_eq!(err.into_value(), value);
+        Ok(())
+    }
+
+    #[test]
+    fn count_equal_reports_satisfying_items_when_too_many() {
+        let value = vec!["a".to_string(), "".to_string(), "c".to_string()];
+        let err = CountEqualVec::<1, NonEmptyStringRule>::new(value).unwrap_err();
+        let locations = err
+            .tree()
+            .children()
+            .iter()
+            .map(|child| child.location().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![Some(Location::Index(0)), Some(Location::Index(2))]
+        );
+    }
 }
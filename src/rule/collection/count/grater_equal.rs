@@ -58,7 +58,13 @@ pub type CountGreaterEqualStrRule<'a, const N: usize, RULE> =
 #[cfg(test)]
 mod tests {
     use crate::result::Error;
+    use crate::rule::Rule;
+    use crate::rule::{
+        CountGreaterEqualHashMap, CountGreaterEqualHashMapRule, CountGreaterEqualHashSet,
+        CountGreaterEqualHashSetRule,
+    };
     use crate::rule::{CountGreaterEqualVec, NonEmptyStringRule};
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn count_greater_equal_1() -> Result<(), Error<Vec<String>>> {
@@ -77,4 +83,32 @@ mod tests {
         assert!(count_greater_equal_result.is_err());
         Ok(())
     }
+
+    #[test]
+    fn count_greater_equal_hash_set() -> anyhow::Result<()> {
+        let value = HashSet::from(["a".to_string(), "".to_string()]);
+        let refined =
+            CountGreaterEqualHashSet::<1, NonEmptyStringRule, String>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashSet::from(["".to_string(), "".to_string()]);
+        assert!(
+            CountGreaterEqualHashSetRule::<1, NonEmptyStringRule, String>::validate(value).is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn count_greater_equal_hash_map() -> anyhow::Result<()> {
+        let value = HashMap::from([(1, "a".to_string()), (2, "".to_string())]);
+        let refined = CountGreaterEqualHashMap::<1, NonEmptyStringRule, i32>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashMap::from([(1, "".to_string()), (2, "".to_string())]);
+        let err =
+            CountGreaterEqualHashMapRule::<1, NonEmptyStringRule, i32>::validate(value.clone())
+                .unwrap_err();
+        assert_eq!(err.into_value(), value);
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
-use crate::result::Error;
-use crate::rule::{Iterable, Rule};
+use crate::result::{Error, ErrorTree};
+use crate::rule::collection::count::{impl_count_rule_ref, CountedItems};
+use crate::rule::IterableItems;
+use crate::rule::{description, Iterable, Rule, RuleDescription};
 use crate::Refined;
+use serde_json::{json, Value};
 use std::collections::VecDeque;
 
 /// A type that holds a value where the count of items in the collection that satisfy the condition is greater than `N`.
@@ -38,36 +41,39 @@ where
 
 impl<const N: usize, ITERABLE, RULE> Rule for CountGreaterRule<N, RULE, ITERABLE>
 where
-    ITERABLE: Iterable<Item = RULE::Item> + FromIterator<ITERABLE::Item>,
+    ITERABLE: IterableItems<Item = RULE::Item>,
     RULE: Rule,
 {
     type Item = ITERABLE;
     fn validate(target: Self::Item) -> crate::Result<Self::Item> {
-        let mut count = 0;
-        let mut deque = VecDeque::new();
-        for item in target.into_iterator() {
-            match RULE::validate(item) {
-                Ok(item) => {
-                    deque.push_back(item);
-                    count += 1
-                }
-                Err(e) => {
-                    deque.push_back(e.into_value());
-                }
-            }
-        }
-        let target = ITERABLE::from_iter(deque);
-        if count > N {
-            Ok(target)
-        } else {
-            Err(Error::new(
-                target,
-                format!("count is not greater than {}, actual count is {}", N, count),
-            ))
+        let (target, counted) = CountedItems::validate::<RULE, _>(target);
+        match violation::<N>(counted) {
+            None => Ok(target),
+            Some(tree) => Err(Error::from_tree(target, tree)),
         }
     }
 }
 
+impl_count_rule_ref!(CountGreaterRule, violation);
+
+/// Returns the `ErrorTree` of the violation unless the count of the satisfying items is greater than `N`
+fn violation<const N: usize>(counted: CountedItems) -> Option<ErrorTree> {
+    let count = counted.count();
+    if count > N {
+        return None;
+    }
+    Some(
+        ErrorTree::new(format!(
+            "count is not greater than {}, actual count is {}",
+            N, count
+        ))
+        .with_rule("CountGreaterRule")
+        .with_expected(format!("count greater than {N}"))
+        .with_actual(count.to_string())
+        .with_children(counted.unsatisfied),
+    )
+}
+
 /// Rule where the count of items in the `Vec` that satisfy the condition is greater than `N`.
 pub type CountGreaterVecRule<const N: usize, RULE> =
     CountGreaterRule<N, RULE, Vec<<RULE as Rule>::Item>>;
@@ -90,10 +96,36 @@ pub type CountGreaterStringRule<const N: usize, RULE> = CountGreaterRule<N, RULE
 /// Rule where the count of items in the `&'a str` that satisfy the condition is greater than `N`.
 pub type CountGreaterStrRule<'a, const N: usize, RULE> = CountGreaterRule<N, RULE, &'a str>;
 
+macro_rules! impl_rule_description {
+    ($($sequence: ident),+) => {$(
+        impl<const N: usize, RULE, T> RuleDescription for CountGreaterRule<N, RULE, $sequence<T>>
+        where
+            RULE: RuleDescription<Item = T>,
+        {
+            const OPERATOR: Option<&'static str> = Some("for");
+
+            fn description() -> String {
+                format!("for more than {N} items, {}", description::operand::<RULE>("for"))
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "array", "contains": RULE::json_schema(), "minContains": N.saturating_add(1) })
+            }
+        }
+    )+};
+}
+
+impl_rule_description!(Vec, VecDeque);
+
 #[cfg(test)]
 mod tests {
-    use crate::result::Error;
-    use crate::rule::{CountGreater, NonEmptyStringRule};
+    use crate::result::{Error, Location};
+    use crate::rule::Rule;
+    use crate::rule::{CountGreater, CountGreaterVec, NonEmptyStringRule};
+    use crate::rule::{
+        CountGreaterHashMap, CountGreaterHashMapRule, CountGreaterHashSet, CountGreaterHashSetRule,
+    };
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn count_greater_1() -> Result<(), Error<Vec<String>>> {
@@ -112,4 +144,44 @@ mod tests {
         assert!(count_greater_result.is_err());
         Ok(())
     }
+
+    #[test]
+    fn count_greater_hash_set() -> anyhow::Result<()> {
+        let value = HashSet::from(["a".to_string(), "b".to_string()]);
+        let refined = CountGreaterHashSet::<1, NonEmptyStringRule, String>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashSet::from(["a".to_string(), "".to_string()]);
+        assert!(CountGreaterHashSetRule::<1, NonEmptyStringRule, String>::validate(value).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn count_greater_hash_map() -> anyhow::Result<()> {
+        let value = HashMap::from([(1, "a".to_string()), (2, "b".to_string())]);
+        let refined = CountGreaterHashMap::<1, NonEmptyStringRule, i32>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashMap::from([(1, "a".to_string()), (2, "".to_string())]);
+        let err = CountGreaterHashMapRule::<1, NonEmptyStringRule, i32>::validate(value.clone())
+            .unwrap_err();
+        assert_eq!(err.into_value(), value);
+        Ok(())
+    }
+
+    #[test]
+    fn count_greater_reports_unsatisfying_items() {
+        let value = vec!["".to_string(), "b".to_string(), "".to_string()];
+        let err = CountGreaterVec::<1, NonEmptyStringRule>::new(value).unwrap_err();
+        let locations = err
+            .tree()
+            .children()
+            .iter()
+            .map(|child| child.location().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![Some(Location::Index(0)), Some(Location::Index(2))]
+        );
+    }
 }
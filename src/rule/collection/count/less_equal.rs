@@ -55,7 +55,13 @@ pub type CountLessEqualStrRule<'a, const N: usize, RULE> = CountLessEqualRule<N,
 #[cfg(test)]
 mod tests {
     use crate::result::Error;
+    use crate::rule::Rule;
+    use crate::rule::{
+        CountLessEqualHashMap, CountLessEqualHashMapRule, CountLessEqualHashSet,
+        CountLessEqualHashSetRule,
+    };
     use crate::rule::{CountLessEqualVec, NonEmptyStringRule};
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn count_less_equal_1() -> Result<(), Error<Vec<String>>> {
@@ -73,4 +79,30 @@ mod tests {
         assert!(count_less_equal_result.is_err());
         Ok(())
     }
+
+    #[test]
+    fn count_less_equal_hash_set() -> anyhow::Result<()> {
+        let value = HashSet::from(["a".to_string(), "".to_string()]);
+        let refined = CountLessEqualHashSet::<1, NonEmptyStringRule, String>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashSet::from(["a".to_string(), "b".to_string()]);
+        assert!(
+            CountLessEqualHashSetRule::<1, NonEmptyStringRule, String>::validate(value).is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn count_less_equal_hash_map() -> anyhow::Result<()> {
+        let value = HashMap::from([(1, "a".to_string()), (2, "".to_string())]);
+        let refined = CountLessEqualHashMap::<1, NonEmptyStringRule, i32>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashMap::from([(1, "a".to_string()), (2, "b".to_string())]);
+        let err = CountLessEqualHashMapRule::<1, NonEmptyStringRule, i32>::validate(value.clone())
+            .unwrap_err();
+        assert_eq!(err.into_value(), value);
+        Ok(())
+    }
 }
@@ -0,0 +1,190 @@
+use crate::result::{Error, ErrorTree, Location};
+use crate::rule::{Iterable, Rule, RuleDescription};
+use crate::Refined;
+use serde_json::{json, Value};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// A type that holds a value satisfying the `DistinctRule`
+pub type Distinct<ITERABLE> = Refined<DistinctRule<ITERABLE>>;
+
+/// A type that holds a Vec value satisfying the `DistinctRule`
+/// # Example
+/// ```rust
+/// use refined_type::rule::DistinctVec;
+///
+/// let (tags, pushed) = DistinctVec::new(vec!["rust", "web"]).unwrap().push("cli");
+/// assert!(pushed);
+///
+/// let (tags, pushed) = tags.push("rust");
+/// assert!(!pushed);
+/// assert_eq!(tags.into_value(), vec!["rust", "web", "cli"]);
+///
+/// assert!(DistinctVec::new(vec!["rust", "web", "rust"]).is_err());
+/// ```
+pub type DistinctVec<T> = Refined<DistinctVecRule<T>>;
+
+/// A type that holds a VecDeque value satisfying the `DistinctRule`
+pub type DistinctVecDeque<T> = Refined<DistinctVecDequeRule<T>>;
+
+/// A type that holds a String value satisfying the `DistinctRule`
+pub type DistinctString = Refined<DistinctStringRule>;
+
+/// Rule where no two items in the collection are equal
+///
+/// When validation fails, every item equal to an earlier one is reported
+/// as a child of the `ErrorTree`, located by its index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DistinctRule<ITERABLE> {
+    _phantom_data: PhantomData<ITERABLE>,
+}
+
+impl<ITERABLE> Rule for DistinctRule<ITERABLE>
+where
+    ITERABLE: Iterable + FromIterator<ITERABLE::Item>,
+    ITERABLE::Item: Eq + Hash,
+{
+    type Item = ITERABLE;
+
+    fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+        let items = target.into_iterator().collect::<Vec<_>>();
+        let mut first_indexes = HashMap::new();
+        let mut failures = Vec::new();
+        for (i, item) in items.iter().enumerate() {
+            match first_indexes.entry(item) {
+                Entry::Occupied(first) => failures.push(
+                    ErrorTree::new(format!(
+                        "the item at index {} duplicates the item at index {}",
+                        i,
+                        first.get()
+                    ))
+                    .with_location(Location::Index(i)),
+                ),
+                Entry::Vacant(entry) => {
+                    entry.insert(i);
+                }
+            }
+        }
+        let target = items.into_iter().collect();
+
+        if failures.is_empty() {
+            Ok(target)
+        } else {
+            let tree = ErrorTree::new(format!("{} items are duplicated", failures.len()))
+                .with_rule("DistinctRule")
+                .with_expected("distinct items")
+                .with_children(failures);
+            Err(Error::from_tree(target, tree))
+        }
+    }
+}
+
+/// Rule where no two items in the `Vec` are equal
+pub type DistinctVecRule<T> = DistinctRule<Vec<T>>;
+
+/// Rule where no two items in the `VecDeque` are equal
+pub type DistinctVecDequeRule<T> = DistinctRule<VecDeque<T>>;
+
+/// Rule where no two chars in the `String` are equal
+pub type DistinctStringRule = DistinctRule<String>;
+
+macro_rules! impl_rule_description {
+    ($($sequence: ident),+) => {$(
+        impl<T: Eq + Hash> RuleDescription for DistinctRule<$sequence<T>> {
+            fn description() -> String {
+                "distinct items".to_string()
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "array", "uniqueItems": true })
+            }
+        }
+    )+};
+}
+
+impl_rule_description!(Vec, VecDeque);
+
+impl<T: Eq + Hash> DistinctVec<T> {
+    /// Appends `value` unless an equal item is already in the `Vec`
+    ///
+    /// Like `HashSet::insert`, the returned `bool` is `false` when `value` was a duplicate
+    /// and has been dropped.
+    pub fn push(self, value: T) -> (Self, bool) {
+        let mut result = self.into_value();
+        let pushed = !result.contains(&value);
+        if pushed {
+            result.push(value);
+        }
+        (Refined::new_unchecked(result), pushed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::result::Location;
+    use crate::rule::{DistinctString, DistinctVec, DistinctVecRule, RuleDescription};
+    use serde_json::json;
+
+    #[test]
+    fn test_distinct() {
+        let table = vec![
+            (vec![], true),
+            (vec![1], true),
+            (vec![1, 2, 3], true),
+            (vec![1, 2, 1], false),
+            (vec![3, 3], false),
+        ];
+
+        for (value, expected) in table {
+            assert_eq!(
+                DistinctVec::new(value.clone()).is_ok(),
+                expected,
+                "{value:?}"
+            );
+        }
+        assert!(DistinctString::new("日本".to_string()).is_ok());
+        assert!(DistinctString::new("日本日".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_distinct_reports_every_duplicate() {
+        let value = vec![1, 2, 1, 3, 2, 1];
+        let err = DistinctVec::new(value.clone()).unwrap_err();
+        let locations = err
+            .tree()
+            .children()
+            .iter()
+            .map(|child| child.location().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![
+                Some(Location::Index(2)),
+                Some(Location::Index(4)),
+                Some(Location::Index(5))
+            ]
+        );
+        assert_eq!(
+            err.tree().children()[2].message(),
+            "the item at index 5 duplicates the item at index 0"
+        );
+        assert_eq!(err.into_value(), value);
+    }
+
+    #[test]
+    fn test_distinct_push() -> anyhow::Result<()> {
+        let (tags, pushed) = DistinctVec::new(vec!["a"])?.push("b");
+        assert!(pushed);
+        let (tags, pushed) = tags.push("a");
+        assert!(!pushed);
+        assert_eq!(tags.len(), 2);
+        assert!(tags.contains(&"b"));
+        assert_eq!(
+            DistinctVecRule::<i32>::json_schema(),
+            json!({ "type": "array", "uniqueItems": true })
+        );
+        Ok(())
+    }
+}
@@ -43,7 +43,10 @@ pub type ExistsStringRule<RULE> = ExistsRule<RULE, String>;
 #[cfg(test)]
 mod tests {
     use crate::result::Error;
+    use crate::rule::Rule;
     use crate::rule::{Exists, NonEmptyStringRule};
+    use crate::rule::{ExistsHashMap, ExistsHashMapRule, ExistsHashSet, ExistsHashSetRule};
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn exists_1() -> Result<(), Error<Vec<String>>> {
@@ -68,4 +71,28 @@ mod tests {
         assert!(exists_result.is_err());
         Ok(())
     }
+
+    #[test]
+    fn exists_hash_set() -> anyhow::Result<()> {
+        let value = HashSet::from(["a".to_string(), "".to_string()]);
+        let refined = ExistsHashSet::<NonEmptyStringRule>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashSet::from(["".to_string(), "".to_string()]);
+        assert!(ExistsHashSetRule::<NonEmptyStringRule>::validate(value).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn exists_hash_map() -> anyhow::Result<()> {
+        let value = HashMap::from([(1, "a".to_string()), (2, "".to_string())]);
+        let refined = ExistsHashMap::<i32, NonEmptyStringRule>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashMap::from([(1, "".to_string()), (2, "".to_string())]);
+        let err =
+            ExistsHashMapRule::<i32, NonEmptyStringRule>::validate(value.clone()).unwrap_err();
+        assert_eq!(err.into_value(), value);
+        Ok(())
+    }
 }
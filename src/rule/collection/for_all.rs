@@ -42,9 +42,12 @@ pub type ForAllStringRule<RULE> = ForAllRule<RULE, String>;
 
 #[cfg(test)]
 mod tests {
-    use crate::result::Error;
+    use crate::result::{Error, Location};
     use crate::rule::ForAll;
+    use crate::rule::{ForAllHashMap, ForAllHashMapRule, ForAllHashSet, ForAllHashSetRule};
     use crate::rule::{ForAllString, ForAllVec, NonEmptyStringRule, Rule};
+    use std::collections::{HashMap, HashSet};
+    use std::hash::{BuildHasherDefault, DefaultHasher};
 
     #[test]
     fn for_all_1() -> Result<(), Error<Vec<String>>> {
@@ -62,6 +65,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn for_all_reports_every_failing_item() {
+        let value = vec![
+            "".to_string(),
+            "hello".to_string(),
+            "".to_string(),
+            "world".to_string(),
+            "".to_string(),
+        ];
+        let err = ForAllVec::<NonEmptyStringRule>::new(value.clone()).unwrap_err();
+        let locations = err
+            .tree()
+            .children()
+            .iter()
+            .map(|child| child.location().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![
+                Some(Location::Index(0)),
+                Some(Location::Index(2)),
+                Some(Location::Index(4))
+            ]
+        );
+        assert_eq!(err.into_value(), value);
+    }
+
     #[test]
     fn for_all_3() -> Result<(), Error<String>> {
         struct CharRule;
@@ -82,4 +112,53 @@ mod tests {
         assert_eq!(for_all.into_value(), value);
         Ok(())
     }
+
+    #[test]
+    fn for_all_hash_set() -> anyhow::Result<()> {
+        let value = HashSet::from(["a".to_string(), "b".to_string()]);
+        let refined = ForAllHashSet::<NonEmptyStringRule>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashSet::from(["a".to_string(), "".to_string()]);
+        let err = ForAllHashSetRule::<NonEmptyStringRule>::validate(value).unwrap_err();
+        assert_eq!(err.tree().children()[0].location(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn for_all_hash_map() -> anyhow::Result<()> {
+        let value = HashMap::from([(1, "a".to_string()), (2, "b".to_string())]);
+        let refined = ForAllHashMap::<i32, NonEmptyStringRule>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashMap::from([(1, "a".to_string()), (2, "".to_string())]);
+        let err =
+            ForAllHashMapRule::<i32, NonEmptyStringRule>::validate(value.clone()).unwrap_err();
+        assert_eq!(
+            err.tree().children()[0].location(),
+            Some(&Location::Key("2".to_string()))
+        );
+        assert_eq!(err.into_value(), value);
+        Ok(())
+    }
+
+    #[test]
+    fn for_all_custom_hasher() -> anyhow::Result<()> {
+        type FixedState = BuildHasherDefault<DefaultHasher>;
+
+        let value: HashSet<String, FixedState> =
+            ["a".to_string(), "b".to_string()].into_iter().collect();
+        let refined = ForAll::<NonEmptyStringRule, _>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value: HashMap<i32, String, FixedState> = [(1, "a".to_string()), (2, "".to_string())]
+            .into_iter()
+            .collect();
+        let err = ForAll::<NonEmptyStringRule, _>::new(value).unwrap_err();
+        assert_eq!(
+            err.tree().children()[0].location(),
+            Some(&Location::Key("2".to_string()))
+        );
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "unicode")]
+use crate::rule::Graphemes;
 use crate::rule::{IndexRule, Rule};
 use crate::Refined;
 use std::collections::VecDeque;
@@ -14,6 +16,10 @@ pub type HeadVecDeque<RULE> = Refined<HeadVecDequeRule<RULE>>;
 /// A type that holds a String value satisfying the `HeadRule`
 pub type HeadString<RULE> = Refined<HeadStringRule<RULE>>;
 
+/// A type that holds a String value whose first grapheme satisfies the `HeadRule`
+#[cfg(feature = "unicode")]
+pub type HeadGraphemes<RULE> = Refined<HeadGraphemesRule<RULE>>;
+
 /// Rule where the first element satisfies the condition
 pub type HeadRule<RULE, ITERABLE> = IndexRule<0, RULE, ITERABLE>;
 
@@ -26,10 +32,15 @@ pub type HeadVecDequeRule<RULE> = HeadRule<RULE, VecDeque<<RULE as Rule>::Item>>
 /// Rule where the first element in the `String` satisfies the condition
 pub type HeadStringRule<RULE> = HeadRule<RULE, String>;
 
+/// Rule where the first grapheme in the `String` satisfies the condition
+#[cfg(feature = "unicode")]
+pub type HeadGraphemesRule<RULE> = HeadRule<RULE, Graphemes>;
+
 #[cfg(test)]
 mod tests {
     use crate::result::Error;
-    use crate::rule::{HeadVec, NonEmptyStringRule};
+    use crate::rule::collection::fixture::UppercaseRule;
+    use crate::rule::{HeadString, HeadVec, NonEmptyStringRule};
 
     #[test]
     fn head_valid() -> Result<(), Error<Vec<String>>> {
@@ -61,4 +72,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn head_string_multibyte() {
+        let table = vec![
+            ("Ωmega", true),
+            ("日本", false),
+            ("😀A", false),
+            ("", false),
+        ];
+
+        for (value, expected) in table {
+            let head = HeadString::<UppercaseRule>::new(value.to_string());
+            assert_eq!(head.is_ok(), expected, "{value}");
+        }
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn head_graphemes() -> anyhow::Result<()> {
+        use crate::rule::HeadGraphemes;
+
+        let head = HeadGraphemes::<NonEmptyStringRule>::new("🇯🇵".to_string())?;
+        assert_eq!(head.into_value(), "🇯🇵");
+        assert!(HeadGraphemes::<NonEmptyStringRule>::new("".to_string()).is_err());
+        Ok(())
+    }
 }
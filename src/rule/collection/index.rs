@@ -1,9 +1,16 @@
-use crate::rule::Rule;
+use crate::result::{Error, ErrorTree, Location};
+#[cfg(feature = "unicode")]
+use crate::rule::Graphemes;
+use crate::rule::{description, Rule, RuleBorrow, RuleDescription, RuleRef};
 use crate::Refined;
+use serde_json::{json, Value};
 use std::collections::VecDeque;
 
 pub type Index<const INDEX: usize, RULE, ITERABLE> = Refined<IndexRule<INDEX, RULE, ITERABLE>>;
 pub type IndexVec<const INDEX: usize, RULE> = Refined<IndexRuleVec<INDEX, RULE>>;
+pub type IndexString<const INDEX: usize, RULE> = Refined<IndexRuleString<INDEX, RULE>>;
+#[cfg(feature = "unicode")]
+pub type IndexGraphemes<const INDEX: usize, RULE> = Refined<IndexRuleGraphemes<INDEX, RULE>>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IndexRule<const INDEX: usize, RULE, ITERABLE>
@@ -21,12 +28,11 @@ where
 {
     type Item = Vec<ITEM>;
 
-    fn validate(target: Self::Item) -> Result<Self::Item, crate::result::Error<Self::Item>> {
+    fn validate(target: Self::Item) -> Result<Self::Item, Error<Self::Item>> {
+        const { crate::rule::refuse_normalized::<RULE>() };
         if INDEX >= target.len() {
-            return Err(crate::result::Error::new(
-                target,
-                format!("index {} is out of bounds", INDEX),
-            ));
+            let tree = out_of_bounds::<INDEX>("an item", target.len());
+            return Err(Error::from_tree(target, tree));
         }
         let mut target = target;
         match RULE::validate(target.remove(INDEX)) {
@@ -35,11 +41,10 @@ where
                 Ok(target)
             }
             Err(err) => {
-                target.insert(INDEX, err.into_value());
-                Err(crate::result::Error::new(
-                    target,
-                    format!("the item at index {} does not satisfy the condition", INDEX),
-                ))
+                let (item, item_tree) = err.into_parts();
+                target.insert(INDEX, item);
+                let tree = unsatisfied::<INDEX>("item", item_tree);
+                Err(Error::from_tree(target, tree))
             }
         }
     }
@@ -54,12 +59,11 @@ where
 {
     type Item = VecDeque<ITEM>;
 
-    fn validate(target: Self::Item) -> Result<Self::Item, crate::result::Error<Self::Item>> {
+    fn validate(target: Self::Item) -> Result<Self::Item, Error<Self::Item>> {
+        const { crate::rule::refuse_normalized::<RULE>() };
         if INDEX >= target.len() {
-            return Err(crate::result::Error::new(
-                target,
-                format!("index {} is out of bounds", INDEX),
-            ));
+            let tree = out_of_bounds::<INDEX>("an item", target.len());
+            return Err(Error::from_tree(target, tree));
         }
         let mut target = target;
         match RULE::validate(
@@ -72,16 +76,18 @@ where
                 Ok(target)
             }
             Err(err) => {
-                target.insert(INDEX, err.into_value());
-                Err(crate::result::Error::new(
-                    target,
-                    format!("the item at index {} does not satisfy the condition", INDEX),
-                ))
+                let (item, item_tree) = err.into_parts();
+                target.insert(INDEX, item);
+                let tree = unsatisfied::<INDEX>("item", item_tree);
+                Err(Error::from_tree(target, tree))
             }
         }
     }
 }
 
+/// Rule where the `char` at `INDEX` in the `String` satisfies the condition
+///
+/// `INDEX` counts chars rather than bytes, so multibyte text is addressed as it is read.
 pub type IndexRuleString<const INDEX: usize, RULE> = IndexRule<INDEX, RULE, String>;
 
 impl<const INDEX: usize, RULE> Rule for IndexRuleString<INDEX, RULE>
@@ -90,33 +96,198 @@ where
 {
     type Item = String;
 
-    fn validate(target: Self::Item) -> Result<Self::Item, crate::result::Error<Self::Item>> {
-        if INDEX >= target.len() {
-            return Err(crate::result::Error::new(
-                target,
-                format!("index {} is out of bounds", INDEX),
-            ));
+    fn validate(target: Self::Item) -> Result<Self::Item, Error<Self::Item>> {
+        const { crate::rule::refuse_normalized::<RULE>() };
+        let Some((start, c)) = target.char_indices().nth(INDEX) else {
+            let tree = out_of_bounds::<INDEX>("a char", target.chars().count());
+            return Err(Error::from_tree(target, tree));
+        };
+        match RULE::validate(c) {
+            Ok(validated) if validated == c => Ok(target),
+            Ok(validated) => {
+                let mut target = target;
+                let mut buffer = [0; 4];
+                target.replace_range(
+                    start..start + c.len_utf8(),
+                    validated.encode_utf8(&mut buffer),
+                );
+                Ok(target)
+            }
+            Err(err) => {
+                let tree = unsatisfied::<INDEX>("char", err.into_tree());
+                Err(Error::from_tree(target, tree))
+            }
         }
-        let mut target = target;
-        match RULE::validate(target.remove(INDEX)) {
-            Ok(validated_item) => {
-                target.insert(INDEX, validated_item);
+    }
+}
+
+/// Rule where the grapheme at `INDEX` in the `String` satisfies the condition
+///
+/// A grapheme is what a user perceives as a single character, e.g. a flag emoji made of two chars.
+#[cfg(feature = "unicode")]
+pub type IndexRuleGraphemes<const INDEX: usize, RULE> = IndexRule<INDEX, RULE, Graphemes>;
+
+#[cfg(feature = "unicode")]
+impl<const INDEX: usize, RULE> Rule for IndexRuleGraphemes<INDEX, RULE>
+where
+    RULE: Rule<Item = String>,
+{
+    type Item = String;
+
+    fn validate(target: Self::Item) -> Result<Self::Item, Error<Self::Item>> {
+        const { crate::rule::refuse_normalized::<RULE>() };
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let Some((start, grapheme)) = target.grapheme_indices(true).nth(INDEX) else {
+            let tree = out_of_bounds::<INDEX>("a grapheme", target.graphemes(true).count());
+            return Err(Error::from_tree(target, tree));
+        };
+        let range = start..start + grapheme.len();
+        match RULE::validate(grapheme.to_string()) {
+            Ok(validated) if validated == target[range.clone()] => Ok(target),
+            Ok(validated) => {
+                let mut target = target;
+                target.replace_range(range, &validated);
                 Ok(target)
             }
             Err(err) => {
-                target.insert(INDEX, err.into_value());
-                Err(crate::result::Error::new(
-                    target,
-                    format!("the item at index {} does not satisfy the condition", INDEX),
-                ))
+                let tree = unsatisfied::<INDEX>("grapheme", err.into_tree());
+                Err(Error::from_tree(target, tree))
+            }
+        }
+    }
+}
+
+macro_rules! impl_rule_ref {
+    ($($sequence: ident),+) => {$(
+        impl<const INDEX: usize, RULE, ITEM> RuleRef for IndexRule<INDEX, RULE, $sequence<ITEM>>
+        where
+            RULE: RuleRef<Item = ITEM>,
+        {
+            fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+                validate_item::<INDEX, RULE>("item", target.get(INDEX), target.len())
             }
         }
+    )+};
+}
+
+impl_rule_ref!(Vec, VecDeque);
+
+impl<const INDEX: usize, RULE, ITEM> RuleBorrow<[ITEM]> for IndexRuleVec<INDEX, RULE>
+where
+    RULE: RuleRef<Item = ITEM>,
+{
+    fn validate_borrow(target: &[ITEM]) -> Result<(), Box<ErrorTree>> {
+        validate_item::<INDEX, RULE>("item", target.get(INDEX), target.len())
+    }
+}
+
+impl<const INDEX: usize, RULE> RuleRef for IndexRuleString<INDEX, RULE>
+where
+    RULE: RuleRef<Item = char>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        Self::validate_borrow(target.as_str())
+    }
+}
+
+impl<const INDEX: usize, RULE> RuleBorrow<str> for IndexRuleString<INDEX, RULE>
+where
+    RULE: RuleRef<Item = char>,
+{
+    fn validate_borrow(target: &str) -> Result<(), Box<ErrorTree>> {
+        let c = target.chars().nth(INDEX);
+        validate_item::<INDEX, RULE>("char", c.as_ref(), target.chars().count())
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<const INDEX: usize, RULE> RuleRef for IndexRuleGraphemes<INDEX, RULE>
+where
+    RULE: Rule<Item = String> + RuleBorrow<str>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        Self::validate_borrow(target.as_str())
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<const INDEX: usize, RULE> RuleBorrow<str> for IndexRuleGraphemes<INDEX, RULE>
+where
+    RULE: Rule<Item = String> + RuleBorrow<str>,
+{
+    fn validate_borrow(target: &str) -> Result<(), Box<ErrorTree>> {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let Some(grapheme) = target.graphemes(true).nth(INDEX) else {
+            let tree = out_of_bounds::<INDEX>("a grapheme", target.graphemes(true).count());
+            return Err(Box::new(tree));
+        };
+        RULE::validate_borrow(grapheme)
+            .map_err(|tree| Box::new(unsatisfied::<INDEX>("grapheme", *tree)))
     }
 }
 
+fn validate_item<const INDEX: usize, RULE: RuleRef>(
+    unit: &str,
+    item: Option<&RULE::Item>,
+    length: usize,
+) -> Result<(), Box<ErrorTree>> {
+    let Some(item) = item else {
+        let article = if unit == "item" { "an" } else { "a" };
+        let tree = out_of_bounds::<INDEX>(&format!("{article} {unit}"), length);
+        return Err(Box::new(tree));
+    };
+    RULE::validate_ref(item).map_err(|tree| Box::new(unsatisfied::<INDEX>(unit, *tree)))
+}
+
+fn out_of_bounds<const INDEX: usize>(expected: &str, length: usize) -> ErrorTree {
+    ErrorTree::new(format!("index {} is out of bounds", INDEX))
+        .with_rule("IndexRule")
+        .with_expected(format!("{expected} at index {INDEX}"))
+        .with_actual(format!("length {length}"))
+}
+
+fn unsatisfied<const INDEX: usize>(unit: &str, tree: ErrorTree) -> ErrorTree {
+    ErrorTree::new(format!(
+        "the {unit} at index {} does not satisfy the condition",
+        INDEX
+    ))
+    .with_rule("IndexRule")
+    .with_children([tree.with_location(Location::Index(INDEX))])
+}
+
+macro_rules! impl_rule_description {
+    ($($sequence: ident),+) => {$(
+        impl<const INDEX: usize, RULE, ITEM> RuleDescription for IndexRule<INDEX, RULE, $sequence<ITEM>>
+        where
+            RULE: RuleDescription<Item = ITEM>,
+        {
+            const OPERATOR: Option<&'static str> = Some("for");
+
+            fn description() -> String {
+                format!(
+                    "for the item at index {INDEX}, {}",
+                    description::operand::<RULE>("for")
+                )
+            }
+
+            fn json_schema() -> Value {
+                let mut prefix_items = vec![json!({}); INDEX];
+                prefix_items.push(RULE::json_schema());
+                json!({ "type": "array", "minItems": INDEX + 1, "prefixItems": prefix_items })
+            }
+        }
+    )+};
+}
+
+impl_rule_description!(Vec, VecDeque);
+
 #[cfg(test)]
 mod tests {
-    use crate::rule::{IndexVec, NonEmptyStringRule};
+    use crate::result::{Error, Location};
+    use crate::rule::collection::fixture::UppercaseRule;
+    use crate::rule::{IndexString, IndexVec, NonEmptyStringRule, Rule};
 
     #[test]
     fn test_index_0_non_empty_string() -> anyhow::Result<()> {
@@ -152,10 +323,79 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_index_1_non_empty_string_err_tree() {
+        let value = vec!["good morning".to_string(), "".to_string()];
+        let err = IndexVec::<1, NonEmptyStringRule>::new(value).unwrap_err();
+        let tree = err.tree();
+        assert_eq!(tree.rule(), Some("IndexRule"));
+        assert_eq!(tree.children().len(), 1);
+        assert_eq!(tree.children()[0].location(), Some(&Location::Index(1)));
+    }
+
     #[test]
     fn test_index_2_non_empty_string_out_of_bounds() {
         let value = vec!["good morning".to_string(), "hello".to_string()];
         let refined = IndexVec::<2, NonEmptyStringRule>::new(value);
         assert!(refined.is_err());
     }
+
+    struct KanjiRule;
+
+    impl Rule for KanjiRule {
+        type Item = char;
+
+        fn validate(_: Self::Item) -> Result<Self::Item, Error<Self::Item>> {
+            Ok('字')
+        }
+    }
+
+    #[test]
+    fn test_index_string_counts_chars() {
+        let table = vec![
+            ("aB", true),
+            ("日B", true),
+            ("😀Ω", true),
+            ("Bb", false),
+            ("日本", false),
+            ("😀", false),
+            ("", false),
+        ];
+
+        for (value, expected) in table {
+            let refined = IndexString::<1, UppercaseRule>::new(value.to_string());
+            assert_eq!(refined.is_ok(), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_index_string_keeps_value_on_error() {
+        let err = IndexString::<1, UppercaseRule>::new("日本語".to_string()).unwrap_err();
+        assert_eq!(
+            err.tree().children()[0].location(),
+            Some(&Location::Index(1))
+        );
+        assert_eq!(err.into_value(), "日本語");
+
+        let err = IndexString::<3, UppercaseRule>::new("日本語".to_string()).unwrap_err();
+        assert_eq!(err.tree().actual(), Some("length 3"));
+    }
+
+    #[test]
+    fn test_index_string_replaces_validated_char() -> anyhow::Result<()> {
+        let refined = IndexString::<1, KanjiRule>::new("aéz".to_string())?;
+        assert_eq!(refined.into_value(), "a字z");
+        Ok(())
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_index_graphemes() -> anyhow::Result<()> {
+        use crate::rule::{IndexGraphemes, NonEmptyStringRule};
+
+        let refined = IndexGraphemes::<1, NonEmptyStringRule>::new("🇯🇵か\u{3099}".to_string())?;
+        assert_eq!(refined.into_value(), "🇯🇵か\u{3099}");
+        assert!(IndexGraphemes::<2, NonEmptyStringRule>::new("🇯🇵か\u{3099}".to_string()).is_err());
+        Ok(())
+    }
 }
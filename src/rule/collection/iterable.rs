@@ -1,5 +1,11 @@
-use std::collections::VecDeque;
+use crate::result::{ErrorTree, Location};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
 
+/// This is a `trait` for the collections whose items are validated by the collection rules, e.g. `ForAllRule`
+///
+/// The items of a map are its values, and its keys are kept as they are.
 pub trait Iterable {
     type Item;
 
@@ -9,7 +15,186 @@ pub trait Iterable {
     fn length(&self) -> usize;
 }
 
-impl<T> Iterable for Vec<T> {
+/// This is a marker `trait` for the collections whose iteration order is the order of their items
+///
+/// The rules comparing each item with the previous one, e.g. `SortedRule`, accept only these collections.
+/// A `HashSet` or `HashMap` is iterated in an arbitrary order, so it is not one of them.
+pub trait OrderedIterable: Iterable {}
+
+impl<T> OrderedIterable for Vec<T> {}
+impl<T> OrderedIterable for VecDeque<T> {}
+impl<T> OrderedIterable for LinkedList<T> {}
+impl<T> OrderedIterable for Box<[T]> {}
+impl<T, const N: usize> OrderedIterable for [T; N] {}
+impl OrderedIterable for String {}
+impl OrderedIterable for &str {}
+
+/// The item-wise access of the std collections that the collection rules are built on
+///
+/// It is kept apart from `Iterable` so that implementing `Iterable` stays as it is.
+pub(crate) trait IterableItems: Iterable {
+    /// Rebuilds the collection with each item replaced by `f(position, item)`, in the order of `into_iterator`
+    ///
+    /// A `&str` cannot own new chars, so it is returned as it is after `f` has seen every char.
+    fn map_items<F>(self, f: F) -> Self
+    where
+        F: FnMut(ItemPosition<'_>, Self::Item) -> Self::Item;
+
+    /// Calls `f(position, &item)` for each item without taking the collection, in the order of `into_iterator`
+    ///
+    /// It is how the collection rules implement `RuleRef`.
+    fn visit_items<F>(&self, f: F)
+    where
+        F: FnMut(ItemPosition<'_>, &Self::Item);
+}
+
+/// The position of an item visited by a collection rule
+///
+/// An item of a sequence is located by its index and a value of a map by its key.
+/// The items of a set have no stable order, so they have no location.
+#[derive(Debug, Clone, Copy)]
+pub struct ItemPosition<'a> {
+    index: usize,
+    kind: PositionKind<'a>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum PositionKind<'a> {
+    Sequence,
+    Set,
+    Map(&'a dyn Debug),
+}
+
+impl<'a> ItemPosition<'a> {
+    pub fn sequence(index: usize) -> Self {
+        Self {
+            index,
+            kind: PositionKind::Sequence,
+        }
+    }
+
+    pub fn set(index: usize) -> Self {
+        Self {
+            index,
+            kind: PositionKind::Set,
+        }
+    }
+
+    pub fn map(index: usize, key: &'a dyn Debug) -> Self {
+        Self {
+            index,
+            kind: PositionKind::Map(key),
+        }
+    }
+
+    /// The number of items visited before this one, which only identifies the item of a sequence
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The `Location` of the item, `None` for an item of a set
+    pub fn location(&self) -> Option<Location> {
+        match self.kind {
+            PositionKind::Sequence => Some(Location::Index(self.index)),
+            PositionKind::Set => None,
+            PositionKind::Map(key) => Some(Location::Key(format!("{key:?}"))),
+        }
+    }
+
+    /// Attaches the location of the item to `tree`
+    pub(crate) fn locate(&self, tree: ErrorTree) -> ErrorTree {
+        match self.location() {
+            Some(location) => tree.with_location(location),
+            None => tree,
+        }
+    }
+
+    /// Describes the item in a message, e.g. `the item at index 2`
+    pub(crate) fn describe(&self) -> String {
+        match self.kind {
+            PositionKind::Sequence => format!("the item at index {}", self.index),
+            PositionKind::Set => "an item".to_string(),
+            PositionKind::Map(key) => format!("the value at key {key:?}"),
+        }
+    }
+}
+
+macro_rules! impl_iterable_sequence {
+    ($($sequence:ident),+) => {$(
+        impl<T> Iterable for $sequence<T> {
+            type Item = T;
+
+            fn into_iterator<'a>(self) -> Box<dyn DoubleEndedIterator<Item = Self::Item> + 'a>
+            where
+                Self: 'a,
+            {
+                Box::new(self.into_iter())
+            }
+
+            fn length(&self) -> usize {
+                self.len()
+            }
+        }
+
+        impl<T> IterableItems for $sequence<T> {
+            fn map_items<F>(self, mut f: F) -> Self
+            where
+                F: FnMut(ItemPosition<'_>, Self::Item) -> Self::Item,
+            {
+                self.into_iter()
+                    .enumerate()
+                    .map(|(i, item)| f(ItemPosition::sequence(i), item))
+                    .collect()
+            }
+
+            fn visit_items<F>(&self, mut f: F)
+            where
+                F: FnMut(ItemPosition<'_>, &Self::Item),
+            {
+                for (i, item) in self.iter().enumerate() {
+                    f(ItemPosition::sequence(i), item);
+                }
+            }
+        }
+    )+};
+}
+
+impl_iterable_sequence!(Vec, VecDeque, LinkedList);
+
+impl<T> Iterable for Box<[T]> {
+    type Item = T;
+
+    fn into_iterator<'a>(self) -> Box<dyn DoubleEndedIterator<Item = Self::Item> + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.into_vec().into_iter())
+    }
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> IterableItems for Box<[T]> {
+    fn map_items<F>(self, f: F) -> Self
+    where
+        F: FnMut(ItemPosition<'_>, Self::Item) -> Self::Item,
+    {
+        self.into_vec().map_items(f).into_boxed_slice()
+    }
+
+    fn visit_items<F>(&self, mut f: F)
+    where
+        F: FnMut(ItemPosition<'_>, &Self::Item),
+    {
+        for (i, item) in self.iter().enumerate() {
+            f(ItemPosition::sequence(i), item);
+        }
+    }
+}
+
+impl<T, const N: usize> Iterable for [T; N] {
     type Item = T;
 
     fn into_iterator<'a>(self) -> Box<dyn DoubleEndedIterator<Item = Self::Item> + 'a>
@@ -19,12 +204,71 @@ impl<T> Iterable for Vec<T> {
         Box::new(self.into_iter())
     }
 
+    fn length(&self) -> usize {
+        N
+    }
+}
+
+impl<T, const N: usize> IterableItems for [T; N] {
+    fn map_items<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(ItemPosition<'_>, Self::Item) -> Self::Item,
+    {
+        let mut i = 0;
+        self.map(|item| {
+            let item = f(ItemPosition::sequence(i), item);
+            i += 1;
+            item
+        })
+    }
+
+    fn visit_items<F>(&self, mut f: F)
+    where
+        F: FnMut(ItemPosition<'_>, &Self::Item),
+    {
+        for (i, item) in self.iter().enumerate() {
+            f(ItemPosition::sequence(i), item);
+        }
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher + Default> Iterable for HashSet<T, S> {
+    type Item = T;
+
+    fn into_iterator<'a>(self) -> Box<dyn DoubleEndedIterator<Item = Self::Item> + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.into_iter().collect::<Vec<_>>().into_iter())
+    }
+
     fn length(&self) -> usize {
         self.len()
     }
 }
 
-impl<T> Iterable for VecDeque<T> {
+impl<T: Eq + Hash, S: BuildHasher + Default> IterableItems for HashSet<T, S> {
+    fn map_items<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(ItemPosition<'_>, Self::Item) -> Self::Item,
+    {
+        self.into_iter()
+            .enumerate()
+            .map(|(i, item)| f(ItemPosition::set(i), item))
+            .collect()
+    }
+
+    fn visit_items<F>(&self, mut f: F)
+    where
+        F: FnMut(ItemPosition<'_>, &Self::Item),
+    {
+        for (i, item) in self.iter().enumerate() {
+            f(ItemPosition::set(i), item);
+        }
+    }
+}
+
+impl<T: Ord> Iterable for BTreeSet<T> {
     type Item = T;
 
     fn into_iterator<'a>(self) -> Box<dyn DoubleEndedIterator<Item = Self::Item> + 'a>
@@ -39,6 +283,105 @@ impl<T> Iterable for VecDeque<T> {
     }
 }
 
+impl<T: Ord> IterableItems for BTreeSet<T> {
+    fn map_items<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(ItemPosition<'_>, Self::Item) -> Self::Item,
+    {
+        self.into_iter()
+            .enumerate()
+            .map(|(i, item)| f(ItemPosition::set(i), item))
+            .collect()
+    }
+
+    fn visit_items<F>(&self, mut f: F)
+    where
+        F: FnMut(ItemPosition<'_>, &Self::Item),
+    {
+        for (i, item) in self.iter().enumerate() {
+            f(ItemPosition::set(i), item);
+        }
+    }
+}
+
+impl<K: Eq + Hash + Debug, V, S: BuildHasher + Default> Iterable for HashMap<K, V, S> {
+    type Item = V;
+
+    fn into_iterator<'a>(self) -> Box<dyn DoubleEndedIterator<Item = Self::Item> + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.into_values().collect::<Vec<_>>().into_iter())
+    }
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K: Eq + Hash + Debug, V, S: BuildHasher + Default> IterableItems for HashMap<K, V, S> {
+    fn map_items<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(ItemPosition<'_>, Self::Item) -> Self::Item,
+    {
+        self.into_iter()
+            .enumerate()
+            .map(|(i, (key, value))| {
+                let value = f(ItemPosition::map(i, &key), value);
+                (key, value)
+            })
+            .collect()
+    }
+
+    fn visit_items<F>(&self, mut f: F)
+    where
+        F: FnMut(ItemPosition<'_>, &Self::Item),
+    {
+        for (i, (key, value)) in self.iter().enumerate() {
+            f(ItemPosition::map(i, key), value);
+        }
+    }
+}
+
+impl<K: Ord + Debug, V> Iterable for BTreeMap<K, V> {
+    type Item = V;
+
+    fn into_iterator<'a>(self) -> Box<dyn DoubleEndedIterator<Item = Self::Item> + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.into_values())
+    }
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K: Ord + Debug, V> IterableItems for BTreeMap<K, V> {
+    fn map_items<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(ItemPosition<'_>, Self::Item) -> Self::Item,
+    {
+        self.into_iter()
+            .enumerate()
+            .map(|(i, (key, value))| {
+                let value = f(ItemPosition::map(i, &key), value);
+                (key, value)
+            })
+            .collect()
+    }
+
+    fn visit_items<F>(&self, mut f: F)
+    where
+        F: FnMut(ItemPosition<'_>, &Self::Item),
+    {
+        for (i, (key, value)) in self.iter().enumerate() {
+            f(ItemPosition::map(i, key), value);
+        }
+    }
+}
+
 impl Iterable for String {
     type Item = char;
 
@@ -54,7 +397,28 @@ impl Iterable for String {
     }
 }
 
-impl<'a> Iterable for &'a str {
+impl IterableItems for String {
+    fn map_items<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(ItemPosition<'_>, Self::Item) -> Self::Item,
+    {
+        self.chars()
+            .enumerate()
+            .map(|(i, item)| f(ItemPosition::sequence(i), item))
+            .collect()
+    }
+
+    fn visit_items<F>(&self, mut f: F)
+    where
+        F: FnMut(ItemPosition<'_>, &Self::Item),
+    {
+        for (i, item) in self.chars().enumerate() {
+            f(ItemPosition::sequence(i), &item);
+        }
+    }
+}
+
+impl Iterable for &str {
     type Item = char;
 
     fn into_iterator<'b>(self) -> Box<dyn DoubleEndedIterator<Item = Self::Item> + 'b>
@@ -68,3 +432,124 @@ impl<'a> Iterable for &'a str {
         self.len()
     }
 }
+
+impl IterableItems for &str {
+    fn map_items<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(ItemPosition<'_>, Self::Item) -> Self::Item,
+    {
+        for (i, item) in self.chars().enumerate() {
+            f(ItemPosition::sequence(i), item);
+        }
+        self
+    }
+
+    fn visit_items<F>(&self, mut f: F)
+    where
+        F: FnMut(ItemPosition<'_>, &Self::Item),
+    {
+        for (i, item) in self.chars().enumerate() {
+            f(ItemPosition::sequence(i), &item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::result::Location;
+    use crate::rule::collection::iterable::IterableItems;
+    use crate::rule::Iterable;
+    use std::collections::{BTreeMap, HashMap, HashSet, LinkedList};
+
+    #[test]
+    fn test_map_items_keeps_keys() {
+        let map = HashMap::from([("a", 1), ("b", 2)]);
+        assert_eq!(
+            map.map_items(|_, value| value * 10),
+            HashMap::from([("a", 10), ("b", 20)])
+        );
+
+        let map = BTreeMap::from([(2, 'b'), (1, 'a')]);
+        let mut indexes = Vec::new();
+        let map = map.map_items(|position, value| {
+            indexes.push((position.location(), value));
+            value.to_ascii_uppercase()
+        });
+        assert_eq!(
+            indexes,
+            vec![
+                (Some(Location::Key("1".to_string())), 'a'),
+                (Some(Location::Key("2".to_string())), 'b')
+            ]
+        );
+        assert_eq!(map, BTreeMap::from([(1, 'A'), (2, 'B')]));
+    }
+
+    #[test]
+    fn test_map_items_keeps_shape() {
+        assert_eq!(
+            [1, 2, 3].map_items(|position, item| item + position.index()),
+            [1, 3, 5]
+        );
+        let boxed: Box<[i32]> = Box::new([1, 2]);
+        assert_eq!(&*boxed.map_items(|_, item| -item), &[-1, -2]);
+        let list = LinkedList::from([1, 2]);
+        assert_eq!(
+            list.map_items(|position, item| item * position.index()),
+            LinkedList::from([0, 2])
+        );
+        assert_eq!("abc".map_items(|_, _| 'x'), "abc");
+        assert_eq!(
+            "abc".to_string().map_items(|_, c| c.to_ascii_uppercase()),
+            "ABC"
+        );
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        assert_eq!(
+            [1, 2, 3].into_iterator().rev().collect::<Vec<_>>(),
+            [3, 2, 1]
+        );
+        let map = BTreeMap::from([("a", 1), ("b", 2)]);
+        assert_eq!(map.length(), 2);
+        assert_eq!(map.into_iterator().collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn test_set_items_have_no_location() {
+        let set = HashSet::from([1, 2]);
+        let mut locations = Vec::new();
+        set.map_items(|position, item| {
+            locations.push(position.location());
+            item
+        });
+        assert_eq!(locations, vec![None, None]);
+    }
+
+    #[test]
+    fn test_implement_iterable() {
+        struct Digits(u32);
+
+        impl Iterable for Digits {
+            type Item = u32;
+
+            fn into_iterator<'a>(self) -> Box<dyn DoubleEndedIterator<Item = Self::Item> + 'a>
+            where
+                Self: 'a,
+            {
+                let digits = self.0.to_string();
+                let digits = digits.chars().filter_map(|c| c.to_digit(10));
+                Box::new(digits.collect::<Vec<_>>().into_iter())
+            }
+
+            fn length(&self) -> usize {
+                self.0.to_string().len()
+            }
+        }
+
+        let digits = Digits(1203);
+        assert_eq!(digits.length(), 4);
+        assert_eq!(digits.into_iterator().collect::<Vec<_>>(), [1, 2, 0, 3]);
+    }
+}
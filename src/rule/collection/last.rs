@@ -1,7 +1,13 @@
 use std::collections::VecDeque;
 
+#[cfg(feature = "unicode")]
+use crate::result::{Error, ErrorTree, Location};
 use crate::rule::{IndexRule, ReverseRule, Rule};
+#[cfg(feature = "unicode")]
+use crate::rule::{RuleBorrow, RuleRef};
 use crate::Refined;
+#[cfg(feature = "unicode")]
+use std::marker::PhantomData;
 
 /// A type that holds a value satisfying the `LastRule`
 pub type Last<RULE, ITERABLE> = Refined<LastRule<RULE, ITERABLE>>;
@@ -15,6 +21,10 @@ pub type LastVecDeque<RULE> = Refined<LastVecDequeRule<RULE>>;
 /// A type that holds a String value satisfying the `LastRule`
 pub type LastString<RULE> = Refined<LastStringRule<RULE>>;
 
+/// A type that holds a String value whose last grapheme satisfies the `LastGraphemesRule`
+#[cfg(feature = "unicode")]
+pub type LastGraphemes<RULE> = Refined<LastGraphemesRule<RULE>>;
+
 /// Rule where the last element satisfies the condition
 pub type LastRule<RULE, ITERABLE> = ReverseRule<IndexRule<0, RULE, ITERABLE>>;
 
@@ -27,10 +37,94 @@ pub type LastVecDequeRule<RULE> = LastRule<RULE, VecDeque<<RULE as Rule>::Item>>
 /// Rule where the last element in the `String` satisfies the condition
 pub type LastStringRule<RULE> = LastRule<RULE, String>;
 
+/// Rule where the last grapheme in the `String` satisfies the condition
+///
+/// Unlike the other `Last` rules, it is not a `ReverseRule`,
+/// since reversing the chars of a `String` would break its graphemes apart.
+#[cfg(feature = "unicode")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LastGraphemesRule<RULE> {
+    _phantom_data: PhantomData<RULE>,
+}
+
+#[cfg(feature = "unicode")]
+impl<RULE> Rule for LastGraphemesRule<RULE>
+where
+    RULE: Rule<Item = String>,
+{
+    type Item = String;
+
+    fn validate(target: Self::Item) -> Result<Self::Item, Error<Self::Item>> {
+        const { crate::rule::refuse_normalized::<RULE>() };
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let Some((start, grapheme)) = target.grapheme_indices(true).next_back() else {
+            return Err(Error::from_tree(target, no_grapheme()));
+        };
+        match RULE::validate(grapheme.to_string()) {
+            Ok(validated) if validated == target[start..] => Ok(target),
+            Ok(validated) => {
+                let mut target = target;
+                target.replace_range(start.., &validated);
+                Ok(target)
+            }
+            Err(err) => {
+                let tree = unsatisfied(&target, err.into_tree());
+                Err(Error::from_tree(target, tree))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<RULE> RuleRef for LastGraphemesRule<RULE>
+where
+    RULE: Rule<Item = String> + RuleBorrow<str>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        Self::validate_borrow(target.as_str())
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<RULE> RuleBorrow<str> for LastGraphemesRule<RULE>
+where
+    RULE: Rule<Item = String> + RuleBorrow<str>,
+{
+    fn validate_borrow(target: &str) -> Result<(), Box<ErrorTree>> {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let Some(grapheme) = target.graphemes(true).next_back() else {
+            return Err(Box::new(no_grapheme()));
+        };
+        RULE::validate_borrow(grapheme).map_err(|tree| Box::new(unsatisfied(target, *tree)))
+    }
+}
+
+#[cfg(feature = "unicode")]
+fn no_grapheme() -> ErrorTree {
+    ErrorTree::new("the string has no last grapheme")
+        .with_rule("LastGraphemesRule")
+        .with_expected("a grapheme")
+        .with_actual("length 0")
+}
+
+/// Locates the failure of the last grapheme by its index, as `IndexRule` does
+#[cfg(feature = "unicode")]
+fn unsatisfied(target: &str, tree: ErrorTree) -> ErrorTree {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let index = target.graphemes(true).count() - 1;
+    ErrorTree::new("the last grapheme does not satisfy the condition")
+        .with_rule("LastGraphemesRule")
+        .with_children([tree.with_location(Location::Index(index))])
+}
+
 #[cfg(test)]
 mod tests {
     use crate::result::Error;
-    use crate::rule::{LastVec, NonEmptyStringRule};
+    use crate::rule::collection::fixture::UppercaseRule;
+    use crate::rule::{LastString, LastVec, NonEmptyStringRule};
 
     #[test]
     fn last_valid() -> Result<(), Error<Vec<String>>> {
@@ -62,4 +156,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn last_string_multibyte() {
+        let table = vec![
+            ("日本Ω", true),
+            ("Ω日本", false),
+            ("A😀", false),
+            ("", false),
+        ];
+
+        for (value, expected) in table {
+            let last = LastString::<UppercaseRule>::new(value.to_string());
+            assert_eq!(last.is_ok(), expected, "{value}");
+        }
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn last_graphemes() -> anyhow::Result<()> {
+        use crate::result::Location;
+        use crate::rule::LastGraphemes;
+
+        let last = LastGraphemes::<NonEmptyStringRule>::new("日本🇯🇵".to_string())?;
+        assert_eq!(last.into_value(), "日本🇯🇵");
+        assert!(LastGraphemes::<NonEmptyStringRule>::new("".to_string()).is_err());
+
+        let err =
+            LastGraphemes::<crate::rule::EmptyRule<String>>::new("a🇯🇵".to_string()).unwrap_err();
+        assert_eq!(
+            err.tree().children()[0].location(),
+            Some(&Location::Index(1))
+        );
+        Ok(())
+    }
 }
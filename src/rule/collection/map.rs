@@ -0,0 +1,11 @@
+mod exists_key;
+mod for_all_entries;
+mod for_all_keys;
+mod for_all_values;
+mod map_iterable;
+
+pub use exists_key::*;
+pub use for_all_entries::*;
+pub use for_all_keys::*;
+pub use for_all_values::*;
+pub use map_iterable::*;
@@ -0,0 +1,147 @@
+use crate::result::{Error, ErrorTree};
+use crate::rule::collection::map::located_by_key;
+use crate::rule::{description, MapIterable, Rule, RuleDescription};
+use crate::Refined;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// A type that holds a map satisfying the `ExistsKeyRule`
+pub type ExistsKey<RULE, MAP> = Refined<ExistsKeyRule<RULE, MAP>>;
+
+/// A type that holds a `HashMap` satisfying the `ExistsKeyRule`
+pub type ExistsKeyHashMap<RULE, V> = Refined<ExistsKeyHashMapRule<RULE, V>>;
+
+/// A type that holds a `BTreeMap` satisfying the `ExistsKeyRule`
+pub type ExistsKeyBTreeMap<RULE, V> = Refined<ExistsKeyBTreeMapRule<RULE, V>>;
+
+/// A type that holds a map containing a key, where `RULE` accepts that key alone
+///
+/// A key of any type cannot be a const parameter, so the key is not given itself:
+/// a rule accepting only that key, e.g. `EqualRuleU16<80>`, stands in for it, and the type is the same as `ExistsKey`.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{ContainsKey, EqualRuleU16};
+/// use std::collections::BTreeMap;
+///
+/// type WithHttp = ContainsKey<EqualRuleU16<80>, BTreeMap<u16, &'static str>>;
+///
+/// assert!(WithHttp::new(BTreeMap::from([(80, "http"), (443, "https")])).is_ok());
+/// assert!(WithHttp::new(BTreeMap::from([(443, "https")])).is_err());
+/// ```
+pub type ContainsKey<RULE, MAP> = ExistsKey<RULE, MAP>;
+
+/// Rule where at least one key of the map satisfies the condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExistsKeyRule<RULE, MAP> {
+    _phantom_data: PhantomData<(RULE, MAP)>,
+}
+
+impl<RULE, MAP> Rule for ExistsKeyRule<RULE, MAP>
+where
+    RULE: Rule,
+    RULE::Item: Debug,
+    MAP: MapIterable<Key = RULE::Item>,
+{
+    type Item = MAP;
+
+    fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+        const { crate::rule::refuse_normalized::<RULE>() };
+        let mut exists = false;
+        let mut failures = Vec::new();
+        let result = target
+            .into_iter()
+            .map(|(key, value)| match RULE::validate(key) {
+                Ok(key) => {
+                    exists = true;
+                    (key, value)
+                }
+                Err(err) => {
+                    let (key, tree) = err.into_parts();
+                    failures.push(located_by_key(tree, &key));
+                    (key, value)
+                }
+            })
+            .collect();
+
+        if exists {
+            Ok(result)
+        } else {
+            let tree = ErrorTree::new("no key satisfies the condition")
+                .with_rule("ExistsKeyRule")
+                .with_expected("at least one key satisfying the condition")
+                .with_children(failures);
+            Err(Error::from_tree(result, tree))
+        }
+    }
+}
+
+/// Keys are serialized as JSON property names, so only `String` keys are described.
+/// The schema states that not every property name fails the condition.
+impl<RULE, MAP> RuleDescription for ExistsKeyRule<RULE, MAP>
+where
+    RULE: RuleDescription<Item = String>,
+    MAP: MapIterable<Key = String>,
+{
+    const OPERATOR: Option<&'static str> = Some("for");
+
+    fn description() -> String {
+        format!("for some key, {}", description::operand::<RULE>("for"))
+    }
+
+    fn json_schema() -> Value {
+        json!({
+            "type": "object",
+            "not": { "propertyNames": description::not(RULE::json_schema()) }
+        })
+    }
+}
+
+/// Rule where at least one key of the `HashMap` satisfies the condition
+pub type ExistsKeyHashMapRule<RULE, V> = ExistsKeyRule<RULE, HashMap<<RULE as Rule>::Item, V>>;
+
+/// Rule where at least one key of the `BTreeMap` satisfies the condition
+pub type ExistsKeyBTreeMapRule<RULE, V> = ExistsKeyRule<RULE, BTreeMap<<RULE as Rule>::Item, V>>;
+
+/// Rule where the map contains a key, where `RULE` accepts that key alone
+pub type ContainsKeyRule<RULE, MAP> = ExistsKeyRule<RULE, MAP>;
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{
+        ExistsKeyHashMap, ExistsKeyHashMapRule, NonEmptyStringRule, RuleDescription,
+    };
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_exists_key() -> anyhow::Result<()> {
+        let value = HashMap::from([("".to_string(), 0), ("b".to_string(), 1)]);
+        let refined = ExistsKeyHashMap::<NonEmptyStringRule, i32>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashMap::from([("".to_string(), 0)]);
+        let err = ExistsKeyHashMap::<NonEmptyStringRule, i32>::new(value.clone()).unwrap_err();
+        assert_eq!(err.tree().children().len(), 1);
+        assert_eq!(err.into_value(), value);
+
+        assert!(ExistsKeyHashMap::<NonEmptyStringRule, i32>::new(HashMap::new()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_exists_key_description() {
+        type Rule = ExistsKeyHashMapRule<NonEmptyStringRule, i32>;
+        assert_eq!(Rule::description(), "for some key, not empty");
+        assert_eq!(
+            Rule::json_schema(),
+            json!({
+                "type": "object",
+                "not": {
+                    "propertyNames": { "type": "string", "not": { "minLength": 1 } }
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate::result::{Error, ErrorTree};
+use crate::rule::collection::map::located_by_key;
+use crate::rule::{description, MapIterable, Rule, RuleDescription};
+use crate::Refined;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// A type that holds a map satisfying the `ForAllEntriesRule`
+pub type ForAllEntries<KRULE, VRULE, MAP> = Refined<ForAllEntriesRule<KRULE, VRULE, MAP>>;
+
+/// A type that holds a `HashMap` satisfying the `ForAllEntriesRule`
+/// # Example
+/// ```rust
+/// use refined_type::rule::{ForAllEntriesHashMap, NonEmptyStringRule, RangeRuleU16};
+/// use std::collections::HashMap;
+///
+/// type Ports = ForAllEntriesHashMap<NonEmptyStringRule, RangeRuleU16<1, 1024>>;
+///
+/// assert!(Ports::new(HashMap::from([("http".to_string(), 80), ("ssh".to_string(), 22)])).is_ok());
+/// assert!(Ports::new(HashMap::from([("".to_string(), 80)])).is_err());
+/// assert!(Ports::new(HashMap::from([("proxy".to_string(), 8080)])).is_err());
+/// ```
+pub type ForAllEntriesHashMap<KRULE, VRULE> = Refined<ForAllEntriesHashMapRule<KRULE, VRULE>>;
+
+/// A type that holds a `BTreeMap` satisfying the `ForAllEntriesRule`
+pub type ForAllEntriesBTreeMap<KRULE, VRULE> = Refined<ForAllEntriesBTreeMapRule<KRULE, VRULE>>;
+
+/// Rule where every key of the map satisfies `KRULE` and every value satisfies `VRULE`
+///
+/// When validation fails, every key and value that does not satisfy its condition is reported
+/// as a child of the `ErrorTree`, located by the key of its entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ForAllEntriesRule<KRULE, VRULE, MAP> {
+    _phantom_data: PhantomData<(KRULE, VRULE, MAP)>,
+}
+
+impl<KRULE, VRULE, MAP> Rule for ForAllEntriesRule<KRULE, VRULE, MAP>
+where
+    KRULE: Rule,
+    VRULE: Rule,
+    KRULE::Item: Debug,
+    MAP: MapIterable<Key = KRULE::Item, Value = VRULE::Item>,
+{
+    type Item = MAP;
+
+    fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+        const { crate::rule::refuse_normalized::<KRULE>() };
+        const { crate::rule::refuse_normalized::<VRULE>() };
+        validate_entries(
+            target,
+            "ForAllEntriesRule",
+            KRULE::validate,
+            VRULE::validate,
+        )
+    }
+}
+
+/// Keys are serialized as JSON property names, so only `String` keys are described
+impl<KRULE, VRULE, MAP> RuleDescription for ForAllEntriesRule<KRULE, VRULE, MAP>
+where
+    KRULE: RuleDescription<Item = String>,
+    VRULE: RuleDescription,
+    MAP: MapIterable<Key = String, Value = VRULE::Item>,
+{
+    const OPERATOR: Option<&'static str> = Some("for");
+
+    fn description() -> String {
+        format!(
+            "for every key, {}, and for every value, {}",
+            description::operand::<KRULE>("for"),
+            description::operand::<VRULE>("for")
+        )
+    }
+
+    fn json_schema() -> Value {
+        json!({
+            "type": "object",
+            "propertyNames": KRULE::json_schema(),
+            "additionalProperties": VRULE::json_schema()
+        })
+    }
+}
+
+/// Validates every entry of `target` by `validate_key` and `validate_value`, reporting every failure
+pub(crate) fn validate_entries<MAP>(
+    target: MAP,
+    rule: &str,
+    validate_key: impl Fn(MAP::Key) -> crate::Result<MAP::Key>,
+    validate_value: impl Fn(MAP::Value) -> crate::Result<MAP::Value>,
+) -> crate::Result<MAP>
+where
+    MAP: MapIterable,
+    MAP::Key: Debug,
+{
+    let mut failures = Vec::new();
+    let result = target
+        .into_iter()
+        .map(|(key, value)| {
+            let key = validate_key(key).unwrap_or_else(|err| {
+                let (key, tree) = err.into_parts();
+                failures.push(located_by_key(
+                    ErrorTree::new(format!("the key does not satisfy the condition: {tree}"))
+                        .with_children([tree]),
+                    &key,
+                ));
+                key
+            });
+            let value = validate_value(value).unwrap_or_else(|err| {
+                let (value, tree) = err.into_parts();
+                failures.push(located_by_key(tree, &key));
+                value
+            });
+            (key, value)
+        })
+        .collect();
+
+    if failures.is_empty() {
+        Ok(result)
+    } else {
+        let message = failures
+            .iter()
+            .map(|tree| {
+                format!(
+                    "the entry at {} does not satisfy the condition: {}",
+                    tree.location().map(ToString::to_string).unwrap_or_default(),
+                    tree
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let tree = ErrorTree::new(message)
+            .with_rule(rule)
+            .with_children(failures);
+        Err(Error::from_tree(result, tree))
+    }
+}
+
+/// Rule where every key of the `HashMap` satisfies `KRULE` and every value satisfies `VRULE`
+pub type ForAllEntriesHashMapRule<KRULE, VRULE> =
+    ForAllEntriesRule<KRULE, VRULE, HashMap<<KRULE as Rule>::Item, <VRULE as Rule>::Item>>;
+
+/// Rule where every key of the `BTreeMap` satisfies `KRULE` and every value satisfies `VRULE`
+pub type ForAllEntriesBTreeMapRule<KRULE, VRULE> =
+    ForAllEntriesRule<KRULE, VRULE, BTreeMap<<KRULE as Rule>::Item, <VRULE as Rule>::Item>>;
+
+#[cfg(test)]
+mod tests {
+    use crate::result::Location;
+    use crate::rule::{
+        ForAllEntriesBTreeMap, ForAllEntriesHashMap, ForAllEntriesHashMapRule, NonEmptyStringRule,
+        RangeRuleU16, RuleDescription,
+    };
+    use serde_json::json;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_for_all_entries() -> anyhow::Result<()> {
+        let value = HashMap::from([("a".to_string(), "x".to_string())]);
+        let refined =
+            ForAllEntriesHashMap::<NonEmptyStringRule, NonEmptyStringRule>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_all_entries_reports_keys_and_values() {
+        let value = BTreeMap::from([
+            ("".to_string(), "x".to_string()),
+            ("b".to_string(), "".to_string()),
+            ("c".to_string(), "z".to_string()),
+        ]);
+        let err =
+            ForAllEntriesBTreeMap::<NonEmptyStringRule, NonEmptyStringRule>::new(value.clone())
+                .unwrap_err();
+        let locations = err
+            .tree()
+            .children()
+            .iter()
+            .map(|child| child.location().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![
+                Some(Location::Key("\"\"".to_string())),
+                Some(Location::Key("\"b\"".to_string()))
+            ]
+        );
+        assert_eq!(err.into_value(), value);
+    }
+
+    #[test]
+    fn test_for_all_entries_description() {
+        type Rule = ForAllEntriesHashMapRule<NonEmptyStringRule, RangeRuleU16<1, 1024>>;
+        assert_eq!(
+            Rule::description(),
+            "for every key, not empty, and for every value, ((equal to 1 or greater than 1) and less than 1024)"
+        );
+        assert_eq!(
+            Rule::json_schema(),
+            json!({
+                "type": "object",
+                "propertyNames": { "type": "string", "minLength": 1 },
+                "additionalProperties": { "type": "integer", "minimum": 1, "exclusiveMaximum": 1024 }
+            })
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::rule::collection::map::validate_entries;
+use crate::rule::{description, MapIterable, Rule, RuleDescription};
+use crate::Refined;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// A type that holds a map satisfying the `ForAllKeysRule`
+pub type ForAllKeys<RULE, MAP> = Refined<ForAllKeysRule<RULE, MAP>>;
+
+/// A type that holds a `HashMap` satisfying the `ForAllKeysRule`
+pub type ForAllKeysHashMap<RULE, V> = Refined<ForAllKeysHashMapRule<RULE, V>>;
+
+/// A type that holds a `BTreeMap` satisfying the `ForAllKeysRule`
+pub type ForAllKeysBTreeMap<RULE, V> = Refined<ForAllKeysBTreeMapRule<RULE, V>>;
+
+/// Rule where every key of the map satisfies the condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ForAllKeysRule<RULE, MAP> {
+    _phantom_data: PhantomData<(RULE, MAP)>,
+}
+
+impl<RULE, MAP> Rule for ForAllKeysRule<RULE, MAP>
+where
+    RULE: Rule,
+    RULE::Item: Debug,
+    MAP: MapIterable<Key = RULE::Item>,
+{
+    type Item = MAP;
+
+    fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+        const { crate::rule::refuse_normalized::<RULE>() };
+        validate_entries(target, "ForAllKeysRule", RULE::validate, Ok)
+    }
+}
+
+/// Keys are serialized as JSON property names, so only `String` keys are described
+impl<RULE, MAP> RuleDescription for ForAllKeysRule<RULE, MAP>
+where
+    RULE: RuleDescription<Item = String>,
+    MAP: MapIterable<Key = String>,
+{
+    const OPERATOR: Option<&'static str> = Some("for");
+
+    fn description() -> String {
+        format!("for every key, {}", description::operand::<RULE>("for"))
+    }
+
+    fn json_schema() -> Value {
+        json!({ "type": "object", "propertyNames": RULE::json_schema() })
+    }
+}
+
+/// Rule where every key of the `HashMap` satisfies the condition
+pub type ForAllKeysHashMapRule<RULE, V> = ForAllKeysRule<RULE, HashMap<<RULE as Rule>::Item, V>>;
+
+/// Rule where every key of the `BTreeMap` satisfies the condition
+pub type ForAllKeysBTreeMapRule<RULE, V> = ForAllKeysRule<RULE, BTreeMap<<RULE as Rule>::Item, V>>;
+
+#[cfg(test)]
+mod tests {
+    use crate::result::Location;
+    use crate::rule::{
+        ForAllKeysBTreeMap, ForAllKeysHashMap, ForAllKeysHashMapRule, NonEmptyStringRule,
+        RuleDescription,
+    };
+    use serde_json::json;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_for_all_keys() -> anyhow::Result<()> {
+        let value = HashMap::from([("a".to_string(), 0), ("b".to_string(), 1)]);
+        let refined = ForAllKeysHashMap::<NonEmptyStringRule, i32>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = BTreeMap::from([("".to_string(), 0), ("b".to_string(), 1)]);
+        let err = ForAllKeysBTreeMap::<NonEmptyStringRule, i32>::new(value.clone()).unwrap_err();
+        assert_eq!(err.tree().rule(), Some("ForAllKeysRule"));
+        assert_eq!(
+            err.tree().children()[0].location(),
+            Some(&Location::Key("\"\"".to_string()))
+        );
+        assert_eq!(err.into_value(), value);
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_all_keys_description() {
+        type Rule = ForAllKeysHashMapRule<NonEmptyStringRule, i32>;
+        assert_eq!(Rule::description(), "for every key, not empty");
+        assert_eq!(
+            Rule::json_schema(),
+            json!({ "type": "object", "propertyNames": { "type": "string", "minLength": 1 } })
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::rule::{ForAllHashMapRule, ForAllRule, Rule};
+use crate::Refined;
+use std::collections::BTreeMap;
+
+/// A type that holds a map satisfying the `ForAllValuesRule`
+pub type ForAllValues<RULE, MAP> = Refined<ForAllValuesRule<RULE, MAP>>;
+
+/// A type that holds a `HashMap` satisfying the `ForAllValuesRule`
+pub type ForAllValuesHashMap<K, RULE> = Refined<ForAllValuesHashMapRule<K, RULE>>;
+
+/// A type that holds a `BTreeMap` satisfying the `ForAllValuesRule`
+pub type ForAllValuesBTreeMap<K, RULE> = Refined<ForAllValuesBTreeMapRule<K, RULE>>;
+
+/// Rule where every value of the map satisfies the condition
+///
+/// It is `ForAllRule` over a map, whose items are its values, so a failing value is located by its key.
+pub type ForAllValuesRule<RULE, MAP> = ForAllRule<RULE, MAP>;
+
+/// Rule where every value of the `HashMap` satisfies the condition
+pub type ForAllValuesHashMapRule<K, RULE> = ForAllHashMapRule<K, RULE>;
+
+/// Rule where every value of the `BTreeMap` satisfies the condition
+pub type ForAllValuesBTreeMapRule<K, RULE> = ForAllRule<RULE, BTreeMap<K, <RULE as Rule>::Item>>;
+
+#[cfg(test)]
+mod tests {
+    use crate::result::Location;
+    use crate::rule::{
+        ForAllValuesBTreeMap, ForAllValuesHashMap, ForAllValuesHashMapRule, NonEmptyStringRule,
+        RuleDescription,
+    };
+    use serde_json::json;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_for_all_values() -> anyhow::Result<()> {
+        let value = BTreeMap::from([(1, "a".to_string()), (2, "b".to_string())]);
+        let refined = ForAllValuesBTreeMap::<i32, NonEmptyStringRule>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = BTreeMap::from([
+            (1, "".to_string()),
+            (2, "b".to_string()),
+            (3, "".to_string()),
+        ]);
+        let err = ForAllValuesBTreeMap::<i32, NonEmptyStringRule>::new(value).unwrap_err();
+        let locations = err
+            .tree()
+            .children()
+            .iter()
+            .map(|child| child.location().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![
+                Some(Location::Key("1".to_string())),
+                Some(Location::Key("3".to_string()))
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_all_values_hash_map() -> anyhow::Result<()> {
+        let value = HashMap::from([("a", "x".to_string()), ("b", "y".to_string())]);
+        let refined = ForAllValuesHashMap::<&str, NonEmptyStringRule>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashMap::from([("a", "x".to_string()), ("b", "".to_string())]);
+        let err = ForAllValuesHashMap::<&str, NonEmptyStringRule>::new(value).unwrap_err();
+        assert_eq!(
+            err.tree().children()[0].location(),
+            Some(&Location::Key("\"b\"".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_all_values_description() {
+        type Rule = ForAllValuesHashMapRule<String, NonEmptyStringRule>;
+        assert!(Rule::description().starts_with("for every value, "));
+        assert_eq!(Rule::json_schema()["type"], json!("object"));
+    }
+}
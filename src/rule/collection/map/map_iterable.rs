@@ -0,0 +1,29 @@
+use crate::result::{ErrorTree, Location};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+
+/// This is a `trait` for the maps whose keys and values are validated by the map rules, e.g. `ForAllKeysRule`
+///
+/// A map is rebuilt from its entries after validation, so a rule that modifies keys keeps them as keys.
+pub trait MapIterable:
+    IntoIterator<Item = (Self::Key, Self::Value)> + FromIterator<(Self::Key, Self::Value)>
+{
+    type Key;
+    type Value;
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> MapIterable for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+}
+
+impl<K: Ord, V> MapIterable for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+}
+
+/// Locates the `ErrorTree` of a key or a value by the key of its entry
+pub(crate) fn located_by_key<K: Debug>(tree: ErrorTree, key: &K) -> ErrorTree {
+    tree.with_location(Location::Key(format!("{key:?}")))
+}
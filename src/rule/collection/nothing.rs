@@ -41,8 +41,11 @@ pub type NothingStringRule<RULE> = NothingRule<RULE, String>;
 
 #[cfg(test)]
 mod tests {
-    use crate::result::Error;
+    use crate::result::{Error, Location};
+    use crate::rule::Rule;
     use crate::rule::{NonEmptyStringRule, NothingVec};
+    use crate::rule::{NothingHashMap, NothingHashMapRule, NothingHashSet, NothingHashSetRule};
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn nothing_valid() -> Result<(), Error<Vec<String>>> {
@@ -70,4 +73,44 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn nothing_reports_every_failing_item() {
+        let value = vec!["hello".to_string(), "".to_string(), "world".to_string()];
+        let err = NothingVec::<NonEmptyStringRule>::new(value).unwrap_err();
+        let locations = err
+            .tree()
+            .children()
+            .iter()
+            .map(|child| child.location().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![Some(Location::Index(0)), Some(Location::Index(2))]
+        );
+    }
+
+    #[test]
+    fn nothing_hash_set() -> anyhow::Result<()> {
+        let value = HashSet::from(["".to_string(), "".to_string()]);
+        let refined = NothingHashSet::<NonEmptyStringRule>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashSet::from(["a".to_string(), "".to_string()]);
+        assert!(NothingHashSetRule::<NonEmptyStringRule>::validate(value).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn nothing_hash_map() -> anyhow::Result<()> {
+        let value = HashMap::from([(1, "".to_string()), (2, "".to_string())]);
+        let refined = NothingHashMap::<i32, NonEmptyStringRule>::new(value.clone())?;
+        assert_eq!(refined.into_value(), value);
+
+        let value = HashMap::from([(1, "a".to_string()), (2, "".to_string())]);
+        let err =
+            NothingHashMapRule::<i32, NonEmptyStringRule>::validate(value.clone()).unwrap_err();
+        assert_eq!(err.into_value(), value);
+        Ok(())
+    }
 }
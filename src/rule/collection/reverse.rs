@@ -1,5 +1,5 @@
-use crate::result::Error;
-use crate::rule::{Iterable, Rule};
+use crate::result::{Error, ErrorTree};
+use crate::rule::{Iterable, Rule, RuleRef};
 use crate::Refined;
 use std::marker::PhantomData;
 
@@ -23,17 +23,39 @@ where
     type Item = RULE::Item;
 
     fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+        const { crate::rule::refuse_normalized::<RULE>() };
         match RULE::validate(ITERABLE::from_iter(target.into_iterator().rev())) {
             Ok(iterable) => Ok(ITERABLE::from_iter(iterable.into_iterator().rev())),
             Err(e) => {
-                let message = format!("ReverseRule validation failed: {}", e);
-                let item = ITERABLE::from_iter(e.into_value().into_iterator().rev());
-                Err(Error::new(item, message))
+                let (item, inner_tree) = e.into_parts();
+                let tree = ErrorTree::new(format!("ReverseRule validation failed: {}", inner_tree))
+                    .with_rule("ReverseRule")
+                    .with_children([inner_tree]);
+                let item = ITERABLE::from_iter(item.into_iterator().rev());
+                Err(Error::from_tree(item, tree))
             }
         }
     }
 }
 
+/// Validating by reference clones the collection to build its reversed copy,
+/// since `RULE` has to see the items in reverse order.
+impl<RULE, ITERABLE> RuleRef for ReverseRule<RULE>
+where
+    RULE: RuleRef<Item = ITERABLE>,
+    ITERABLE: Iterable + FromIterator<ITERABLE::Item> + Clone,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        let reversed = ITERABLE::from_iter(target.clone().into_iterator().rev());
+        RULE::validate_ref(&reversed).map_err(|inner_tree| {
+            let tree = ErrorTree::new(format!("ReverseRule validation failed: {}", inner_tree))
+                .with_rule("ReverseRule")
+                .with_children([*inner_tree]);
+            Box::new(tree)
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::result::Error;
@@ -1,12 +1,17 @@
 mod option;
 
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
 use std::marker::PhantomData;
 
 pub use option::*;
 
-use crate::rule::{Iterable, Rule};
+use crate::result::{Error, ErrorTree};
+use crate::rule::IterableItems;
+use crate::rule::{description, ItemPosition, Rule, RuleBorrow, RuleDescription, RuleRef};
 use crate::Refined;
+use serde_json::{json, Value};
 
 /// A type that holds a value satisfying the `SkipRule`
 pub type Skip<RULE, ITERABLE, OPTION> = Refined<SkipRule<RULE, ITERABLE, OPTION>>;
@@ -21,6 +26,9 @@ pub type SkipVecDeque<RULE, OPTION> = Refined<SkipVecDequeRule<RULE, OPTION>>;
 pub type SkipString<RULE, OPTION> = Refined<SkipStringRule<RULE, OPTION>>;
 
 /// Rule where the data in the collection satisfies the condition after skipping the first element
+///
+/// When validation fails, every item that does not satisfy the condition is reported
+/// as a child of the `ErrorTree`, located by its index.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SkipRule<RULE, ITERABLE, OPTION>
 where
@@ -33,46 +41,109 @@ where
 impl<RULE, ITERABLE, OPTION> Rule for SkipRule<RULE, ITERABLE, OPTION>
 where
     RULE: Rule,
-    ITERABLE: Iterable<Item = RULE::Item> + FromIterator<RULE::Item>,
+    ITERABLE: IterableItems<Item = RULE::Item>,
     OPTION: SkipOption<Item = RULE::Item>,
 {
     type Item = ITERABLE;
 
     fn validate(target: Self::Item) -> crate::Result<Self::Item> {
-        let mut remains = target.into_iterator();
-        let mut result = VecDeque::new();
-        let (mut is_valid, mut message) = (true, String::new());
+        const { crate::rule::refuse_normalized::<RULE>() };
+        let mut failures = Vec::new();
         let mut accumlator = None;
-        for (i, item) in remains.by_ref().enumerate() {
-            if OPTION::should_skip(i, accumlator.as_mut(), &item) {
-                result.push_back(item);
-                continue;
+        let result = target.map_items(|position, item| {
+            if OPTION::should_skip(position.index(), accumlator.as_mut(), &item) {
+                return item;
             }
             match RULE::validate(item) {
-                Ok(validated_item) => result.push_back(validated_item),
+                Ok(validated_item) => validated_item,
                 Err(err) => {
-                    is_valid = false;
-                    message = format!(
-                        "the item at index {} does not satisfy the condition: {}",
-                        i, err
-                    );
-                    result.push_back(err.into_value());
+                    let (item, tree) = err.into_parts();
+                    failures.push((position.describe(), position.locate(tree)));
+                    item
                 }
             }
-        }
+        });
 
-        if is_valid {
-            Ok(result.into_iter().collect())
+        if failures.is_empty() {
+            Ok(result)
         } else {
-            result.append(&mut remains.collect::<VecDeque<_>>());
-            Err(crate::result::Error::new(
-                result.into_iter().collect(),
-                message,
-            ))
+            Err(Error::from_tree(result, failure_tree(failures)))
+        }
+    }
+}
+
+impl<RULE, ITERABLE, OPTION> RuleRef for SkipRule<RULE, ITERABLE, OPTION>
+where
+    RULE: RuleRef,
+    ITERABLE: IterableItems<Item = RULE::Item>,
+    OPTION: SkipOption<Item = RULE::Item>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        validate_visited::<RULE, OPTION>(|f| target.visit_items(f))
+    }
+}
+
+impl<RULE, T, OPTION> RuleBorrow<[T]> for SkipRule<RULE, Vec<T>, OPTION>
+where
+    RULE: RuleRef<Item = T>,
+    OPTION: SkipOption<Item = T>,
+{
+    fn validate_borrow(target: &[T]) -> Result<(), Box<ErrorTree>> {
+        validate_visited::<RULE, OPTION>(|f| {
+            for (i, item) in target.iter().enumerate() {
+                f(ItemPosition::sequence(i), item);
+            }
+        })
+    }
+}
+
+impl<RULE, OPTION> RuleBorrow<str> for SkipRule<RULE, String, OPTION>
+where
+    RULE: RuleRef<Item = char>,
+    OPTION: SkipOption<Item = char>,
+{
+    fn validate_borrow(target: &str) -> Result<(), Box<ErrorTree>> {
+        validate_visited::<RULE, OPTION>(|f| target.visit_items(f))
+    }
+}
+
+/// Validates the items given by `visit` by reference, reporting every item that does not satisfy the condition
+fn validate_visited<RULE, OPTION>(
+    visit: impl FnOnce(&mut dyn FnMut(ItemPosition<'_>, &RULE::Item)),
+) -> Result<(), Box<ErrorTree>>
+where
+    RULE: RuleRef,
+    OPTION: SkipOption<Item = RULE::Item>,
+{
+    let mut failures = Vec::new();
+    let mut accumlator = None;
+    visit(&mut |position, item| {
+        if OPTION::should_skip(position.index(), accumlator.as_mut(), item) {
+            return;
         }
+        if let Err(tree) = RULE::validate_ref(item) {
+            failures.push((position.describe(), position.locate(*tree)));
+        }
+    });
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(Box::new(failure_tree(failures)))
     }
 }
 
+fn failure_tree(failures: Vec<(String, ErrorTree)>) -> ErrorTree {
+    let message = failures
+        .iter()
+        .map(|(item, tree)| format!("{} does not satisfy the condition: {}", item, tree))
+        .collect::<Vec<_>>()
+        .join(", ");
+    ErrorTree::new(message)
+        .with_rule("SkipRule")
+        .with_children(failures.into_iter().map(|(_, tree)| tree))
+}
+
 /// Rule where the data in the `Vec` satisfies the condition after skipping the first element
 pub type SkipVecRule<RULE, OPTION> = SkipRule<RULE, Vec<<RULE as Rule>::Item>, OPTION>;
 
@@ -82,9 +153,70 @@ pub type SkipVecDequeRule<RULE, OPTION> = SkipRule<RULE, VecDeque<<RULE as Rule>
 /// Rule where the data in the `String` satisfies the condition after skipping the first element
 pub type SkipStringRule<RULE, OPTION> = SkipRule<RULE, String, OPTION>;
 
+macro_rules! impl_rule_description {
+    ($($sequence: ident),+) => {$(
+        impl<RULE, T> RuleDescription for SkipRule<RULE, $sequence<T>, NoSkip<T>>
+        where
+            RULE: RuleDescription<Item = T>,
+        {
+            const OPERATOR: Option<&'static str> = Some("for");
+
+            fn description() -> String {
+                format!("for every item, {}", description::operand::<RULE>("for"))
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "array", "items": RULE::json_schema() })
+            }
+        }
+
+        impl<RULE, T> RuleDescription for SkipRule<RULE, $sequence<T>, SkipFirst<T>>
+        where
+            RULE: RuleDescription<Item = T>,
+        {
+            const OPERATOR: Option<&'static str> = Some("for");
+
+            fn description() -> String {
+                format!(
+                    "for every item after the first, {}",
+                    description::operand::<RULE>("for")
+                )
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "array", "prefixItems": [{}], "items": RULE::json_schema() })
+            }
+        }
+    )+};
+}
+
+impl_rule_description!(Vec, VecDeque);
+
+macro_rules! impl_map_rule_description {
+    ($($map: ident: $($bound: path),+);+) => {$(
+        impl<RULE, K, T> RuleDescription for SkipRule<RULE, $map<K, T>, NoSkip<T>>
+        where
+            RULE: RuleDescription<Item = T>,
+            K: Debug $(+ $bound)+,
+        {
+            const OPERATOR: Option<&'static str> = Some("for");
+
+            fn description() -> String {
+                format!("for every value, {}", description::operand::<RULE>("for"))
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "object", "additionalProperties": RULE::json_schema() })
+            }
+        }
+    )+};
+}
+
+impl_map_rule_description!(HashMap: Eq, Hash; BTreeMap: Ord);
+
 #[cfg(test)]
 mod tests {
-    use crate::result::Error;
+    use crate::result::{Error, Location};
     use crate::rule::{NonEmptyStringRule, SkipFirst, SkipVec};
 
     #[test]
@@ -124,4 +256,29 @@ mod tests {
             assert!(value.is_err());
         }
     }
+
+    #[test]
+    fn test_skip_first_invalid_tree() {
+        let data = vec![
+            "".to_string(),
+            "".to_string(),
+            "world".to_string(),
+            "".to_string(),
+        ];
+        let err = SkipVec::<NonEmptyStringRule, SkipFirst<_>>::new(data.clone()).unwrap_err();
+        let tree = err.tree();
+        assert_eq!(tree.rule(), Some("SkipRule"));
+        assert_eq!(
+            tree.children()
+                .iter()
+                .map(|child| child.location())
+                .collect::<Vec<_>>(),
+            vec![Some(&Location::Index(1)), Some(&Location::Index(3))]
+        );
+        assert!(tree
+            .children()
+            .iter()
+            .all(|child| child.rule() == Some("Not")));
+        assert_eq!(err.into_value(), data);
+    }
 }
@@ -0,0 +1,433 @@
+use crate::result::{Error, ErrorTree, Location};
+use crate::rule::{Iterable, OrderedIterable, Rule, RuleDescription};
+use crate::Refined;
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// A type that holds a value satisfying the `SortedRule`
+pub type Sorted<ITERABLE> = Refined<SortedRule<ITERABLE>>;
+
+/// A type that holds a Vec value satisfying the `SortedRule`
+/// # Example
+/// ```rust
+/// use refined_type::rule::SortedVec;
+///
+/// let versions = SortedVec::new(vec![1, 3, 5]).unwrap().insert(4).insert(1);
+/// assert_eq!(versions.into_value(), vec![1, 1, 3, 4, 5]);
+///
+/// assert!(SortedVec::new(vec![1, 5, 3]).is_err());
+/// ```
+pub type SortedVec<T> = Refined<SortedVecRule<T>>;
+
+/// A type that holds a VecDeque value satisfying the `SortedRule`
+pub type SortedVecDeque<T> = Refined<SortedVecDequeRule<T>>;
+
+/// A type that holds a String value satisfying the `SortedRule`
+pub type SortedString = Refined<SortedStringRule>;
+
+/// A type that holds a value satisfying the `SortedByKeyRule`
+pub type SortedByKey<KEY, ITERABLE> = Refined<SortedByKeyRule<KEY, ITERABLE>>;
+
+/// A type that holds a Vec value satisfying the `SortedByKeyRule`
+pub type SortedByKeyVec<KEY> = Refined<SortedByKeyVecRule<KEY>>;
+
+/// A type that holds a VecDeque value satisfying the `SortedByKeyRule`
+pub type SortedByKeyVecDeque<KEY> = Refined<SortedByKeyVecDequeRule<KEY>>;
+
+/// A type that holds a value satisfying the `StrictlyIncreasingRule`
+pub type StrictlyIncreasing<ITERABLE> = Refined<StrictlyIncreasingRule<ITERABLE>>;
+
+/// A type that holds a Vec value satisfying the `StrictlyIncreasingRule`
+pub type StrictlyIncreasingVec<T> = Refined<StrictlyIncreasingVecRule<T>>;
+
+/// A type that holds a VecDeque value satisfying the `StrictlyIncreasingRule`
+pub type StrictlyIncreasingVecDeque<T> = Refined<StrictlyIncreasingVecDequeRule<T>>;
+
+/// A type that holds a String value satisfying the `StrictlyIncreasingRule`
+pub type StrictlyIncreasingString = Refined<StrictlyIncreasingStringRule>;
+
+/// Rule where every item in the collection is greater than or equal to the previous one
+///
+/// The collection must be an `OrderedIterable`, so a `HashSet` or `HashMap` is not accepted.
+/// When validation fails, every item less than the previous one is reported
+/// as a child of the `ErrorTree`, located by its index.
+///
+/// ```compile_fail
+/// use refined_type::rule::Sorted;
+/// use std::collections::HashSet;
+///
+/// let _ = Sorted::new(HashSet::from([1, 2, 3]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedRule<ITERABLE> {
+    _phantom_data: PhantomData<ITERABLE>,
+}
+
+impl<ITERABLE> Rule for SortedRule<ITERABLE>
+where
+    ITERABLE: OrderedIterable + FromIterator<ITERABLE::Item>,
+    ITERABLE::Item: PartialOrd,
+{
+    type Item = ITERABLE;
+
+    fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+        validate_order(target, "SortedRule", "sorted items", |prev, item| {
+            prev <= item
+        })
+    }
+}
+
+/// This is a `trait` that gives the key by which the `SortedByKeyRule` orders the items
+/// # Example
+/// ```rust
+/// use refined_type::rule::{SortKey, SortedByKeyVec};
+///
+/// struct ByLength;
+///
+/// impl SortKey for ByLength {
+///     type Item = String;
+///     type Key = usize;
+///
+///     fn key(item: &Self::Item) -> Self::Key {
+///         item.len()
+///     }
+/// }
+///
+/// let words = vec!["a".to_string(), "abc".to_string(), "ab".to_string()];
+/// assert!(SortedByKeyVec::<ByLength>::new(words).is_err());
+/// ```
+pub trait SortKey {
+    type Item;
+    type Key: PartialOrd;
+
+    fn key(item: &Self::Item) -> Self::Key;
+}
+
+/// Rule where the key of every item in the collection is greater than or equal to that of the previous one
+///
+/// Like `SortedRule`, it accepts only an `OrderedIterable`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedByKeyRule<KEY, ITERABLE> {
+    _phantom_data: PhantomData<(KEY, ITERABLE)>,
+}
+
+impl<KEY, ITERABLE> Rule for SortedByKeyRule<KEY, ITERABLE>
+where
+    KEY: SortKey<Item = ITERABLE::Item>,
+    ITERABLE: OrderedIterable + FromIterator<ITERABLE::Item>,
+{
+    type Item = ITERABLE;
+
+    fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+        validate_order(
+            target,
+            "SortedByKeyRule",
+            "items sorted by key",
+            |prev, item| KEY::key(prev) <= KEY::key(item),
+        )
+    }
+}
+
+/// Rule where every item in the collection is greater than the previous one
+///
+/// That is, the items are sorted and distinct. Like `SortedRule`, it accepts only an `OrderedIterable`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrictlyIncreasingRule<ITERABLE> {
+    _phantom_data: PhantomData<ITERABLE>,
+}
+
+impl<ITERABLE> Rule for StrictlyIncreasingRule<ITERABLE>
+where
+    ITERABLE: OrderedIterable + FromIterator<ITERABLE::Item>,
+    ITERABLE::Item: PartialOrd,
+{
+    type Item = ITERABLE;
+
+    fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+        validate_order(
+            target,
+            "StrictlyIncreasingRule",
+            "strictly increasing items",
+            |prev, item| prev < item,
+        )
+    }
+}
+
+/// Checks every pair of adjacent items by `in_order` and reports every item out of order
+fn validate_order<ITERABLE>(
+    target: ITERABLE,
+    rule: &str,
+    expected: &str,
+    in_order: impl Fn(&ITERABLE::Item, &ITERABLE::Item) -> bool,
+) -> crate::Result<ITERABLE>
+where
+    ITERABLE: Iterable + FromIterator<ITERABLE::Item>,
+{
+    let items = target.into_iterator().collect::<Vec<_>>();
+    let failures = items
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| !in_order(&pair[0], &pair[1]))
+        .map(|(i, _)| {
+            ErrorTree::new(format!(
+                "the item at index {} is out of order after the item at index {}",
+                i + 1,
+                i
+            ))
+            .with_location(Location::Index(i + 1))
+        })
+        .collect::<Vec<_>>();
+    let target = items.into_iter().collect();
+
+    if failures.is_empty() {
+        Ok(target)
+    } else {
+        let tree = ErrorTree::new(format!("{} items are out of order", failures.len()))
+            .with_rule(rule)
+            .with_expected(expected)
+            .with_children(failures);
+        Err(Error::from_tree(target, tree))
+    }
+}
+
+/// Rule where every item in the `Vec` is greater than or equal to the previous one
+pub type SortedVecRule<T> = SortedRule<Vec<T>>;
+
+/// Rule where every item in the `VecDeque` is greater than or equal to the previous one
+pub type SortedVecDequeRule<T> = SortedRule<VecDeque<T>>;
+
+/// Rule where every char in the `String` is greater than or equal to the previous one
+pub type SortedStringRule = SortedRule<String>;
+
+/// Rule where the key of every item in the `Vec` is greater than or equal to that of the previous one
+pub type SortedByKeyVecRule<KEY> = SortedByKeyRule<KEY, Vec<<KEY as SortKey>::Item>>;
+
+/// Rule where the key of every item in the `VecDeque` is greater than or equal to that of the previous one
+pub type SortedByKeyVecDequeRule<KEY> = SortedByKeyRule<KEY, VecDeque<<KEY as SortKey>::Item>>;
+
+/// Rule where every item in the `Vec` is greater than the previous one
+pub type StrictlyIncreasingVecRule<T> = StrictlyIncreasingRule<Vec<T>>;
+
+/// Rule where every item in the `VecDeque` is greater than the previous one
+pub type StrictlyIncreasingVecDequeRule<T> = StrictlyIncreasingRule<VecDeque<T>>;
+
+/// Rule where every char in the `String` is greater than the previous one
+pub type StrictlyIncreasingStringRule = StrictlyIncreasingRule<String>;
+
+macro_rules! impl_rule_description {
+    ($($sequence: ident),+) => {$(
+        impl<T: PartialOrd> RuleDescription for SortedRule<$sequence<T>> {
+            fn description() -> String {
+                "sorted items".to_string()
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "array" })
+            }
+        }
+
+        impl<KEY: SortKey> RuleDescription for SortedByKeyRule<KEY, $sequence<KEY::Item>> {
+            fn description() -> String {
+                "items sorted by key".to_string()
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "array" })
+            }
+        }
+
+        impl<T: PartialOrd> RuleDescription for StrictlyIncreasingRule<$sequence<T>> {
+            fn description() -> String {
+                "strictly increasing items".to_string()
+            }
+
+            fn json_schema() -> Value {
+                json!({ "type": "array" })
+            }
+        }
+    )+};
+}
+
+impl_rule_description!(Vec, VecDeque);
+
+impl<T: Ord> SortedVec<T> {
+    /// Inserts `value` after the items less than or equal to it, keeping the `Vec` sorted
+    pub fn insert(self, value: T) -> Self {
+        let mut result = self.into_value();
+        let index = result.partition_point(|item| item <= &value);
+        result.insert(index, value);
+        Refined::new_unchecked(result)
+    }
+
+    /// Merges the items of `iter` into the `Vec`, keeping it sorted
+    ///
+    /// Only the new items are sorted, and each is placed after the items equal to it, as `insert` does.
+    pub fn extend<I>(self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut added = iter.into_iter().collect::<Vec<_>>();
+        added.sort();
+        let current = self.into_value();
+        let mut result = Vec::with_capacity(current.len() + added.len());
+        let mut current = current.into_iter().peekable();
+        for item in added {
+            while let Some(prev) = current.next_if(|prev| prev <= &item) {
+                result.push(prev);
+            }
+            result.push(item);
+        }
+        result.extend(current);
+        Refined::new_unchecked(result)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.value().binary_search(value).is_ok()
+    }
+}
+
+impl<T: Ord> StrictlyIncreasingVec<T> {
+    /// Inserts `value` in order unless an equal item is already in the `Vec`
+    ///
+    /// Like `HashSet::insert`, the returned `bool` is `false` when `value` was a duplicate
+    /// and has been dropped.
+    pub fn insert(self, value: T) -> (Self, bool) {
+        let mut result = self.into_value();
+        let inserted = match result.binary_search(&value) {
+            Ok(_) => false,
+            Err(index) => {
+                result.insert(index, value);
+                true
+            }
+        };
+        (Refined::new_unchecked(result), inserted)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.value().binary_search(value).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::result::Location;
+    use crate::rule::{
+        RuleDescription, SortKey, SortedByKeyVec, SortedByKeyVecRule, SortedString, SortedVec,
+        SortedVecRule, StrictlyIncreasingVec, StrictlyIncreasingVecDequeRule,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_sorted() {
+        let table = vec![
+            (vec![], true),
+            (vec![1], true),
+            (vec![1, 1, 2], true),
+            (vec![2, 1], false),
+            (vec![1, 3, 2, 4], false),
+        ];
+
+        for (value, expected) in table {
+            assert_eq!(SortedVec::new(value.clone()).is_ok(), expected, "{value:?}");
+        }
+        assert!(SortedString::new("abbc".to_string()).is_ok());
+        assert!(SortedString::new("あい".to_string()).is_ok());
+        assert!(SortedString::new("いあ".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_sorted_reports_every_item_out_of_order() {
+        let value = vec![1.0, 0.5, 2.0, f64::NAN, 3.0];
+        let err = SortedVec::new(value).unwrap_err();
+        let locations = err
+            .tree()
+            .children()
+            .iter()
+            .map(|child| child.location().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![
+                Some(Location::Index(1)),
+                Some(Location::Index(3)),
+                Some(Location::Index(4))
+            ]
+        );
+        assert_eq!(err.tree().rule(), Some("SortedRule"));
+    }
+
+    #[test]
+    fn test_strictly_increasing() {
+        let table = vec![
+            (vec![], true),
+            (vec![1, 2, 5], true),
+            (vec![1, 1, 2], false),
+            (vec![2, 1], false),
+        ];
+
+        for (value, expected) in table {
+            assert_eq!(
+                StrictlyIncreasingVec::new(value.clone()).is_ok(),
+                expected,
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sorted_by_key() {
+        struct ByTimestamp;
+
+        impl SortKey for ByTimestamp {
+            type Item = (u64, &'static str);
+            type Key = u64;
+
+            fn key(item: &Self::Item) -> Self::Key {
+                item.0
+            }
+        }
+
+        let events = vec![(1, "created"), (5, "updated"), (5, "renamed")];
+        assert!(SortedByKeyVec::<ByTimestamp>::new(events).is_ok());
+        let events = vec![(5, "updated"), (1, "created")];
+        assert!(SortedByKeyVec::<ByTimestamp>::new(events).is_err());
+        assert_eq!(
+            SortedByKeyVecRule::<ByTimestamp>::description(),
+            "items sorted by key"
+        );
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(SortedVecRule::<i32>::description(), "sorted items");
+        assert_eq!(
+            SortedVecRule::<i32>::json_schema(),
+            json!({ "type": "array" })
+        );
+        assert_eq!(
+            StrictlyIncreasingVecDequeRule::<i32>::description(),
+            "strictly increasing items"
+        );
+        assert_eq!(
+            StrictlyIncreasingVecDequeRule::<i32>::json_schema(),
+            json!({ "type": "array" })
+        );
+    }
+
+    #[test]
+    fn test_insert_keeps_order() -> anyhow::Result<()> {
+        let sorted = SortedVec::new(vec![1, 3])?.insert(2).insert(3).insert(0);
+        assert_eq!(sorted.value(), &vec![0, 1, 2, 3, 3]);
+        let sorted = sorted.extend([9, 2, -1]);
+        assert_eq!(sorted.value(), &vec![-1, 0, 1, 2, 2, 3, 3, 9]);
+        assert!(sorted.contains(&2));
+
+        let (increasing, inserted) = StrictlyIncreasingVec::new(vec![1, 3])?.insert(2);
+        assert!(inserted);
+        let (increasing, inserted) = increasing.insert(3);
+        assert!(!inserted);
+        assert_eq!(increasing.into_value(), vec![1, 2, 3]);
+        Ok(())
+    }
+}
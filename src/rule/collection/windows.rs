@@ -0,0 +1,232 @@
+use crate::result::{Error, ErrorTree, Location};
+use crate::rule::{description, Iterable, JsonTyped, Rule, RuleDescription, RuleRef};
+use crate::Refined;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// A type that holds a value satisfying the `WindowsRule`
+pub type Windows<const N: usize, RULE, ITERABLE> = Refined<WindowsRule<N, RULE, ITERABLE>>;
+
+/// A type that holds a Vec value satisfying the `WindowsRule`
+pub type WindowsVec<const N: usize, RULE> = Refined<WindowsVecRule<N, RULE>>;
+
+/// A type that holds a VecDeque value satisfying the `WindowsRule`
+pub type WindowsVecDeque<const N: usize, RULE> = Refined<WindowsVecDequeRule<N, RULE>>;
+
+/// A type that holds a value satisfying the `AdjacentRule`
+pub type Adjacent<RULE, ITERABLE> = Refined<AdjacentRule<RULE, ITERABLE>>;
+
+/// A type that holds a Vec value satisfying the `AdjacentRule`
+/// # Example
+/// ```rust
+/// use refined_type::define_rule;
+/// use refined_type::rule::AdjacentVec;
+///
+/// define_rule![pub BoundedDelta, [i32; 2], |[prev, next]| (next - prev).abs() <= 5, "the delta of {value} exceeds 5"];
+///
+/// assert!(AdjacentVec::<BoundedDeltaRule>::new(vec![20, 23, 19, 24]).is_ok());
+/// assert!(AdjacentVec::<BoundedDeltaRule>::new(vec![20, 23, 30, 24]).is_err());
+/// ```
+pub type AdjacentVec<RULE> = Refined<AdjacentVecRule<RULE>>;
+
+/// A type that holds a VecDeque value satisfying the `AdjacentRule`
+pub type AdjacentVecDeque<RULE> = Refined<AdjacentVecDequeRule<RULE>>;
+
+/// A type that holds a String value satisfying the `AdjacentRule`
+pub type AdjacentString<RULE> = Refined<AdjacentStringRule<RULE>>;
+
+/// Rule where every `N` consecutive items in the collection, as `[T; N]`, satisfy the condition
+///
+/// The windows are borrowed from the collection, so `RULE` checks them by `RuleRef` without cloning the items.
+/// A collection shorter than `N` has no window and always satisfies the rule.
+/// When validation fails, every window that does not satisfy the condition is reported
+/// as a child of the `ErrorTree`, located by the index of its first item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WindowsRule<const N: usize, RULE, ITERABLE> {
+    _phantom_data: PhantomData<(RULE, ITERABLE)>,
+}
+
+impl<const N: usize, RULE, ITERABLE, T> Rule for WindowsRule<N, RULE, ITERABLE>
+where
+    RULE: RuleRef<Item = [T; N]>,
+    ITERABLE: Iterable<Item = T> + FromIterator<T>,
+{
+    type Item = ITERABLE;
+
+    fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+        const { assert!(N > 0, "a window must have at least one item") };
+
+        let items = target.into_iterator().collect::<Vec<_>>();
+        let failures = items
+            .windows(N)
+            .enumerate()
+            .filter_map(|(i, window)| {
+                let window = <&[T; N]>::try_from(window).expect("This error is always unreachable");
+                RULE::validate_ref(window)
+                    .err()
+                    .map(|tree| tree.with_location(Location::Index(i)))
+            })
+            .collect::<Vec<_>>();
+        let target = items.into_iter().collect();
+
+        if failures.is_empty() {
+            Ok(target)
+        } else {
+            let tree = ErrorTree::new(format!(
+                "{} windows do not satisfy the condition",
+                failures.len()
+            ))
+            .with_rule("WindowsRule")
+            .with_expected(format!("every window of {N} items satisfies the condition"))
+            .with_children(failures);
+            Err(Error::from_tree(target, tree))
+        }
+    }
+}
+
+/// JSON Schema cannot constrain consecutive items, so the schema only states the type
+impl<const N: usize, RULE, ITERABLE, T> RuleDescription for WindowsRule<N, RULE, ITERABLE>
+where
+    RULE: RuleRef<Item = [T; N]> + RuleDescription,
+    ITERABLE: Iterable<Item = T> + FromIterator<T> + JsonTyped,
+{
+    const OPERATOR: Option<&'static str> = Some("for");
+
+    fn description() -> String {
+        format!(
+            "for every window of {N} items, {}",
+            description::operand::<RULE>("for")
+        )
+    }
+
+    fn json_schema() -> Value {
+        ITERABLE::JSON_TYPE.schema()
+    }
+}
+
+/// Rule where every `N` consecutive items in the `Vec` satisfy the condition
+pub type WindowsVecRule<const N: usize, RULE> =
+    WindowsRule<N, RULE, Vec<<<RULE as Rule>::Item as IntoIterator>::Item>>;
+
+/// Rule where every `N` consecutive items in the `VecDeque` satisfy the condition
+pub type WindowsVecDequeRule<const N: usize, RULE> =
+    WindowsRule<N, RULE, VecDeque<<<RULE as Rule>::Item as IntoIterator>::Item>>;
+
+/// Rule where every pair of adjacent items in the collection, as `[T; 2]`, satisfies the condition
+pub type AdjacentRule<RULE, ITERABLE> = WindowsRule<2, RULE, ITERABLE>;
+
+/// Rule where every pair of adjacent items in the `Vec` satisfies the condition
+pub type AdjacentVecRule<RULE> = WindowsVecRule<2, RULE>;
+
+/// Rule where every pair of adjacent items in the `VecDeque` satisfies the condition
+pub type AdjacentVecDequeRule<RULE> = WindowsVecDequeRule<2, RULE>;
+
+/// Rule where every pair of adjacent chars in the `String` satisfies the condition
+pub type AdjacentStringRule<RULE> = AdjacentRule<RULE, String>;
+
+#[cfg(test)]
+mod tests {
+    use crate::define_rule;
+    use crate::result::Location;
+    use crate::rule::{
+        AdjacentString, AdjacentStringRule, AdjacentVec, AdjacentVecDeque, Invalid,
+        RuleDescription, Valid, WindowsVec, WindowsVecRule,
+    };
+    use serde_json::json;
+    use std::collections::VecDeque;
+
+    define_rule![
+        #[allow(dead_code)]
+        BoundedDelta,
+        [f64; 2],
+        |[prev, next]| (next - prev).abs() <= 1.5,
+        "the delta of {value} is too large"
+    ];
+
+    define_rule![
+        #[allow(dead_code)]
+        NotEqual,
+        [char; 2],
+        |[prev, next]| prev != next,
+        "{value} are equal"
+    ];
+
+    define_rule![
+        #[allow(dead_code)]
+        Monotonic,
+        [i32; 3],
+        |[a, b, c]| (a <= b && b <= c) || (a >= b && b >= c),
+        "{value} is not monotonic"
+    ];
+
+    #[test]
+    fn test_adjacent() {
+        let table = vec![
+            (vec![], true),
+            (vec![20.0], true),
+            (vec![20.0, 21.0, 19.5, 20.5], true),
+            (vec![20.0, 22.0], false),
+            (vec![20.0, 21.0, 23.0], false),
+        ];
+
+        for (value, expected) in table {
+            let refined = AdjacentVec::<BoundedDeltaRule>::new(value.clone());
+            assert_eq!(refined.is_ok(), expected, "{value:?}");
+        }
+
+        let readings = VecDeque::from([1.0, 2.0, 3.0]);
+        assert!(AdjacentVecDeque::<BoundedDeltaRule>::new(readings).is_ok());
+        assert!(AdjacentString::<NotEqualRule>::new("日本日".to_string()).is_ok());
+        assert!(AdjacentString::<NotEqualRule>::new("ああ".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_adjacent_reports_every_window() {
+        let value = vec![0.0, 5.0, 5.5, 0.0];
+        let err = AdjacentVec::<BoundedDeltaRule>::new(value.clone()).unwrap_err();
+        let locations = err
+            .tree()
+            .children()
+            .iter()
+            .map(|child| child.location().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![Some(Location::Index(0)), Some(Location::Index(2))]
+        );
+        assert_eq!(err.tree().rule(), Some("WindowsRule"));
+        assert_eq!(err.to_string(), "2 windows do not satisfy the condition");
+        assert_eq!(
+            err.tree().expected(),
+            Some("every window of 2 items satisfies the condition")
+        );
+        assert_eq!(err.into_value(), value);
+    }
+
+    #[test]
+    fn test_windows_3() {
+        let table = vec![
+            (vec![1, 2], true),
+            (vec![1, 2, 2, 5], true),
+            (vec![1, 2, 1], false),
+            (vec![5, 4, 6, 7], false),
+        ];
+
+        for (value, expected) in table {
+            let refined = WindowsVec::<3, MonotonicRule>::new(value.clone());
+            assert_eq!(refined.is_ok(), expected, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn test_windows_description() {
+        type Rule = WindowsVecRule<3, Invalid<[i32; 3]>>;
+        assert_eq!(Rule::description(), "for every window of 3 items, nothing");
+        assert_eq!(Rule::json_schema(), json!({ "type": "array" }));
+        assert_eq!(
+            AdjacentStringRule::<Valid<[char; 2]>>::json_schema(),
+            json!({ "type": "string" })
+        );
+    }
+}
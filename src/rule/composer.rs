@@ -1,19 +1,23 @@
 mod and;
+mod and_all;
 mod equiv;
 mod if_else;
 mod imply;
 mod nand;
 mod nor;
+mod normalized;
 mod not;
 mod or;
 mod xor;
 
 pub use and::And;
+pub use and_all::AndAll;
 pub use equiv::Equiv;
 pub use if_else::IfElse;
 pub use imply::{If, Imply};
 pub use nand::Nand;
 pub use nor::Nor;
+pub use normalized::Normalized;
 pub use not::Not;
 pub use or::Or;
 pub use xor::Xor;
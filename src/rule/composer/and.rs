@@ -1,5 +1,6 @@
-use crate::result::Error;
-use crate::rule::Rule;
+use crate::result::{Error, ErrorTree};
+use crate::rule::{description, ConstRule, Rule, RuleBorrow, RuleDescription, RuleRef};
+use serde_json::Value;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
@@ -41,10 +42,10 @@ pub struct And<RULE1, RULE2> {
     _rule2: PhantomData<RULE2>,
 }
 
-impl<'a, T, RULE1, RULE2> And<RULE1, RULE2>
+impl<T, RULE1, RULE2> And<RULE1, RULE2>
 where
-    RULE1: Rule<Item = T> + 'a,
-    RULE2: Rule<Item = T> + 'a,
+    RULE1: Rule<Item = T>,
+    RULE2: Rule<Item = T>,
 {
     pub fn new() -> Self {
         Self::default()
@@ -60,30 +61,117 @@ impl<RULE1, RULE2> Default for And<RULE1, RULE2> {
     }
 }
 
-impl<'a, T: Debug, RULE1, RULE2> Rule for And<RULE1, RULE2>
+impl<T: Debug, RULE1, RULE2> Rule for And<RULE1, RULE2>
 where
-    RULE1: Rule<Item = T> + 'a,
-    RULE2: Rule<Item = T> + 'a,
+    RULE1: Rule<Item = T>,
+    RULE2: Rule<Item = T>,
 {
     type Item = T;
 
     fn validate(target: Self::Item) -> crate::Result<T> {
-        match RULE1::validate(target) {
-            Ok(value) => RULE2::validate(value),
+        const { crate::rule::refuse_normalized::<RULE1>() };
+        const { crate::rule::refuse_normalized::<RULE2>() };
+        let (value, rule1_tree) = match RULE1::validate(target) {
+            Ok(value) => (value, None),
             Err(err) => {
-                let rule1_error_message = err.to_string();
-                match RULE2::validate(err.into_value()) {
-                    Ok(value) => Err(Error::new(value, rule1_error_message)),
-                    Err(err) => {
-                        let message = format!("[{rule1_error_message} && {err}]",);
-                        Err(Error::new(err.into_value(), message))
-                    }
-                }
+                let (value, tree) = err.into_parts();
+                (value, Some(tree))
             }
+        };
+        let (value, rule2_tree) = match RULE2::validate(value) {
+            Ok(value) => (value, None),
+            Err(err) => {
+                let (value, tree) = err.into_parts();
+                (value, Some(tree))
+            }
+        };
+        match and_tree(&value, rule1_tree, rule2_tree) {
+            Some(tree) => Err(Error::from_tree(value, tree)),
+            None => Ok(value),
         }
     }
 }
 
+impl<T: Debug, RULE1, RULE2> RuleRef for And<RULE1, RULE2>
+where
+    RULE1: RuleRef<Item = T>,
+    RULE2: RuleRef<Item = T>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        let rule1_tree = RULE1::validate_ref(target).err().map(|tree| *tree);
+        let rule2_tree = RULE2::validate_ref(target).err().map(|tree| *tree);
+        match and_tree(target, rule1_tree, rule2_tree) {
+            Some(tree) => Err(Box::new(tree)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<B: Debug + ?Sized, RULE1, RULE2> RuleBorrow<B> for And<RULE1, RULE2>
+where
+    RULE1: RuleBorrow<B>,
+    RULE2: RuleBorrow<B, Item = RULE1::Item>,
+    RULE1::Item: Debug,
+{
+    fn validate_borrow(target: &B) -> Result<(), Box<ErrorTree>> {
+        let rule1_tree = RULE1::validate_borrow(target).err().map(|tree| *tree);
+        let rule2_tree = RULE2::validate_borrow(target).err().map(|tree| *tree);
+        match and_tree(target, rule1_tree, rule2_tree) {
+            Some(tree) => Err(Box::new(tree)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Builds the error of `And` from the errors of its sub-rules, or returns `None` if both are satisfied
+fn and_tree<T: Debug + ?Sized>(
+    value: &T,
+    rule1_tree: Option<ErrorTree>,
+    rule2_tree: Option<ErrorTree>,
+) -> Option<ErrorTree> {
+    let (message, children) = match (rule1_tree, rule2_tree) {
+        (None, None) => return None,
+        (Some(tree), None) | (None, Some(tree)) => (tree.message().to_string(), vec![tree]),
+        (Some(rule1_tree), Some(rule2_tree)) => (
+            format!("[{rule1_tree} && {rule2_tree}]"),
+            vec![rule1_tree, rule2_tree],
+        ),
+    };
+    let tree = ErrorTree::new(message)
+        .with_rule("And")
+        .with_actual(format!("{value:?}"))
+        .with_children(children);
+    Some(tree)
+}
+
+impl<LITERAL, RULE1, RULE2> ConstRule<LITERAL> for And<RULE1, RULE2>
+where
+    RULE1: ConstRule<LITERAL>,
+    RULE2: ConstRule<LITERAL>,
+{
+    const VALID: bool = RULE1::VALID && RULE2::VALID;
+}
+
+impl<T: Debug, RULE1, RULE2> RuleDescription for And<RULE1, RULE2>
+where
+    RULE1: RuleDescription<Item = T>,
+    RULE2: RuleDescription<Item = T>,
+{
+    const OPERATOR: Option<&'static str> = Some("and");
+
+    fn description() -> String {
+        format!(
+            "{} and {}",
+            description::operand::<RULE1>("and"),
+            description::operand::<RULE2>("and")
+        )
+    }
+
+    fn json_schema() -> Value {
+        description::all_of(RULE1::json_schema(), RULE2::json_schema())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::rule::composer::And;
@@ -102,6 +190,30 @@ mod test {
         assert_eq!(Target::validate(11).unwrap_err().to_string(), "[the value must be even, but received 11 && the value must be less than 10, but received 11]");
     }
 
+    #[test]
+    fn test_rule_binder_err_tree() {
+        type Target = And![EvenRuleU8, LessRuleU8<10>];
+        let err = Target::validate(11).unwrap_err();
+        let tree = err.tree();
+        assert_eq!(tree.rule(), Some("And"));
+        assert_eq!(tree.actual(), Some("11"));
+        assert_eq!(
+            tree.children()
+                .iter()
+                .map(|child| child.rule())
+                .collect::<Vec<_>>(),
+            vec![Some("EvenRuleU8"), Some("LessRuleU8")]
+        );
+
+        let err = Target::validate(12).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the value must be less than 10, but received 12"
+        );
+        assert_eq!(err.tree().children().len(), 1);
+        assert_eq!(err.tree().children()[0].expected(), Some("less than 10"));
+    }
+
     #[test]
     fn test_rule_binder_macro_ok() {
         type SampleRule = And![EmailRule<String>, NonEmptyStringRule, EmailRule<String>];
@@ -0,0 +1,283 @@
+use crate::result::{Error, ErrorTree};
+use crate::rule::{description, ConstRule, Rule, RuleBorrow, RuleDescription, RuleRef};
+use serde_json::Value;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// A macro to generate a `Rule` that runs every given rule and reports all of their violations
+/// # Example
+/// ```rust
+/// use refined_type::rule::{EvenRuleU8, GreaterRuleU8, LessRuleU8, Rule};
+/// use refined_type::AndAll;
+///
+/// type Target = AndAll![EvenRuleU8, GreaterRuleU8<10>, LessRuleU8<20>];
+///
+/// assert!(Target::validate(12).is_ok());
+///
+/// let err = Target::validate(7).unwrap_err();
+/// assert_eq!(err.tree().children().len(), 2);
+/// assert_eq!(err.into_value(), 7);
+/// ```
+#[macro_export]
+macro_rules! AndAll {
+    ($rule1:ty, $rule2:ty) => {
+        $crate::rule::composer::AndAll<$rule1, $rule2>
+    };
+    ($rule1:ty, $($rule2: ty), +) => {
+        $crate::rule::composer::AndAll<$rule1, $crate::AndAll![$($rule2), +]>
+    }
+}
+
+/// A binder that combines two rules and, unlike `And`, reports every violated rule side by side
+///
+/// While `And` nests the errors of its sub-rules in the same shape as the composed type,
+/// `AndAll` flattens nested `AndAll`s so that the `ErrorTree` lists each violated rule as a direct child.
+/// # Example
+/// ```rust
+/// use refined_type::rule::composer::AndAll;
+/// use refined_type::rule::{EvenRuleU8, LessRuleU8, Rule};
+///
+/// type Target = AndAll<EvenRuleU8, LessRuleU8<10>>;
+///
+/// assert!(Target::validate(8).is_ok());
+///
+/// let err = Target::validate(11).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "[the value must be even, but received 11 && the value must be less than 10, but received 11]"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct AndAll<RULE1, RULE2> {
+    _rule1: PhantomData<RULE1>,
+    _rule2: PhantomData<RULE2>,
+}
+
+impl<RULE1, RULE2> AndAll<RULE1, RULE2> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<RULE1, RULE2> Default for AndAll<RULE1, RULE2> {
+    fn default() -> Self {
+        Self {
+            _rule1: Default::default(),
+            _rule2: Default::default(),
+        }
+    }
+}
+
+impl<T: Debug, RULE1, RULE2> Rule for AndAll<RULE1, RULE2>
+where
+    RULE1: Rule<Item = T>,
+    RULE2: Rule<Item = T>,
+{
+    type Item = T;
+    const LISTS_VIOLATIONS: bool = true;
+
+    fn validate(target: Self::Item) -> crate::Result<T> {
+        const { crate::rule::refuse_normalized::<RULE1>() };
+        const { crate::rule::refuse_normalized::<RULE2>() };
+        let mut violations = Vec::new();
+        let value = match RULE1::validate(target) {
+            Ok(value) => value,
+            Err(err) => {
+                let (value, tree) = err.into_parts();
+                violations.extend(flatten::<RULE1>(tree));
+                value
+            }
+        };
+        let value = match RULE2::validate(value) {
+            Ok(value) => value,
+            Err(err) => {
+                let (value, tree) = err.into_parts();
+                violations.extend(flatten::<RULE2>(tree));
+                value
+            }
+        };
+
+        match and_all_tree(&value, violations) {
+            Some(tree) => Err(Error::from_tree(value, tree)),
+            None => Ok(value),
+        }
+    }
+}
+
+impl<T: Debug, RULE1, RULE2> RuleRef for AndAll<RULE1, RULE2>
+where
+    RULE1: RuleRef<Item = T>,
+    RULE2: RuleRef<Item = T>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        let violations = [
+            RULE1::validate_ref(target).map_err(|tree| flatten::<RULE1>(*tree)),
+            RULE2::validate_ref(target).map_err(|tree| flatten::<RULE2>(*tree)),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .flatten()
+        .collect();
+        match and_all_tree(target, violations) {
+            Some(tree) => Err(Box::new(tree)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<B: Debug + ?Sized, RULE1, RULE2> RuleBorrow<B> for AndAll<RULE1, RULE2>
+where
+    RULE1: RuleBorrow<B>,
+    RULE2: RuleBorrow<B, Item = RULE1::Item>,
+    RULE1::Item: Debug,
+{
+    fn validate_borrow(target: &B) -> Result<(), Box<ErrorTree>> {
+        let violations = [
+            RULE1::validate_borrow(target).map_err(|tree| flatten::<RULE1>(*tree)),
+            RULE2::validate_borrow(target).map_err(|tree| flatten::<RULE2>(*tree)),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .flatten()
+        .collect();
+        match and_all_tree(target, violations) {
+            Some(tree) => Err(Box::new(tree)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Builds the error of `AndAll` from the flattened violations, or returns `None` if there are none
+fn and_all_tree<T: Debug + ?Sized>(value: &T, violations: Vec<ErrorTree>) -> Option<ErrorTree> {
+    let message = match violations.as_slice() {
+        [] => return None,
+        [violation] => violation.to_string(),
+        violations => format!(
+            "[{}]",
+            violations
+                .iter()
+                .map(|violation| violation.to_string())
+                .collect::<Vec<_>>()
+                .join(" && ")
+        ),
+    };
+    let tree = ErrorTree::new(message)
+        .with_rule("AndAll")
+        .with_actual(format!("{value:?}"))
+        .with_children(violations);
+    Some(tree)
+}
+
+impl<LITERAL, RULE1, RULE2> ConstRule<LITERAL> for AndAll<RULE1, RULE2>
+where
+    RULE1: ConstRule<LITERAL>,
+    RULE2: ConstRule<LITERAL>,
+{
+    const VALID: bool = RULE1::VALID && RULE2::VALID;
+}
+
+impl<T: Debug, RULE1, RULE2> RuleDescription for AndAll<RULE1, RULE2>
+where
+    RULE1: RuleDescription<Item = T>,
+    RULE2: RuleDescription<Item = T>,
+{
+    const OPERATOR: Option<&'static str> = Some("and");
+
+    fn description() -> String {
+        format!(
+            "{} and {}",
+            description::operand::<RULE1>("and"),
+            description::operand::<RULE2>("and")
+        )
+    }
+
+    fn json_schema() -> Value {
+        description::all_of(RULE1::json_schema(), RULE2::json_schema())
+    }
+}
+
+/// Lifts the violations listed by a nested `AndAll`, keeping the tree of any other rule as it is
+fn flatten<RULE: Rule>(tree: ErrorTree) -> Vec<ErrorTree> {
+    if RULE::LISTS_VIOLATIONS {
+        tree.into_children()
+    } else {
+        vec![tree]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::result::{Error, ErrorTree};
+    use crate::rule::composer::AndAll;
+    use crate::rule::{EvenRuleU8, GreaterRuleU8, LessRuleU8, Rule};
+
+    type Target = AndAll![EvenRuleU8, GreaterRuleU8<10>, LessRuleU8<20>];
+
+    #[test]
+    fn test_and_all_ok() {
+        assert!(Target::validate(12).is_ok());
+    }
+
+    #[test]
+    fn test_and_all_err_single() {
+        let err = Target::validate(22).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the value must be less than 20, but received 22"
+        );
+        assert_eq!(err.tree().children().len(), 1);
+    }
+
+    #[test]
+    fn test_and_all_err_every_violation() {
+        let err = Target::validate(7).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "[the value must be even, but received 7 && the value must be greater than 10, but received 7]"
+        );
+        assert_eq!(
+            err.tree()
+                .children()
+                .iter()
+                .map(|child| child.rule())
+                .collect::<Vec<_>>(),
+            vec![Some("EvenRuleU8"), Some("GreaterRuleU8")]
+        );
+        assert_eq!(err.into_value(), 7);
+    }
+
+    #[test]
+    fn test_and_all_flattens_both_sides() {
+        type Nested = AndAll<AndAll<EvenRuleU8, GreaterRuleU8<10>>, LessRuleU8<5>>;
+        let err = Nested::validate(7).unwrap_err();
+        assert_eq!(err.tree().children().len(), 3);
+    }
+
+    #[test]
+    fn test_and_all_keeps_other_rules_named_and_all() {
+        struct Impostor;
+
+        impl Rule for Impostor {
+            type Item = u8;
+
+            fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+                let tree = ErrorTree::new("impostor")
+                    .with_rule("AndAll")
+                    .with_children([ErrorTree::new("a"), ErrorTree::new("b")]);
+                Err(Error::from_tree(target, tree))
+            }
+        }
+
+        let err = AndAll::<Impostor, LessRuleU8<5>>::validate(7).unwrap_err();
+        assert_eq!(err.tree().children().len(), 2);
+        assert_eq!(err.tree().children()[0].message(), "impostor");
+    }
+
+    #[test]
+    fn test_and_all_new() {
+        assert_eq!(
+            AndAll::<EvenRuleU8, LessRuleU8<5>>::new(),
+            AndAll::default()
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use crate::rule::{description, Rule, RuleDescription, Transform};
+use serde_json::Value;
+use std::marker::PhantomData;
+
+/// `Normalized` applies a `Transform` to the value and then validates it by a `Rule`
+///
+/// The value stored by `Refined::new` and by `Deserialize` is the normalized one,
+/// and so is the value returned by the error of an invalid input.
+///
+/// `Normalized` must be the outermost rule. A composer or a collection rule would pass the normalized value out
+/// without checking it against its other rules, e.g. `And<NonEmptyStringRule, Normalized<TrimTransform, _>>`
+/// would accept `"   "` and hold `""`, so wrapping `Normalized` fails to compile.
+/// Put the composed rule inside instead, as in `Normalized<TrimTransform, And<..>>`,
+/// and chain the transforms as a tuple rather than nesting `Normalized`.
+///
+/// It does not implement `RuleRef`, since a borrowed value cannot be normalized in place,
+/// so `RefinedRef` and `RefinedStr` cannot hold a value that only satisfies the rule after normalization.
+/// # Example
+/// ```rust
+/// use refined_type::rule::composer::Normalized;
+/// use refined_type::rule::{AlphabetRule, Rule, TrimTransform};
+/// use refined_type::Refined;
+///
+/// type TrimmedAlphabet = Normalized<TrimTransform, AlphabetRule<String>>;
+///
+/// let name = Refined::<TrimmedAlphabet>::new("  Bob ".to_string()).unwrap();
+/// assert_eq!(name.into_value(), "Bob");
+///
+/// let err = TrimmedAlphabet::validate(" Bob 2 ".to_string()).unwrap_err();
+/// assert_eq!(err.into_value(), "Bob 2");
+/// ```
+///
+/// Wrapping it in a composer does not compile.
+/// ```compile_fail
+/// use refined_type::rule::composer::Normalized;
+/// use refined_type::rule::{NonEmptyStringRule, TrimTransform, Valid};
+/// use refined_type::{And, Refined};
+///
+/// type Trimmed = And![NonEmptyStringRule, Normalized<TrimTransform, Valid<String>>];
+///
+/// let _ = Refined::<Trimmed>::new("   ".to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Normalized<TRANSFORM, RULE> {
+    _transform: PhantomData<TRANSFORM>,
+    _rule: PhantomData<RULE>,
+}
+
+impl<T, TRANSFORM, RULE> Rule for Normalized<TRANSFORM, RULE>
+where
+    TRANSFORM: Transform<Item = T>,
+    RULE: Rule<Item = T>,
+{
+    type Item = T;
+
+    const NORMALIZES: bool = true;
+
+    fn validate(target: Self::Item) -> crate::Result<T> {
+        const { crate::rule::refuse_normalized::<RULE>() };
+        RULE::validate(TRANSFORM::transform(target))
+    }
+}
+
+/// The JSON Schema is that of `RULE`, which the normalized value satisfies
+impl<T, TRANSFORM, RULE> RuleDescription for Normalized<TRANSFORM, RULE>
+where
+    TRANSFORM: Transform<Item = T>,
+    RULE: RuleDescription<Item = T>,
+{
+    const OPERATOR: Option<&'static str> = Some("after");
+
+    fn description() -> String {
+        format!(
+            "{} after {}",
+            description::operand::<RULE>("after"),
+            TRANSFORM::description()
+        )
+    }
+
+    fn json_schema() -> Value {
+        RULE::json_schema()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::rule::composer::Normalized;
+    use crate::rule::{
+        CollapseWhitespaceTransform, EmailStringRule, LowercaseEmailDomainTransform,
+        LowercaseTransform, NonEmptyStringRule, Rule, RuleDescription, TrimTransform, Valid,
+    };
+    use crate::{And, Refined};
+
+    type Email = Normalized<(TrimTransform, LowercaseEmailDomainTransform), EmailStringRule>;
+
+    #[test]
+    fn test_normalized() {
+        let email = Refined::<Email>::new(" Bob@Example.COM\n".to_string()).unwrap();
+        assert_eq!(email.into_value(), "Bob@example.com");
+
+        type Name = Normalized<CollapseWhitespaceTransform, NonEmptyStringRule>;
+        assert_eq!(
+            Name::validate(" Bob   Smith ".to_string()).unwrap(),
+            "Bob Smith"
+        );
+        assert_eq!(
+            Name::validate("  \t ".to_string())
+                .unwrap_err()
+                .into_value(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_normalized_outermost() {
+        type Trimmed = Normalized<TrimTransform, And![NonEmptyStringRule, Valid<String>]>;
+        assert!(Refined::<Trimmed>::new("   ".to_string()).is_err());
+        assert_eq!(
+            Refined::<Trimmed>::new(" a ".to_string())
+                .unwrap()
+                .into_value(),
+            "a"
+        );
+    }
+
+    #[test]
+    fn test_normalized_deserialize() {
+        let email: Refined<Email> = serde_json::from_str("\" Bob@EXAMPLE.com \"").unwrap();
+        assert_eq!(email.into_value(), "Bob@example.com");
+        assert!(serde_json::from_str::<Refined<Email>>("\" bob \"").is_err());
+    }
+
+    #[test]
+    fn test_normalized_description() {
+        type Lowercase = Normalized<LowercaseTransform, NonEmptyStringRule>;
+        assert_eq!(
+            Lowercase::description(),
+            format!("{} after lowercasing", NonEmptyStringRule::description())
+        );
+        assert_eq!(Lowercase::json_schema(), NonEmptyStringRule::json_schema());
+    }
+}
@@ -1,5 +1,6 @@
-use crate::result::Error;
-use crate::rule::Rule;
+use crate::result::{rule_name, Error, ErrorTree};
+use crate::rule::{description, ConstRule, Rule, RuleBorrow, RuleDescription, RuleRef};
+use serde_json::Value;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
@@ -19,21 +20,18 @@ pub struct Not<RULE> {
     _rule: PhantomData<RULE>,
 }
 
-impl<'a, T: Debug, RULE> Rule for Not<RULE>
+impl<T: Debug, RULE> Rule for Not<RULE>
 where
-    RULE: Rule<Item = T> + 'a,
+    RULE: Rule<Item = T>,
 {
     type Item = T;
 
     fn validate(target: Self::Item) -> crate::Result<T> {
+        const { crate::rule::refuse_normalized::<RULE>() };
         let bounded_rule = |t: T| match RULE::validate(t) {
             Ok(value) => {
-                let type_name = std::any::type_name::<RULE>()
-                    .replace("refined_type::rule::composer::or::Or", "Or")
-                    .replace("refined_type::rule::composer::and::And", "And")
-                    .replace("refined_type::rule::composer::not::Not", "Not");
-                let message = format!("{value:?} does not satisfy Not<{type_name}>");
-                Err(Error::new(value, message))
+                let tree = not_tree::<RULE, T>(&value);
+                Err(Error::from_tree(value, tree))
             }
             Err(err) => Ok(err.into_value()),
         };
@@ -41,6 +39,59 @@ where
     }
 }
 
+impl<T: Debug, RULE> RuleRef for Not<RULE>
+where
+    RULE: RuleRef<Item = T>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        match RULE::validate_ref(target) {
+            Ok(()) => Err(Box::new(not_tree::<RULE, T>(target))),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+impl<B: Debug + ?Sized, RULE> RuleBorrow<B> for Not<RULE>
+where
+    RULE: RuleBorrow<B>,
+    RULE::Item: Debug,
+{
+    fn validate_borrow(target: &B) -> Result<(), Box<ErrorTree>> {
+        match RULE::validate_borrow(target) {
+            Ok(()) => Err(Box::new(not_tree::<RULE, B>(target))),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+fn not_tree<RULE, T: Debug + ?Sized>(value: &T) -> ErrorTree {
+    let rule = rule_name::<RULE>();
+    ErrorTree::new(format!("{value:?} does not satisfy Not<{rule}>"))
+        .with_rule("Not")
+        .with_expected(format!("not {rule}"))
+        .with_actual(format!("{value:?}"))
+}
+
+impl<LITERAL, RULE> ConstRule<LITERAL> for Not<RULE>
+where
+    RULE: ConstRule<LITERAL>,
+{
+    const VALID: bool = !RULE::VALID;
+}
+
+impl<T: Debug, RULE> RuleDescription for Not<RULE>
+where
+    RULE: RuleDescription<Item = T>,
+{
+    fn description() -> String {
+        format!("not {}", description::operand::<RULE>("not"))
+    }
+
+    fn json_schema() -> Value {
+        description::not(RULE::json_schema())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::rule::composer::Not;
@@ -50,6 +101,21 @@ mod test {
     fn test_not() {
         type NonNonEmptyString = Not<NonEmptyStringRule>;
         assert!(NonNonEmptyString::validate("".to_string()).is_ok());
-        assert_eq!(NonNonEmptyString::validate("Hello".to_string()).unwrap_err().to_string(), "\"Hello\" does not satisfy Not<Not<refined_type::rule::empty::EmptyRule<alloc::string::String>>>")
+        assert_eq!(
+            NonNonEmptyString::validate("Hello".to_string())
+                .unwrap_err()
+                .to_string(),
+            "\"Hello\" does not satisfy Not<Not<EmptyRule<String>>>"
+        )
+    }
+
+    #[test]
+    fn test_not_err_tree() {
+        type NonNonEmptyString = Not<NonEmptyStringRule>;
+        let err = NonNonEmptyString::validate("Hello".to_string()).unwrap_err();
+        let tree = err.tree();
+        assert_eq!(tree.rule(), Some("Not"));
+        assert_eq!(tree.expected(), Some("not Not<EmptyRule<String>>"));
+        assert_eq!(tree.actual(), Some("\"Hello\""));
     }
 }
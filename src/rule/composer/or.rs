@@ -1,5 +1,6 @@
-use crate::result::Error;
-use crate::rule::Rule;
+use crate::result::{Error, ErrorTree};
+use crate::rule::{description, ConstRule, Rule, RuleBorrow, RuleDescription, RuleRef};
+use serde_json::Value;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
@@ -41,26 +42,26 @@ pub struct Or<RULE1, RULE2> {
     _rule2: PhantomData<RULE2>,
 }
 
-impl<'a, T: Debug, RULE1, RULE2> Rule for Or<RULE1, RULE2>
+impl<T: Debug, RULE1, RULE2> Rule for Or<RULE1, RULE2>
 where
-    RULE1: Rule<Item = T> + 'a,
-    RULE2: Rule<Item = T> + 'a,
+    RULE1: Rule<Item = T>,
+    RULE2: Rule<Item = T>,
 {
     type Item = T;
 
     fn validate(target: Self::Item) -> crate::Result<T> {
+        const { crate::rule::refuse_normalized::<RULE1>() };
+        const { crate::rule::refuse_normalized::<RULE2>() };
         let bounded_rule = |t: T| match RULE1::validate(t) {
             Ok(value) => Ok(value),
             Err(err) => {
-                let rule1_error_message = err.to_string();
-                match RULE2::validate(err.into_value()) {
+                let (value, rule1_tree) = err.into_parts();
+                match RULE2::validate(value) {
                     Ok(value) => Ok(value),
                     Err(err) => {
-                        let rule2_error_message = err.to_string();
-                        Err(Error::new(
-                            err.into_value(),
-                            format!("[{rule1_error_message} || {rule2_error_message}]"),
-                        ))
+                        let (value, rule2_tree) = err.into_parts();
+                        let tree = or_tree(&value, rule1_tree, rule2_tree);
+                        Err(Error::from_tree(value, tree))
                     }
                 }
             }
@@ -69,10 +70,80 @@ where
     }
 }
 
+impl<T: Debug, RULE1, RULE2> RuleRef for Or<RULE1, RULE2>
+where
+    RULE1: RuleRef<Item = T>,
+    RULE2: RuleRef<Item = T>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        match RULE1::validate_ref(target) {
+            Ok(()) => Ok(()),
+            Err(rule1_tree) => RULE2::validate_ref(target)
+                .map_err(|rule2_tree| Box::new(or_tree(target, *rule1_tree, *rule2_tree))),
+        }
+    }
+}
+
+impl<B: Debug + ?Sized, RULE1, RULE2> RuleBorrow<B> for Or<RULE1, RULE2>
+where
+    RULE1: RuleBorrow<B>,
+    RULE2: RuleBorrow<B, Item = RULE1::Item>,
+    RULE1::Item: Debug,
+{
+    fn validate_borrow(target: &B) -> Result<(), Box<ErrorTree>> {
+        match RULE1::validate_borrow(target) {
+            Ok(()) => Ok(()),
+            Err(rule1_tree) => RULE2::validate_borrow(target)
+                .map_err(|rule2_tree| Box::new(or_tree(target, *rule1_tree, *rule2_tree))),
+        }
+    }
+}
+
+fn or_tree<T: Debug + ?Sized>(
+    value: &T,
+    rule1_tree: ErrorTree,
+    rule2_tree: ErrorTree,
+) -> ErrorTree {
+    ErrorTree::new(format!("[{rule1_tree} || {rule2_tree}]"))
+        .with_rule("Or")
+        .with_actual(format!("{value:?}"))
+        .with_children([rule1_tree, rule2_tree])
+}
+
+impl<LITERAL, RULE1, RULE2> ConstRule<LITERAL> for Or<RULE1, RULE2>
+where
+    RULE1: ConstRule<LITERAL>,
+    RULE2: ConstRule<LITERAL>,
+{
+    const VALID: bool = RULE1::VALID || RULE2::VALID;
+}
+
+impl<T: Debug, RULE1, RULE2> RuleDescription for Or<RULE1, RULE2>
+where
+    RULE1: RuleDescription<Item = T>,
+    RULE2: RuleDescription<Item = T>,
+{
+    const OPERATOR: Option<&'static str> = Some("or");
+
+    fn description() -> String {
+        format!(
+            "{} or {}",
+            description::operand::<RULE1>("or"),
+            description::operand::<RULE2>("or")
+        )
+    }
+
+    fn json_schema() -> Value {
+        description::any_of(RULE1::json_schema(), RULE2::json_schema())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::rule::composer::Or;
-    use crate::rule::{AlphabetRule, EmailRule, NonEmptyStringRule, Rule};
+    use crate::rule::{
+        AlphabetRule, EmailRule, GreaterRuleU8, LessRuleU8, NonEmptyStringRule, Rule,
+    };
 
     #[test]
     fn test_or() {
@@ -88,9 +159,25 @@ mod test {
         assert!(SampleRule::validate("hoge".to_string()).is_ok());
     }
 
+    #[test]
+    fn test_or_err_tree() {
+        type Target = Or![LessRuleU8<10>, GreaterRuleU8<50>];
+        let err = Target::validate(20).unwrap_err();
+        let tree = err.tree();
+        assert_eq!(tree.rule(), Some("Or"));
+        assert_eq!(
+            tree.children()
+                .iter()
+                .map(|child| child.expected())
+                .collect::<Vec<_>>(),
+            vec![Some("less than 10"), Some("greater than 50")]
+        );
+        assert_eq!(err.into_value(), 20);
+    }
+
     #[test]
     fn test_rule_binder_macro_err() {
         type SampleRule = Or![EmailRule<String>, NonEmptyStringRule];
-        assert_eq!(SampleRule::validate("".to_string()).unwrap_err().to_string(), "[\"\" does not match the regex pattern ^[a-zA-Z0-9_.+-]+@([a-zA-Z0-9][a-zA-Z0-9-]*[a-zA-Z0-9]*\\.)+[a-zA-Z]{2,}$ || \"\" does not satisfy Not<refined_type::rule::empty::EmptyRule<alloc::string::String>>]");
+        assert_eq!(SampleRule::validate("".to_string()).unwrap_err().to_string(), "[\"\" does not match the regex pattern ^[a-zA-Z0-9_.+-]+@([a-zA-Z0-9][a-zA-Z0-9-]*[a-zA-Z0-9]*\\.)+[a-zA-Z]{2,}$ || \"\" does not satisfy Not<EmptyRule<String>>]");
     }
 }
@@ -0,0 +1,122 @@
+use crate::rule::Rule;
+use crate::Refined;
+use std::cmp::Ordering;
+
+/// This is a `trait` that tells at compile time whether the literal `LITERAL` satisfies the rule
+///
+/// It is what `refined!` uses to reject invalid literals during compilation.
+/// A literal is encoded into a type by `IntLiteral` or `StrLiteral`.
+/// # Example
+/// ```rust
+/// use refined_type::refined;
+/// use refined_type::rule::{NonEmptyString, RangeU8};
+///
+/// let name = refined!(NonEmptyString, "hello");
+/// assert_eq!(name.into_value(), "hello");
+///
+/// let level = refined!(RangeU8<1, 10>, 5);
+/// assert_eq!(level.into_value(), 5);
+/// ```
+///
+/// An invalid literal does not compile.
+/// ```compile_fail
+/// use refined_type::refined;
+/// use refined_type::rule::RangeU8;
+///
+/// let level = refined!(RangeU8<1, 10>, 10);
+/// ```
+pub trait ConstRule<LITERAL> {
+    const VALID: bool;
+}
+
+/// An integer literal encoded into a type, whose value is `-ABS` when `NEGATIVE` and `ABS` otherwise
+pub struct IntLiteral<const NEGATIVE: bool, const ABS: u128>;
+
+impl<const NEGATIVE: bool, const ABS: u128> IntLiteral<NEGATIVE, ABS> {
+    /// Compares the literal with `other`, which is given both as `i128` and `u128` so that any integer type fits
+    pub(crate) const fn compare(
+        other_signed: i128,
+        other_unsigned: u128,
+        signed: bool,
+    ) -> Ordering {
+        let (other_negative, other_abs) = if signed && other_signed < 0 {
+            (true, other_signed.unsigned_abs())
+        } else {
+            (false, other_unsigned)
+        };
+        match (NEGATIVE, other_negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_u128(ABS, other_abs),
+            (true, true) => compare_u128(other_abs, ABS),
+        }
+    }
+
+    pub(crate) const fn is_even() -> bool {
+        ABS.is_multiple_of(2)
+    }
+
+    pub(crate) const fn is_zero() -> bool {
+        ABS == 0
+    }
+}
+
+const fn compare_u128(lhs: u128, rhs: u128) -> Ordering {
+    if lhs < rhs {
+        Ordering::Less
+    } else if lhs > rhs {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// A string literal encoded into a type by its number of `char`s
+pub struct StrLiteral<const CHARS: usize>;
+
+/// Used by `refined!` to check a literal at compile time and to build `Refined` from it
+#[doc(hidden)]
+pub trait ConstRefined<LITERAL>: Sized {
+    type Item;
+
+    const VALID: bool;
+
+    fn from_literal(item: Self::Item) -> Self;
+}
+
+impl<RULE, LITERAL> ConstRefined<LITERAL> for Refined<RULE>
+where
+    RULE: Rule + ConstRule<LITERAL>,
+{
+    type Item = RULE::Item;
+
+    const VALID: bool = RULE::VALID;
+
+    fn from_literal(item: Self::Item) -> Self {
+        Refined::new_unchecked(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::IntLiteral;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_compare_int_literal() {
+        assert_eq!(
+            IntLiteral::<false, 5>::compare(3, 3, true),
+            Ordering::Greater
+        );
+        assert_eq!(IntLiteral::<true, 5>::compare(-3, 0, true), Ordering::Less);
+        assert_eq!(
+            IntLiteral::<true, 5>::compare(-7, 0, true),
+            Ordering::Greater
+        );
+        assert_eq!(IntLiteral::<false, 0>::compare(0, 0, true), Ordering::Equal);
+        assert_eq!(
+            IntLiteral::<false, { u128::MAX }>::compare(-1, u128::MAX, false),
+            Ordering::Equal
+        );
+    }
+}
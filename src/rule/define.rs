@@ -0,0 +1,215 @@
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+
+/// The outcome of a predicate given to `define_rule!`
+///
+/// A predicate returns either a `bool` or a `Result<(), E>` whose error explains why the value was rejected.
+pub trait PredicateOutcome {
+    /// Returns the reason of the rejection if the value does not satisfy the predicate
+    fn into_result(self) -> Result<(), String>;
+}
+
+impl PredicateOutcome for bool {
+    fn into_result(self) -> Result<(), String> {
+        if self {
+            Ok(())
+        } else {
+            Err(String::new())
+        }
+    }
+}
+
+impl<E: Display> PredicateOutcome for Result<(), E> {
+    fn into_result(self) -> Result<(), String> {
+        self.map_err(|e| e.to_string())
+    }
+}
+
+/// Evaluates the predicate of `define_rule!`, which lets the compiler infer the type of a closure's parameter
+pub fn evaluate_predicate<T, O: PredicateOutcome>(
+    predicate: impl FnOnce(&T) -> O,
+    target: &T,
+) -> Result<(), String> {
+    predicate(target).into_result()
+}
+
+/// Gives the `Debug` rendering of `T` to `define_rule!` if `T` implements `Debug`, and `None` otherwise
+///
+/// The inherent `DEBUG` takes precedence over that of `NoDebug` whenever its bound holds.
+pub struct DebugOf<T>(PhantomData<T>);
+
+impl<T: Debug> DebugOf<T> {
+    pub const DEBUG: Option<fn(&T) -> String> = Some(|value| format!("{value:?}"));
+}
+
+/// The fallback of `DebugOf::DEBUG` for a `T` that does not implement `Debug`
+pub trait NoDebug<T> {
+    const DEBUG: Option<fn(&T) -> String> = None;
+}
+
+impl<T> NoDebug<T> for DebugOf<T> {}
+
+/// Returns `true` if the message template of `define_rule!` contains `{value}`
+pub const fn uses_value(message: &str) -> bool {
+    let message = message.as_bytes();
+    let placeholder = b"{value}";
+    let mut start = 0;
+    while start + placeholder.len() <= message.len() {
+        let mut i = 0;
+        while i < placeholder.len() && message[start + i] == placeholder[i] {
+            i += 1;
+        }
+        if i == placeholder.len() {
+            return true;
+        }
+        start += 1;
+    }
+    false
+}
+
+#[macro_export]
+/// A macro to define a `Rule` from a predicate and a message template
+///
+/// `define_rule![Name, T, predicate, message]` defines `NameRule` and the alias `Name = Refined<NameRule>`.
+/// The predicate takes `&T` and returns a `bool` or a `Result<(), E>`.
+/// In the message, `{value}` is replaced with the rejected value and `{reason}` with the error of the predicate.
+/// `T` must implement `Debug` only when the message contains `{value}`,
+/// and the `ErrorTree` has the rejected value as `actual` only when it does.
+/// ```rust
+/// use refined_type::define_rule;
+///
+/// define_rule![pub Port, u16, |port| *port != 0, "the port must not be zero, but received {value}"];
+///
+/// assert!(Port::new(8080).is_ok());
+///
+/// let error = Port::new(0).unwrap_err();
+/// assert_eq!(error.to_string(), "the port must not be zero, but received 0");
+/// ```
+///
+/// `{value}` fails the compilation for a `T` that does not implement `Debug`.
+/// ```compile_fail
+/// use refined_type::define_rule;
+///
+/// pub struct Secret(String);
+///
+/// define_rule![pub StrongSecret, Secret, |secret| secret.0.len() >= 12, "{value} is too short"];
+/// ```
+macro_rules! define_rule {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $t:ty, $predicate:expr, $message:literal) => {
+        $crate::paste::item! {
+            $(#[$meta])*
+            $vis type $name = $crate::Refined<[<$name Rule>]>;
+
+            #[doc = concat!("Rule where the target value satisfies the predicate of `", stringify!($name), "`")]
+            #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+            $vis struct [<$name Rule>];
+
+            impl $crate::rule::Rule for [<$name Rule>] {
+                type Item = $t;
+
+                fn validate(target: Self::Item) -> Result<Self::Item, $crate::result::Error<Self::Item>> {
+                    match <Self as $crate::rule::RuleRef>::validate_ref(&target) {
+                        Ok(()) => Ok(target),
+                        Err(tree) => Err($crate::result::Error::from_tree(target, *tree)),
+                    }
+                }
+            }
+
+            impl $crate::rule::RuleRef for [<$name Rule>] {
+                fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    #[allow(unused_imports)]
+                    use $crate::__private::NoDebug as _;
+
+                    const DEBUG: Option<fn(&$t) -> String> = $crate::__private::DebugOf::<$t>::DEBUG;
+                    const {
+                        assert!(
+                            DEBUG.is_some() || !$crate::__private::uses_value($message),
+                            "`{{value}}` in the message of `define_rule!` needs a type implementing `Debug`"
+                        )
+                    };
+                    $crate::__private::evaluate_predicate($predicate, target).map_err(|reason| {
+                        let actual = DEBUG.map(|debug| debug(target));
+                        let message = match &actual {
+                            Some(actual) => $message.replace("{value}", actual),
+                            None => $message.to_string(),
+                        }
+                        .replace("{reason}", &reason);
+                        let tree = $crate::result::ErrorTree::new(message)
+                            .with_rule(stringify!([<$name Rule>]));
+                        Box::new(match actual {
+                            Some(actual) => tree.with_actual(actual),
+                            None => tree,
+                        })
+                    })
+                }
+            }
+        }
+    };
+    ($(($($rule:tt)+)),+) => {
+        $($crate::define_rule!($($rule)+);)+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::Rule;
+
+    define_rule![Even, u32, |n| n % 2 == 0, "{value} is not even"];
+
+    define_rule![
+        (Lowercase, String, |s| s.chars().all(char::is_lowercase), "{value} is not lowercase"),
+        (Slug, &'static str, validate_slug, "invalid slug {value}: {reason}")
+    ];
+
+    fn validate_slug(s: &&str) -> Result<(), String> {
+        match s.chars().find(|c| !c.is_ascii_alphanumeric() && *c != '-') {
+            Some(c) => Err(format!("{c:?} is not allowed")),
+            None => Ok(()),
+        }
+    }
+
+    #[test]
+    fn test_define_rule_bool() {
+        assert!(Even::new(2).is_ok());
+
+        let error = EvenRule::validate(3).unwrap_err();
+        assert_eq!(error.to_string(), "3 is not even");
+        assert_eq!(error.tree().rule(), Some("EvenRule"));
+        assert_eq!(error.tree().actual(), Some("3"));
+    }
+
+    #[test]
+    fn test_define_rule_without_debug() {
+        struct Secret(&'static str);
+
+        define_rule![
+            StrongSecret,
+            Secret,
+            |secret| secret.0.len() >= 12,
+            "the secret is too short"
+        ];
+
+        assert!(StrongSecret::new(Secret("correct horse battery")).is_ok());
+
+        let error = StrongSecretRule::validate(Secret("hunter2")).err().unwrap();
+        assert_eq!(error.tree().message(), "the secret is too short");
+        assert_eq!(error.tree().actual(), None);
+    }
+
+    #[test]
+    fn test_define_rule_multiple() {
+        assert!(Lowercase::new("abc".to_string()).is_ok());
+        assert!(Lowercase::new("aBc".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_define_rule_result() {
+        assert!(Slug::new("hello-world").is_ok());
+
+        let error = Slug::new("hello world").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid slug \"hello world\": ' ' is not allowed"
+        );
+    }
+}
@@ -0,0 +1,547 @@
+use crate::rule::Rule;
+use serde_json::{json, Map, Number, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::fmt::Display;
+
+/// This is a `trait` that describes the conditions of a `Rule`, both for humans and as a JSON Schema
+///
+/// `description` reads like the `expected` of an `ErrorTree`, e.g. `greater than 10`,
+/// and `json_schema` is a JSON Schema (draft 2020-12) fragment that every serialized value
+/// satisfying the rule conforms to. Composers map to `allOf`, `anyOf` and `not`,
+/// merging the fragments of their sub-rules where the result is equivalent.
+///
+/// Rules whose condition has no JSON Schema counterpart, such as `LastRule` or `define_rule!` rules,
+/// do not implement it, so that the schema of a rule, and of its negation by `Not`, stays exact.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{RangeRuleU8, RuleDescription};
+/// use serde_json::json;
+///
+/// assert_eq!(
+///     RangeRuleU8::<1, 10>::description(),
+///     "(equal to 1 or greater than 1) and less than 10"
+/// );
+/// assert_eq!(
+///     RangeRuleU8::<1, 10>::json_schema(),
+///     json!({ "type": "integer", "minimum": 1, "exclusiveMaximum": 10 })
+/// );
+/// ```
+pub trait RuleDescription: Rule {
+    /// The operator that joins the descriptions of the sub-rules, e.g. `and` for `And`,
+    /// which tells a composer whether to parenthesize this description
+    #[doc(hidden)]
+    const OPERATOR: Option<&'static str> = None;
+
+    fn description() -> String;
+
+    fn json_schema() -> Value;
+}
+
+/// The type of JSON value that a Rust type is serialized into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonType {
+    Boolean,
+    Integer,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+impl JsonType {
+    /// The name of the type, as used by the `type` keyword
+    pub fn name(&self) -> &'static str {
+        match self {
+            JsonType::Boolean => "boolean",
+            JsonType::Integer => "integer",
+            JsonType::Number => "number",
+            JsonType::String => "string",
+            JsonType::Array => "array",
+            JsonType::Object => "object",
+        }
+    }
+
+    /// The keywords that bound the length of a value of this type, e.g. `minLength` and `maxLength`
+    pub fn length_keywords(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            JsonType::String => Some(("minLength", "maxLength")),
+            JsonType::Array => Some(("minItems", "maxItems")),
+            JsonType::Object => Some(("minProperties", "maxProperties")),
+            _ => None,
+        }
+    }
+
+    /// A schema that only requires this type
+    pub fn schema(&self) -> Value {
+        json!({ "type": self.name() })
+    }
+}
+
+/// This is a `trait` that tells the `JsonType` a type is serialized into
+pub trait JsonTyped {
+    const JSON_TYPE: JsonType;
+}
+
+macro_rules! impl_json_typed {
+    ($json_type: ident => $($t: ty),+) => {
+        $(impl JsonTyped for $t {
+            const JSON_TYPE: JsonType = JsonType::$json_type;
+        })+
+    };
+}
+
+impl_json_typed!(Boolean => bool);
+impl_json_typed!(Integer => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_json_typed!(Number => f32, f64);
+impl_json_typed!(String => char, str, String);
+
+impl JsonTyped for &str {
+    const JSON_TYPE: JsonType = JsonType::String;
+}
+
+impl<T> JsonTyped for [T] {
+    const JSON_TYPE: JsonType = JsonType::Array;
+}
+
+impl<T> JsonTyped for Vec<T> {
+    const JSON_TYPE: JsonType = JsonType::Array;
+}
+
+impl<T> JsonTyped for VecDeque<T> {
+    const JSON_TYPE: JsonType = JsonType::Array;
+}
+
+impl<T> JsonTyped for LinkedList<T> {
+    const JSON_TYPE: JsonType = JsonType::Array;
+}
+
+impl<T, S> JsonTyped for HashSet<T, S> {
+    const JSON_TYPE: JsonType = JsonType::Array;
+}
+
+impl<T> JsonTyped for BTreeSet<T> {
+    const JSON_TYPE: JsonType = JsonType::Array;
+}
+
+impl<K, V, S> JsonTyped for HashMap<K, V, S> {
+    const JSON_TYPE: JsonType = JsonType::Object;
+}
+
+impl<K, V> JsonTyped for BTreeMap<K, V> {
+    const JSON_TYPE: JsonType = JsonType::Object;
+}
+
+/// Returns the description of `RULE`, parenthesized unless it is joined by `operator` as well
+pub(crate) fn operand<RULE: RuleDescription>(operator: &str) -> String {
+    match RULE::OPERATOR {
+        Some(inner) if inner != operator => format!("({})", RULE::description()),
+        _ => RULE::description(),
+    }
+}
+
+/// Converts an integer into a JSON number without going through a narrower type
+pub(crate) fn json_number(value: impl Display) -> Value {
+    value
+        .to_string()
+        .parse::<Number>()
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+/// Converts a float into a JSON number
+pub(crate) fn json_float(value: f64) -> Value {
+    Number::from_f64(value)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+/// A schema of `json_type` with the keywords
+pub(crate) fn typed_schema<const N: usize>(
+    json_type: JsonType,
+    keywords: [(&str, Value); N],
+) -> Value {
+    let mut map = Map::new();
+    map.insert("type".to_string(), json!(json_type.name()));
+    map.extend(
+        keywords
+            .into_iter()
+            .map(|(keyword, value)| (keyword.to_string(), value)),
+    );
+    Value::Object(map)
+}
+
+/// A schema of `json_type` whose length is bounded by `min` and `max`, both inclusive
+pub(crate) fn length_schema(json_type: JsonType, min: Option<usize>, max: Option<usize>) -> Value {
+    let mut schema = json_type.schema();
+    if let (Some((min_keyword, max_keyword)), Value::Object(map)) =
+        (json_type.length_keywords(), &mut schema)
+    {
+        if let Some(min) = min {
+            map.insert(min_keyword.to_string(), json!(min));
+        }
+        if let Some(max) = max {
+            map.insert(max_keyword.to_string(), json!(max));
+        }
+    }
+    schema
+}
+
+/// The schema of values that satisfy both schemas
+///
+/// The keywords are merged into one object as long as they do not conflict, and the rest goes to `allOf`.
+/// Two bounds of the same kind, e.g. two `minimum`s, are merged into the tighter one.
+pub(crate) fn all_of(schema1: Value, schema2: Value) -> Value {
+    let mut schemas = Vec::new();
+    for schema in [schema1, schema2] {
+        match schema {
+            Value::Object(mut map) => {
+                let nested = map.remove("allOf");
+                schemas.push(Value::Object(map));
+                match nested {
+                    Some(Value::Array(nested)) => schemas.extend(nested),
+                    Some(nested) => schemas.push(nested),
+                    None => {}
+                }
+            }
+            schema => schemas.push(schema),
+        }
+    }
+    let mut merged = Map::new();
+    let mut rest = Vec::new();
+    for schema in schemas {
+        match schema {
+            Value::Object(map) => match merge_keywords(&merged, &map) {
+                Some(keywords) => merged.extend(keywords),
+                None => rest.push(Value::Object(map)),
+            },
+            schema => rest.push(schema),
+        }
+    }
+    if !rest.is_empty() {
+        merged.insert("allOf".to_string(), Value::Array(rest));
+    }
+    Value::Object(merged)
+}
+
+/// Merges the keywords of `map` into those of `merged`, or returns `None` if any of them conflicts
+fn merge_keywords(
+    merged: &Map<String, Value>,
+    map: &Map<String, Value>,
+) -> Option<Map<String, Value>> {
+    map.iter()
+        .map(|(keyword, value)| {
+            let value = match merged.get(keyword) {
+                None => value.clone(),
+                Some(current) if current == value => value.clone(),
+                Some(current) => merge_keyword(keyword, current, value)?,
+            };
+            Some((keyword.clone(), value))
+        })
+        .collect()
+}
+
+fn merge_keyword(keyword: &str, current: &Value, value: &Value) -> Option<Value> {
+    let tighter = |pick_greater: bool| {
+        let greater = current.as_f64()? < value.as_f64()?;
+        Some(
+            if greater == pick_greater {
+                value
+            } else {
+                current
+            }
+            .clone(),
+        )
+    };
+    match keyword {
+        "items" | "additionalProperties" => Some(all_of(current.clone(), value.clone())),
+        "minimum" | "exclusiveMinimum" | "minLength" | "minItems" | "minProperties"
+        | "minContains" => tighter(true),
+        "maximum" | "exclusiveMaximum" | "maxLength" | "maxItems" | "maxProperties"
+        | "maxContains" => tighter(false),
+        _ => None,
+    }
+}
+
+/// The schema of values that satisfy either schema
+///
+/// A `const` next to the exclusive bound of the same value, as in `GreaterEqualRule`, becomes an inclusive bound.
+pub(crate) fn any_of(schema1: Value, schema2: Value) -> Value {
+    if schema1 == schema2 {
+        return schema1;
+    }
+    if let Some(schema) =
+        inclusive_bound(&schema1, &schema2).or_else(|| inclusive_bound(&schema2, &schema1))
+    {
+        return schema;
+    }
+    let mut schemas = Vec::new();
+    for schema in [schema1, schema2] {
+        match schema {
+            Value::Object(mut map) if map.len() == 1 && map.contains_key("anyOf") => {
+                match map.remove("anyOf") {
+                    Some(Value::Array(nested)) => schemas.extend(nested),
+                    Some(nested) => schemas.push(nested),
+                    None => {}
+                }
+            }
+            schema => schemas.push(schema),
+        }
+    }
+    json!({ "anyOf": schemas })
+}
+
+/// The schema of values that do not satisfy the schema
+///
+/// The `type` stays outside of `not`, since the Rust type already decides it.
+pub(crate) fn not(schema: Value) -> Value {
+    let Value::Object(mut map) = schema else {
+        return json!({ "not": schema });
+    };
+    let json_type = map.remove("type");
+    let negated = negate(map);
+    match json_type {
+        Some(json_type) => all_of(json!({ "type": json_type }), negated),
+        None => negated,
+    }
+}
+
+fn negate(map: Map<String, Value>) -> Value {
+    if map.len() == 1 {
+        let (keyword, value) = map.iter().next().expect("map has one entry");
+        match (keyword.as_str(), value) {
+            ("not", schema) => return schema.clone(),
+            ("maxLength", value) if value == 0 => return json!({ "minLength": 1 }),
+            ("maxItems", value) if value == 0 => return json!({ "minItems": 1 }),
+            ("maxProperties", value) if value == 0 => return json!({ "minProperties": 1 }),
+            ("items", schema) => return json!({ "contains": not(schema.clone()) }),
+            _ => {}
+        }
+    }
+    json!({ "not": map })
+}
+
+fn inclusive_bound(constant: &Value, bound: &Value) -> Option<Value> {
+    let (mut constant, mut bound) = (constant.as_object()?.clone(), bound.as_object()?.clone());
+    let json_type = constant.remove("type");
+    if json_type != bound.remove("type") || constant.len() != 1 || bound.len() != 1 {
+        return None;
+    }
+    let value = constant.get("const")?;
+    let inclusive = [
+        ("exclusiveMinimum", "minimum"),
+        ("exclusiveMaximum", "maximum"),
+    ]
+    .into_iter()
+    .find_map(|(exclusive, inclusive)| {
+        (bound.get(exclusive) == Some(value)).then_some(inclusive)
+    })?;
+    let mut map = Map::new();
+    if let Some(json_type) = json_type {
+        map.insert("type".to_string(), json_type);
+    }
+    map.insert(inclusive.to_string(), value.clone());
+    Some(Value::Object(map))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::description::{all_of, any_of, not};
+    use crate::rule::{
+        CountEqualVecRule, EmailStringRule, ExistsVecRule, ForAllVecRule, GreaterEqualRuleF64,
+        HeadVecRule, Ipv4AddrRule, LengthEqualHashMapRule, LengthLessStringRule, MinMaxRuleI32,
+        NonEmptyStringRule, NonEmptyVecRule, OddRuleU8, RuleDescription, TailVecRule,
+    };
+    use crate::Or;
+    use serde_json::json;
+
+    #[test]
+    fn test_number_rules() {
+        assert_eq!(
+            MinMaxRuleI32::<-5, 5>::json_schema(),
+            json!({ "type": "integer", "minimum": -5, "maximum": 5 })
+        );
+        assert_eq!(
+            GreaterEqualRuleF64::<1, 2>::json_schema(),
+            json!({ "type": "number", "minimum": 0.5 })
+        );
+        assert_eq!(
+            OddRuleU8::json_schema(),
+            json!({ "type": "integer", "not": { "multipleOf": 2 } })
+        );
+    }
+
+    #[test]
+    fn test_length_rules() {
+        type Username = crate::And![NonEmptyStringRule, LengthLessStringRule<9>];
+        assert_eq!(Username::description(), "not empty and length less than 9");
+        assert_eq!(
+            Username::json_schema(),
+            json!({ "type": "string", "minLength": 1, "maxLength": 8 })
+        );
+        assert_eq!(
+            LengthEqualHashMapRule::<2, String, u8>::json_schema(),
+            json!({ "type": "object", "minProperties": 2, "maxProperties": 2 })
+        );
+        assert_eq!(
+            NonEmptyVecRule::<u8>::json_schema(),
+            json!({ "type": "array", "minItems": 1 })
+        );
+    }
+
+    #[test]
+    fn test_string_rules() {
+        type Contact = Or![EmailStringRule, Ipv4AddrRule<String>];
+        assert_eq!(
+            Contact::json_schema(),
+            json!({
+                "anyOf": [
+                    { "type": "string", "pattern": EmailStringRule::json_schema()["pattern"] },
+                    { "type": "string", "format": "ipv4" }
+                ]
+            })
+        );
+        assert_eq!(
+            Contact::description(),
+            format!("{} or a valid IPv4 address", EmailStringRule::description())
+        );
+    }
+
+    #[test]
+    fn test_collection_rules() {
+        assert_eq!(
+            ForAllVecRule::<NonEmptyStringRule>::json_schema(),
+            json!({ "type": "array", "items": { "type": "string", "minLength": 1 } })
+        );
+        assert_eq!(
+            ExistsVecRule::<NonEmptyStringRule>::json_schema(),
+            json!({ "type": "array", "contains": { "type": "string", "minLength": 1 } })
+        );
+        assert_eq!(
+            HeadVecRule::<OddRuleU8>::json_schema(),
+            json!({
+                "type": "array",
+                "minItems": 1,
+                "prefixItems": [{ "type": "integer", "not": { "multipleOf": 2 } }]
+            })
+        );
+        assert_eq!(
+            TailVecRule::<NonEmptyStringRule>::json_schema(),
+            json!({
+                "type": "array",
+                "prefixItems": [{}],
+                "items": { "type": "string", "minLength": 1 }
+            })
+        );
+        assert_eq!(
+            CountEqualVecRule::<2, OddRuleU8>::description(),
+            "for exactly 2 items, odd"
+        );
+        assert_eq!(
+            CountEqualVecRule::<2, OddRuleU8>::json_schema()["maxContains"],
+            json!(2)
+        );
+    }
+
+    #[test]
+    fn test_description_parenthesizes_nested_composers() {
+        type Target = crate::And![
+            ForAllVecRule<NonEmptyStringRule>,
+            Or![NonEmptyVecRule<String>, crate::rule::EmptyRule<Vec<String>>]
+        ];
+        assert_eq!(
+            Target::description(),
+            "(for every item, not empty) and (not empty or empty)"
+        );
+    }
+
+    #[test]
+    fn test_all_of_merges_compatible_keywords() {
+        let schema = all_of(
+            json!({ "type": "string", "minLength": 1 }),
+            json!({ "type": "string", "maxLength": 8 }),
+        );
+        assert_eq!(
+            schema,
+            json!({ "type": "string", "minLength": 1, "maxLength": 8 })
+        );
+    }
+
+    #[test]
+    fn test_all_of_keeps_conflicting_keywords() {
+        let schema = all_of(
+            json!({ "type": "string", "pattern": "^a" }),
+            all_of(
+                json!({ "type": "string", "pattern": "b$" }),
+                json!({ "type": "string", "pattern": "c" }),
+            ),
+        );
+        assert_eq!(
+            schema,
+            json!({
+                "type": "string",
+                "pattern": "^a",
+                "allOf": [{ "type": "string", "pattern": "b$" }, { "type": "string", "pattern": "c" }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_all_of_keeps_tighter_bounds() {
+        let schema = all_of(
+            json!({ "type": "integer", "format": "uint8", "minimum": 0, "maximum": 255 }),
+            json!({ "type": "integer", "minimum": 18, "maximum": 80 }),
+        );
+        assert_eq!(
+            schema,
+            json!({ "type": "integer", "format": "uint8", "minimum": 18, "maximum": 80 })
+        );
+
+        let schema = all_of(
+            json!({ "type": "array", "items": { "type": "string" }, "maxItems": 3 }),
+            json!({ "type": "array", "items": { "minLength": 1 }, "maxItems": 5 }),
+        );
+        assert_eq!(
+            schema,
+            json!({ "type": "array", "items": { "type": "string", "minLength": 1 }, "maxItems": 3 })
+        );
+    }
+
+    #[test]
+    fn test_any_of() {
+        let schema = any_of(
+            json!({ "type": "integer", "const": 1 }),
+            json!({ "type": "integer", "exclusiveMinimum": 1 }),
+        );
+        assert_eq!(schema, json!({ "type": "integer", "minimum": 1 }));
+
+        let schema = any_of(
+            json!({ "type": "integer", "const": 1 }),
+            any_of(json!({ "const": 2 }), json!({ "const": 3 })),
+        );
+        assert_eq!(
+            schema,
+            json!({ "anyOf": [{ "type": "integer", "const": 1 }, { "const": 2 }, { "const": 3 }] })
+        );
+    }
+
+    #[test]
+    fn test_not() {
+        let schema = not(json!({ "type": "string", "maxLength": 0 }));
+        assert_eq!(schema, json!({ "type": "string", "minLength": 1 }));
+
+        let schema = not(json!({ "type": "integer", "multipleOf": 2 }));
+        assert_eq!(
+            schema,
+            json!({ "type": "integer", "not": { "multipleOf": 2 } })
+        );
+
+        let schema = not(not(json!({ "type": "integer", "multipleOf": 2 })));
+        assert_eq!(schema, json!({ "type": "integer", "multipleOf": 2 }));
+
+        let schema = not(json!({ "type": "array", "items": { "type": "string", "maxLength": 0 } }));
+        assert_eq!(
+            schema,
+            json!({ "type": "array", "contains": { "type": "string", "minLength": 1 } })
+        );
+    }
+}
@@ -3,10 +3,12 @@ mod iterator;
 mod number;
 mod string;
 
-use crate::result::Error;
-use crate::rule::Rule;
+use crate::result::{Error, ErrorTree};
+use crate::rule::{description, JsonTyped, Rule, RuleBorrow, RuleDescription, RuleRef};
 use crate::Refined;
 
+use serde_json::{json, Value};
+use std::borrow::Borrow;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::ops::Add;
@@ -63,10 +65,51 @@ where
     type Item = T;
 
     fn validate(target: Self::Item) -> crate::Result<Self::Item> {
+        match Self::validate_ref(&target) {
+            Ok(()) => Ok(target),
+            Err(tree) => Err(Error::from_tree(target, *tree)),
+        }
+    }
+}
+
+impl<T> RuleRef for EmptyRule<T>
+where
+    T: EmptyDefinition,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        <Self as RuleBorrow<T>>::validate_borrow(target)
+    }
+}
+
+impl<T, B> RuleBorrow<B> for EmptyRule<T>
+where
+    T: EmptyDefinition + Borrow<B>,
+    B: EmptyDefinition + ?Sized,
+{
+    fn validate_borrow(target: &B) -> Result<(), Box<ErrorTree>> {
         if target.empty() {
-            Ok(target)
+            Ok(())
         } else {
-            Err(Error::new(target, "The input value is not empty"))
+            let tree = ErrorTree::new("The input value is not empty")
+                .with_rule("EmptyRule")
+                .with_expected("empty");
+            Err(Box::new(tree))
+        }
+    }
+}
+
+impl<T> RuleDescription for EmptyRule<T>
+where
+    T: EmptyDefinition + JsonTyped,
+{
+    fn description() -> String {
+        "empty".to_string()
+    }
+
+    fn json_schema() -> Value {
+        match T::JSON_TYPE.length_keywords() {
+            Some((_, max)) => description::typed_schema(T::JSON_TYPE, [(max, json!(0))]),
+            None => description::typed_schema(T::JSON_TYPE, [("const", json!(0))]),
         }
     }
 }
@@ -9,6 +9,12 @@ impl<T> EmptyDefinition for Vec<T> {
     }
 }
 
+impl<T> EmptyDefinition for [T] {
+    fn empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
 impl<T> EmptyDefinition for std::vec::IntoIter<T> {
     fn empty(&self) -> bool {
         self.len() == 0
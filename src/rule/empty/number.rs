@@ -7,6 +7,10 @@ macro_rules! empty_definition {
                 *self == 0
             }
         }
+
+        impl<const NEGATIVE: bool, const ABS: u128> $crate::rule::ConstRule<$crate::rule::IntLiteral<NEGATIVE, ABS>> for $crate::rule::EmptyRule<$t> {
+            const VALID: bool = $crate::rule::IntLiteral::<NEGATIVE, ABS>::is_zero();
+        }
     };
     ($t:ty, $($ts:ty), +) => {
         empty_definition!($t);
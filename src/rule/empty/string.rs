@@ -1,4 +1,4 @@
-use crate::rule::EmptyDefinition;
+use crate::rule::{ConstRule, EmptyDefinition, EmptyRule, StrLiteral};
 
 impl EmptyDefinition for String {
     fn empty(&self) -> bool {
@@ -6,8 +6,22 @@ impl EmptyDefinition for String {
     }
 }
 
+impl EmptyDefinition for str {
+    fn empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
 impl EmptyDefinition for &str {
     fn empty(&self) -> bool {
         self == &""
     }
 }
+
+impl<const CHARS: usize> ConstRule<StrLiteral<CHARS>> for EmptyRule<String> {
+    const VALID: bool = CHARS == 0;
+}
+
+impl<const CHARS: usize> ConstRule<StrLiteral<CHARS>> for EmptyRule<&str> {
+    const VALID: bool = CHARS == 0;
+}
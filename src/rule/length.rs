@@ -1,12 +1,18 @@
 pub use length_definition::*;
+#[cfg(feature = "unicode")]
+pub use length_unit::Graphemes;
+pub use length_unit::{Bytes, DefinedLength, LengthUnit};
 
 mod equal;
 mod grater;
 mod length_definition;
+pub(crate) mod length_unit;
 mod less;
 mod min_max;
+mod string_length;
 
 pub use equal::*;
 pub use grater::*;
 pub use less::*;
 pub use min_max::*;
+pub use string_length::*;
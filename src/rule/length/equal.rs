@@ -1,6 +1,12 @@
-use crate::result::Error;
-use crate::rule::{LengthDefinition, Rule};
+use crate::result::{Error, ErrorTree};
+use crate::rule::length::length_unit::{unit_length, unit_length_schema};
+use crate::rule::{
+    ConstRule, DefinedLength, JsonTyped, LengthUnit, Rule, RuleBorrow, RuleDescription, RuleRef,
+    StrLiteral,
+};
 use crate::Refined;
+use serde_json::Value;
+use std::borrow::Borrow;
 
 /// A type that holds a value satisfying the `LengthEqualRule`
 pub type LengthEqual<const LENGTH: usize, ITEM> = Refined<LengthEqualRule<LENGTH, ITEM>>;
@@ -28,8 +34,8 @@ pub type LengthEqualStr<'a, const LENGTH: usize> = LengthEqual<LENGTH, &'a str>;
 
 /// Rule where the input `ITEM` has a length equal to `LENGTH`
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
-pub struct LengthEqualRule<const LENGTH: usize, ITEM> {
-    _phantom: std::marker::PhantomData<ITEM>,
+pub struct LengthEqualRule<const LENGTH: usize, ITEM, UNIT = DefinedLength> {
+    _phantom: std::marker::PhantomData<(ITEM, UNIT)>,
 }
 
 /// Rule where the input `Vec` has a length equal to `LENGTH`
@@ -53,20 +59,77 @@ pub type LengthEqualStringRule<const LENGTH: usize> = LengthEqualRule<LENGTH, St
 /// Rule where the input `&str` has a length equal to `LENGTH`
 pub type LengthEqualStrRule<'a, const LENGTH: usize> = LengthEqualRule<LENGTH, &'a str>;
 
-impl<const LENGTH: usize, ITEM: LengthDefinition> Rule for LengthEqualRule<LENGTH, ITEM> {
+impl<const LENGTH: usize, ITEM, UNIT> Rule for LengthEqualRule<LENGTH, ITEM, UNIT>
+where
+    UNIT: LengthUnit<ITEM>,
+{
     type Item = ITEM;
     fn validate(target: Self::Item) -> Result<Self::Item, Error<Self::Item>> {
-        if target.length() == LENGTH {
-            Ok(target)
+        match Self::validate_ref(&target) {
+            Ok(()) => Ok(target),
+            Err(tree) => Err(Error::from_tree(target, *tree)),
+        }
+    }
+}
+
+impl<const LENGTH: usize, ITEM, UNIT> RuleRef for LengthEqualRule<LENGTH, ITEM, UNIT>
+where
+    UNIT: LengthUnit<ITEM>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        <Self as RuleBorrow<ITEM>>::validate_borrow(target)
+    }
+}
+
+impl<const LENGTH: usize, ITEM, UNIT, B> RuleBorrow<B> for LengthEqualRule<LENGTH, ITEM, UNIT>
+where
+    ITEM: Borrow<B>,
+    UNIT: LengthUnit<ITEM> + LengthUnit<B>,
+    B: ?Sized,
+{
+    fn validate_borrow(target: &B) -> Result<(), Box<ErrorTree>> {
+        let length = <UNIT as LengthUnit<B>>::length(target);
+        if length == LENGTH {
+            Ok(())
         } else {
-            Err(Error::new(
-                target,
-                format!("target length is not equal to {}", LENGTH),
-            ))
+            let tree = ErrorTree::new(format!("target length is not equal to {}", LENGTH))
+                .with_rule("LengthEqualRule")
+                .with_expected(format!(
+                    "length equal to {}",
+                    unit_length::<B, UNIT>(LENGTH)
+                ))
+                .with_actual(format!("length {}", unit_length::<B, UNIT>(length)));
+            Err(Box::new(tree))
         }
     }
 }
 
+impl<const LENGTH: usize, const CHARS: usize> ConstRule<StrLiteral<CHARS>>
+    for LengthEqualRule<LENGTH, String>
+{
+    const VALID: bool = CHARS == LENGTH;
+}
+
+impl<const LENGTH: usize, const CHARS: usize> ConstRule<StrLiteral<CHARS>>
+    for LengthEqualRule<LENGTH, &str>
+{
+    const VALID: bool = CHARS == LENGTH;
+}
+
+impl<const LENGTH: usize, ITEM, UNIT> RuleDescription for LengthEqualRule<LENGTH, ITEM, UNIT>
+where
+    ITEM: JsonTyped,
+    UNIT: LengthUnit<ITEM>,
+{
+    fn description() -> String {
+        format!("length equal to {}", unit_length::<ITEM, UNIT>(LENGTH))
+    }
+
+    fn json_schema() -> Value {
+        unit_length_schema::<ITEM, UNIT>(ITEM::JSON_TYPE, Some(LENGTH), Some(LENGTH))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::result::Error;
@@ -1,6 +1,12 @@
-use crate::result::Error;
-use crate::rule::{LengthDefinition, Rule};
+use crate::result::{Error, ErrorTree};
+use crate::rule::length::length_unit::{unit_length, unit_length_schema};
+use crate::rule::{
+    ConstRule, DefinedLength, JsonTyped, LengthUnit, Rule, RuleBorrow, RuleDescription, RuleRef,
+    StrLiteral,
+};
 use crate::Refined;
+use serde_json::Value;
+use std::borrow::Borrow;
 
 /// A type that holds a value satisfying the `LengthGreaterRule`
 pub type LengthGreater<const THAN: usize, ITEM> = Refined<LengthGreaterRule<THAN, ITEM>>;
@@ -28,8 +34,8 @@ pub type LengthGreaterStr<'a, const THAN: usize> = LengthGreater<THAN, &'a str>;
 
 /// Rule where the input `ITEM` has a length greater than `THAN`
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
-pub struct LengthGreaterRule<const THAN: usize, ITEM> {
-    _phantom: std::marker::PhantomData<ITEM>,
+pub struct LengthGreaterRule<const THAN: usize, ITEM, UNIT = DefinedLength> {
+    _phantom: std::marker::PhantomData<(ITEM, UNIT)>,
 }
 
 /// Rule where the input `Vec` has a length greater than `THAN`
@@ -53,20 +59,77 @@ pub type LengthGreaterStringRule<const THAN: usize> = LengthGreaterRule<THAN, St
 /// Rule where the input `&str` has a length greater than `THAN`
 pub type LengthGreaterStrRule<'a, const THAN: usize> = LengthGreaterRule<THAN, &'a str>;
 
-impl<const THAN: usize, ITEM: LengthDefinition> Rule for LengthGreaterRule<THAN, ITEM> {
+impl<const THAN: usize, ITEM, UNIT> Rule for LengthGreaterRule<THAN, ITEM, UNIT>
+where
+    UNIT: LengthUnit<ITEM>,
+{
     type Item = ITEM;
     fn validate(target: Self::Item) -> Result<Self::Item, Error<Self::Item>> {
-        if target.length() > THAN {
-            Ok(target)
+        match Self::validate_ref(&target) {
+            Ok(()) => Ok(target),
+            Err(tree) => Err(Error::from_tree(target, *tree)),
+        }
+    }
+}
+
+impl<const THAN: usize, ITEM, UNIT> RuleRef for LengthGreaterRule<THAN, ITEM, UNIT>
+where
+    UNIT: LengthUnit<ITEM>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        <Self as RuleBorrow<ITEM>>::validate_borrow(target)
+    }
+}
+
+impl<const THAN: usize, ITEM, UNIT, B> RuleBorrow<B> for LengthGreaterRule<THAN, ITEM, UNIT>
+where
+    ITEM: Borrow<B>,
+    UNIT: LengthUnit<ITEM> + LengthUnit<B>,
+    B: ?Sized,
+{
+    fn validate_borrow(target: &B) -> Result<(), Box<ErrorTree>> {
+        let length = <UNIT as LengthUnit<B>>::length(target);
+        if length > THAN {
+            Ok(())
         } else {
-            Err(Error::new(
-                target,
-                format!("target length is not greater than {}", THAN),
-            ))
+            let tree = ErrorTree::new(format!("target length is not greater than {}", THAN))
+                .with_rule("LengthGreaterRule")
+                .with_expected(format!(
+                    "length greater than {}",
+                    unit_length::<B, UNIT>(THAN)
+                ))
+                .with_actual(format!("length {}", unit_length::<B, UNIT>(length)));
+            Err(Box::new(tree))
         }
     }
 }
 
+impl<const THAN: usize, const CHARS: usize> ConstRule<StrLiteral<CHARS>>
+    for LengthGreaterRule<THAN, String>
+{
+    const VALID: bool = CHARS > THAN;
+}
+
+impl<const THAN: usize, const CHARS: usize> ConstRule<StrLiteral<CHARS>>
+    for LengthGreaterRule<THAN, &str>
+{
+    const VALID: bool = CHARS > THAN;
+}
+
+impl<const THAN: usize, ITEM, UNIT> RuleDescription for LengthGreaterRule<THAN, ITEM, UNIT>
+where
+    ITEM: JsonTyped,
+    UNIT: LengthUnit<ITEM>,
+{
+    fn description() -> String {
+        format!("length greater than {}", unit_length::<ITEM, UNIT>(THAN))
+    }
+
+    fn json_schema() -> Value {
+        unit_length_schema::<ITEM, UNIT>(ITEM::JSON_TYPE, Some(THAN.saturating_add(1)), None)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::result::Error;
@@ -0,0 +1,109 @@
+use crate::rule::{description, JsonType, LengthDefinition};
+use serde_json::Value;
+
+/// This is a `trait` that measures the length of the target for the length rules, e.g. `LengthLessRule`
+///
+/// The length rules measure by `DefinedLength` unless another unit is given,
+/// so a `String` is measured in chars. `Bytes` and, with the `unicode` feature, `Graphemes` measure a string otherwise.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{Bytes, DefinedLength, LengthUnit};
+///
+/// assert_eq!(<DefinedLength as LengthUnit<str>>::length("日本語"), 3);
+/// assert_eq!(<Bytes as LengthUnit<str>>::length("日本語"), 9);
+/// ```
+pub trait LengthUnit<T: ?Sized> {
+    /// The name of the unit appended to the lengths in descriptions and errors, `None` for `DefinedLength`
+    const NAME: Option<&'static str> = None;
+
+    fn length(target: &T) -> usize;
+}
+
+/// Unit measuring the length given by `LengthDefinition`, i.e. the chars of a string or the items of a collection
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DefinedLength;
+
+impl<T: LengthDefinition + ?Sized> LengthUnit<T> for DefinedLength {
+    fn length(target: &T) -> usize {
+        target.length()
+    }
+}
+
+/// Unit measuring the UTF-8 bytes of a string
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Bytes;
+
+impl<T: AsRef<str> + ?Sized> LengthUnit<T> for Bytes {
+    const NAME: Option<&'static str> = Some("bytes");
+
+    fn length(target: &T) -> usize {
+        target.as_ref().len()
+    }
+}
+
+/// Unit measuring the extended grapheme clusters of a string, i.e. the characters as a user perceives them
+///
+/// `"が"` written as `"か\u{3099}"` or a flag emoji is a single grapheme, although it consists of two chars.
+#[cfg(feature = "unicode")]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Graphemes;
+
+#[cfg(feature = "unicode")]
+impl<T: AsRef<str> + ?Sized> LengthUnit<T> for Graphemes {
+    const NAME: Option<&'static str> = Some("graphemes");
+
+    fn length(target: &T) -> usize {
+        unicode_segmentation::UnicodeSegmentation::graphemes(target.as_ref(), true).count()
+    }
+}
+
+/// Formats `length` followed by the name of `UNIT`, e.g. `3 bytes`
+pub(crate) fn unit_length<T: ?Sized, UNIT: LengthUnit<T>>(length: usize) -> String {
+    match UNIT::NAME {
+        Some(name) => format!("{length} {name}"),
+        None => length.to_string(),
+    }
+}
+
+/// JSON Schema only bounds the length in chars or items, so a length in another unit is not expressed
+pub(crate) fn unit_length_schema<T: ?Sized, UNIT: LengthUnit<T>>(
+    json_type: JsonType,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Value {
+    match UNIT::NAME {
+        Some(_) => json_type.schema(),
+        None => description::length_schema(json_type, min, max),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{Bytes, DefinedLength, LengthUnit};
+
+    #[test]
+    fn test_length_unit() {
+        assert_eq!(
+            <DefinedLength as LengthUnit<String>>::length(&"héllo".to_string()),
+            5
+        );
+        assert_eq!(
+            <DefinedLength as LengthUnit<Vec<u8>>>::length(&vec![1, 2]),
+            2
+        );
+        assert_eq!(
+            <Bytes as LengthUnit<String>>::length(&"héllo".to_string()),
+            6
+        );
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_graphemes() {
+        use crate::rule::Graphemes;
+
+        assert_eq!(<Graphemes as LengthUnit<str>>::length("か\u{3099}な"), 2);
+        assert_eq!(<Graphemes as LengthUnit<str>>::length("🇯🇵"), 1);
+        assert_eq!(<Graphemes as LengthUnit<str>>::length("\r\n"), 1);
+    }
+}
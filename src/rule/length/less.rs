@@ -1,6 +1,12 @@
-use crate::result::Error;
-use crate::rule::{LengthDefinition, Rule};
+use crate::result::{Error, ErrorTree};
+use crate::rule::length::length_unit::{unit_length, unit_length_schema};
+use crate::rule::{
+    description, ConstRule, DefinedLength, JsonTyped, LengthUnit, Rule, RuleBorrow,
+    RuleDescription, RuleRef, StrLiteral,
+};
 use crate::Refined;
+use serde_json::{json, Value};
+use std::borrow::Borrow;
 
 /// A type that holds a value satisfying the `LengthLessRule`
 pub type LengthLess<const THAN: usize, ITEM> = Refined<LengthLessRule<THAN, ITEM>>;
@@ -25,8 +31,8 @@ pub type LengthLessStr<'a, const THAN: usize> = LengthLess<THAN, &'a str>;
 
 /// Rule where the input `ITEM` has a length less than `THAN`
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
-pub struct LengthLessRule<const THAN: usize, ITEM> {
-    _phantom: std::marker::PhantomData<ITEM>,
+pub struct LengthLessRule<const THAN: usize, ITEM, UNIT = DefinedLength> {
+    _phantom: std::marker::PhantomData<(ITEM, UNIT)>,
 }
 
 /// Rule where the input `Vec` has a length less than `THAN`
@@ -50,16 +56,73 @@ pub type LengthLessStringRule<const THAN: usize> = LengthLessRule<THAN, String>;
 /// Rule where the input `&str` has a length less than `THAN`
 pub type LengthLessStrRule<'a, const THAN: usize> = LengthLessRule<THAN, &'a str>;
 
-impl<const THAN: usize, ITEM: LengthDefinition> Rule for LengthLessRule<THAN, ITEM> {
+impl<const THAN: usize, ITEM, UNIT> Rule for LengthLessRule<THAN, ITEM, UNIT>
+where
+    UNIT: LengthUnit<ITEM>,
+{
     type Item = ITEM;
     fn validate(target: Self::Item) -> Result<Self::Item, Error<Self::Item>> {
-        if target.length() < THAN {
-            Ok(target)
+        match Self::validate_ref(&target) {
+            Ok(()) => Ok(target),
+            Err(tree) => Err(Error::from_tree(target, *tree)),
+        }
+    }
+}
+
+impl<const THAN: usize, ITEM, UNIT> RuleRef for LengthLessRule<THAN, ITEM, UNIT>
+where
+    UNIT: LengthUnit<ITEM>,
+{
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        <Self as RuleBorrow<ITEM>>::validate_borrow(target)
+    }
+}
+
+impl<const THAN: usize, ITEM, UNIT, B> RuleBorrow<B> for LengthLessRule<THAN, ITEM, UNIT>
+where
+    ITEM: Borrow<B>,
+    UNIT: LengthUnit<ITEM> + LengthUnit<B>,
+    B: ?Sized,
+{
+    fn validate_borrow(target: &B) -> Result<(), Box<ErrorTree>> {
+        let length = <UNIT as LengthUnit<B>>::length(target);
+        if length < THAN {
+            Ok(())
         } else {
-            Err(Error::new(
-                target,
-                format!("target length is not less than {}", THAN),
-            ))
+            let tree = ErrorTree::new(format!("target length is not less than {}", THAN))
+                .with_rule("LengthLessRule")
+                .with_expected(format!("length less than {}", unit_length::<B, UNIT>(THAN)))
+                .with_actual(format!("length {}", unit_length::<B, UNIT>(length)));
+            Err(Box::new(tree))
+        }
+    }
+}
+
+impl<const THAN: usize, const CHARS: usize> ConstRule<StrLiteral<CHARS>>
+    for LengthLessRule<THAN, String>
+{
+    const VALID: bool = CHARS < THAN;
+}
+
+impl<const THAN: usize, const CHARS: usize> ConstRule<StrLiteral<CHARS>>
+    for LengthLessRule<THAN, &str>
+{
+    const VALID: bool = CHARS < THAN;
+}
+
+impl<const THAN: usize, ITEM, UNIT> RuleDescription for LengthLessRule<THAN, ITEM, UNIT>
+where
+    ITEM: JsonTyped,
+    UNIT: LengthUnit<ITEM>,
+{
+    fn description() -> String {
+        format!("length less than {}", unit_length::<ITEM, UNIT>(THAN))
+    }
+
+    fn json_schema() -> Value {
+        match THAN.checked_sub(1) {
+            Some(max) => unit_length_schema::<ITEM, UNIT>(ITEM::JSON_TYPE, None, Some(max)),
+            None => description::all_of(ITEM::JSON_TYPE.schema(), json!({ "not": {} })),
         }
     }
 }
@@ -1,12 +1,12 @@
-use crate::rule::{LengthEqualRule, LengthGreaterRule, LengthLessRule};
+use crate::rule::{DefinedLength, LengthEqualRule, LengthGreaterRule, LengthLessRule};
 use crate::{And, Or, Refined};
 
-pub type LengthMinMax<const MIN: usize, const MAX: usize, ITEM> =
-    Refined<LengthMinMaxRule<MIN, MAX, ITEM>>;
+pub type LengthMinMax<const MIN: usize, const MAX: usize, ITEM, UNIT = DefinedLength> =
+    Refined<LengthMinMaxRule<MIN, MAX, ITEM, UNIT>>;
 
-pub type LengthMinMaxRule<const MIN: usize, const MAX: usize, ITEM> = And![
-    Or![LengthEqualRule<MIN, ITEM>, LengthGreaterRule<MIN, ITEM>],
-    Or![LengthEqualRule<MAX, ITEM>, LengthLessRule<MAX, ITEM>]
+pub type LengthMinMaxRule<const MIN: usize, const MAX: usize, ITEM, UNIT = DefinedLength> = And![
+    Or![LengthEqualRule<MIN, ITEM, UNIT>, LengthGreaterRule<MIN, ITEM, UNIT>],
+    Or![LengthEqualRule<MAX, ITEM, UNIT>, LengthLessRule<MAX, ITEM, UNIT>]
 ];
 
 #[cfg(test)]
@@ -0,0 +1,92 @@
+#[cfg(feature = "unicode")]
+use crate::rule::Graphemes;
+use crate::rule::{Bytes, DefinedLength};
+
+macro_rules! define_string_length_rules {
+    ($prefix:ident, $unit:ty, $doc:literal) => {
+        $crate::paste::item! {
+            #[doc = "Rule where the input `String` has " $doc " less than `THAN`"]
+            pub type [<$prefix LengthLessRule>]<const THAN: usize> =
+                $crate::rule::LengthLessRule<THAN, String, $unit>;
+
+            #[doc = "Rule where the input `String` has " $doc " greater than `THAN`"]
+            pub type [<$prefix LengthGreaterRule>]<const THAN: usize> =
+                $crate::rule::LengthGreaterRule<THAN, String, $unit>;
+
+            #[doc = "Rule where the input `String` has " $doc " equal to `LENGTH`"]
+            pub type [<$prefix LengthEqualRule>]<const LENGTH: usize> =
+                $crate::rule::LengthEqualRule<LENGTH, String, $unit>;
+
+            #[doc = "Rule where the input `String` has " $doc " between `MIN` and `MAX`, inclusive"]
+            pub type [<$prefix LengthMinMaxRule>]<const MIN: usize, const MAX: usize> =
+                $crate::rule::LengthMinMaxRule<MIN, MAX, String, $unit>;
+
+            #[doc = "A type that holds a `String` satisfying the `" [<$prefix LengthLessRule>] "`"]
+            pub type [<$prefix LengthLess>]<const THAN: usize> =
+                $crate::Refined<[<$prefix LengthLessRule>]<THAN>>;
+
+            #[doc = "A type that holds a `String` satisfying the `" [<$prefix LengthGreaterRule>] "`"]
+            pub type [<$prefix LengthGreater>]<const THAN: usize> =
+                $crate::Refined<[<$prefix LengthGreaterRule>]<THAN>>;
+
+            #[doc = "A type that holds a `String` satisfying the `" [<$prefix LengthEqualRule>] "`"]
+            pub type [<$prefix LengthEqual>]<const LENGTH: usize> =
+                $crate::Refined<[<$prefix LengthEqualRule>]<LENGTH>>;
+
+            #[doc = "A type that holds a `String` satisfying the `" [<$prefix LengthMinMaxRule>] "`"]
+            pub type [<$prefix LengthMinMax>]<const MIN: usize, const MAX: usize> =
+                $crate::Refined<[<$prefix LengthMinMaxRule>]<MIN, MAX>>;
+        }
+    };
+}
+
+define_string_length_rules!(Char, DefinedLength, "a number of chars");
+define_string_length_rules!(Byte, Bytes, "a number of UTF-8 bytes");
+#[cfg(feature = "unicode")]
+define_string_length_rules!(Grapheme, Graphemes, "a number of grapheme clusters");
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{
+        ByteLengthLess, ByteLengthLessRule, CharLengthLess, CharLengthMinMax, RuleDescription,
+    };
+
+    #[test]
+    fn test_char_length() {
+        assert!(CharLengthLess::<4>::new("山田太郎".to_string()).is_err());
+        assert!(CharLengthLess::<5>::new("山田太郎".to_string()).is_ok());
+        assert!(CharLengthMinMax::<1, 4>::new("やまだ".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_byte_length() {
+        assert!(ByteLengthLess::<12>::new("山田太郎".to_string()).is_err());
+        assert!(ByteLengthLess::<13>::new("山田太郎".to_string()).is_ok());
+
+        let err = ByteLengthLess::<4>::new("é".repeat(2)).unwrap_err();
+        assert_eq!(err.tree().expected(), Some("length less than 4 bytes"));
+        assert_eq!(err.tree().actual(), Some("length 4 bytes"));
+    }
+
+    #[test]
+    fn test_byte_length_description() {
+        assert_eq!(
+            ByteLengthLessRule::<4>::description(),
+            "length less than 4 bytes"
+        );
+        assert_eq!(
+            ByteLengthLessRule::<4>::json_schema(),
+            serde_json::json!({ "type": "string" })
+        );
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_grapheme_length() {
+        use crate::rule::{GraphemeLengthEqual, GraphemeLengthMinMax};
+
+        assert!(GraphemeLengthEqual::<1>::new("か\u{3099}".to_string()).is_ok());
+        assert!(GraphemeLengthEqual::<1>::new("👨‍👩‍👧".to_string()).is_ok());
+        assert!(GraphemeLengthMinMax::<1, 2>::new("🇯🇵🇺🇸🇫🇷".to_string()).is_err());
+    }
+}
@@ -37,7 +37,7 @@ impl<K: Debug, V: Debug, S> NonEmptyHashMap<K, V, S> {
     }
 
     #[allow(clippy::should_implement_trait)]
-    pub fn iter(&self) -> NonEmpty<std::collections::hash_map::Iter<K, V>> {
+    pub fn iter(&self) -> NonEmpty<std::collections::hash_map::Iter<'_, K, V>> {
         Refined::new_unchecked(self.value().iter())
     }
 
@@ -57,7 +57,7 @@ impl<K: Debug, V: Debug, S> NonEmptyHashMap<K, V, S> {
         false
     }
 
-    pub fn keys(&self) -> Keys<K, V> {
+    pub fn keys(&self) -> Keys<'_, K, V> {
         self.value().keys()
     }
 
@@ -65,13 +65,45 @@ impl<K: Debug, V: Debug, S> NonEmptyHashMap<K, V, S> {
         self.into_value().into_keys()
     }
 
-    pub fn values(&self) -> Values<K, V> {
+    pub fn values(&self) -> Values<'_, K, V> {
         self.value().values()
     }
 
     pub fn into_values(self) -> IntoValues<K, V> {
         self.into_value().into_values()
     }
+
+    pub fn max(self) -> (K, V)
+    where
+        K: Ord,
+        V: Ord,
+    {
+        self.into_value()
+            .into_iter()
+            .max()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn min(self) -> (K, V)
+    where
+        K: Ord,
+        V: Ord,
+    {
+        self.into_value()
+            .into_iter()
+            .min()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn reduce<F>(self, f: F) -> (K, V)
+    where
+        F: FnMut((K, V), (K, V)) -> (K, V),
+    {
+        self.into_value()
+            .into_iter()
+            .reduce(f)
+            .expect("This error is always unreachable")
+    }
 }
 
 impl<K, V, S> NonEmptyHashMap<K, V, S>
@@ -93,13 +125,56 @@ where
         result.insert(k, v);
         Refined::new_unchecked(result)
     }
+
+    pub fn extend<I>(self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut result = self.into_value();
+        result.extend(iter);
+        Refined::new_unchecked(result)
+    }
+
+    /// Applies `f` to every entry
+    ///
+    /// It is not named `map`, which is the adapter of the non-empty iterators given by `into_iter`.
+    ///
+    /// Entries that map to equal keys are merged, so the result may be smaller, but never empty.
+    /// The hasher of `self` is not kept: the result is built with a fresh `S::default()`,
+    /// so any state given to the original hasher, such as a fixed seed, is lost.
+    pub fn map_each<K2, V2, F>(self, f: F) -> NonEmptyHashMap<K2, V2, S>
+    where
+        K2: Eq + Hash + Debug,
+        V2: Debug,
+        F: FnMut((K, V)) -> (K2, V2),
+        S: Default,
+    {
+        Refined::new_unchecked(self.into_value().into_iter().map(f).collect())
+    }
+
+    /// Removes an arbitrary entry and returns it along with the rest, which may be empty
+    ///
+    /// Which entry is removed depends on the hasher, so it can differ between runs.
+    /// The rest keeps the original hasher and capacity.
+    pub fn pop(self) -> (HashMap<K, V, S>, (K, V)) {
+        let mut result = self.into_value();
+        let mut drain = result.drain();
+        let entry = drain.next().expect("This error is always unreachable");
+        let rest = drain.collect::<Vec<_>>();
+        result.extend(rest);
+        (result, entry)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::result::Error;
     use crate::rule::{NonEmptyHashMap, NonEmptyVec};
+    use std::collections::hash_map::DefaultHasher;
     use std::collections::{HashMap, HashSet};
+    use std::hash::BuildHasherDefault;
+
+    type FixedState = BuildHasherDefault<DefaultHasher>;
 
     #[test]
     fn test_map_len() -> Result<(), Error<HashMap<&'static str, i32>>> {
@@ -194,4 +269,59 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_map_extend() -> Result<(), Error<HashMap<&'static str, i32>>> {
+        let map = NonEmptyHashMap::new(HashMap::from([("1", 1)]))?.extend(vec![("2", 2)]);
+        assert_eq!(map.into_value(), HashMap::from([("1", 1), ("2", 2)]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_pop() -> Result<(), Error<HashMap<&'static str, i32>>> {
+        let (rest, (key, _)) = NonEmptyHashMap::new(HashMap::from([("1", 1), ("2", 2)]))?.pop();
+        assert_eq!(rest.len(), 1);
+        assert!(!rest.contains_key(key));
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_pop_keeps_hasher() -> Result<(), Error<HashMap<&'static str, i32, FixedState>>> {
+        let map = HashMap::from_iter([("1", 1), ("2", 2)]);
+        let (rest, (key, _)) = NonEmptyHashMap::<&str, i32, FixedState>::new(map)?.pop();
+        assert_eq!(rest.len(), 1);
+        assert!(!rest.contains_key(key));
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_max_min_reduce() -> Result<(), Error<HashMap<&'static str, i32>>> {
+        let map = HashMap::from([("a", 2), ("b", 3), ("c", 1)]);
+        assert_eq!(NonEmptyHashMap::new(map.clone())?.max(), ("c", 1));
+        assert_eq!(NonEmptyHashMap::new(map.clone())?.min(), ("a", 2));
+        let (_, sum) = NonEmptyHashMap::new(map)?.reduce(|(k, a), (_, b)| (k, a + b));
+        assert_eq!(sum, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_map_each() -> Result<(), Error<HashMap<&'static str, i32>>> {
+        let map = NonEmptyHashMap::new(HashMap::from([("a", 1), ("b", 2)]))?
+            .map_each(|(k, v)| (k.to_uppercase(), v * 10));
+        assert_eq!(
+            map.into_value(),
+            HashMap::from([("A".to_string(), 10), ("B".to_string(), 20)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_map_values() -> Result<(), Error<HashMap<&'static str, i32>>> {
+        let map: NonEmptyHashMap<&str, i32> = NonEmptyHashMap::new(HashMap::from([("1", 1)]))?
+            .into_iter()
+            .map(|(k, v)| (k, v * 10))
+            .collect();
+        assert_eq!(map.into_value(), HashMap::from([("1", 10)]));
+        Ok(())
+    }
 }
@@ -34,7 +34,7 @@ impl<T: Debug, S> NonEmptyHashSet<T, S> {
     }
 
     #[allow(clippy::should_implement_trait)]
-    pub fn iter(&self) -> NonEmpty<std::collections::hash_set::Iter<T>> {
+    pub fn iter(&self) -> NonEmpty<std::collections::hash_set::Iter<'_, T>> {
         Refined::new_unchecked(self.value().iter())
     }
 
@@ -53,6 +53,36 @@ impl<T: Debug, S> NonEmptyHashSet<T, S> {
     pub fn hasher(&self) -> &S {
         self.value().hasher()
     }
+
+    pub fn max(self) -> T
+    where
+        T: Ord,
+    {
+        self.into_value()
+            .into_iter()
+            .max()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn min(self) -> T
+    where
+        T: Ord,
+    {
+        self.into_value()
+            .into_iter()
+            .min()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.into_value()
+            .into_iter()
+            .reduce(f)
+            .expect("This error is always unreachable")
+    }
 }
 
 impl<T, S> NonEmptyHashSet<T, S>
@@ -85,13 +115,55 @@ where
     pub fn difference<'a>(&'a self, other: &'a HashSet<T, S>) -> Difference<'a, T, S> {
         self.value().difference(other)
     }
+
+    pub fn extend<I>(self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut result = self.into_value();
+        result.extend(iter);
+        Refined::new_unchecked(result)
+    }
+
+    /// Applies `f` to every element
+    ///
+    /// It is not named `map`, which is the adapter of the non-empty iterators given by `into_iter`.
+    ///
+    /// Elements that map to equal values are merged, so the result may be smaller, but never empty.
+    /// The hasher of `self` is not kept: the result is built with a fresh `S::default()`,
+    /// so any state given to the original hasher, such as a fixed seed, is lost.
+    pub fn map_each<U, F>(self, f: F) -> NonEmptyHashSet<U, S>
+    where
+        U: Eq + Hash + Debug,
+        F: FnMut(T) -> U,
+        S: Default,
+    {
+        Refined::new_unchecked(self.into_value().into_iter().map(f).collect())
+    }
+
+    /// Removes an arbitrary element and returns it along with the rest, which may be empty
+    ///
+    /// Which element is removed depends on the hasher, so it can differ between runs.
+    /// The rest keeps the original hasher and capacity.
+    pub fn pop(self) -> (HashSet<T, S>, T) {
+        let mut result = self.into_value();
+        let mut drain = result.drain();
+        let element = drain.next().expect("This error is always unreachable");
+        let rest = drain.collect::<Vec<_>>();
+        result.extend(rest);
+        (result, element)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::result::Error;
     use crate::rule::NonEmptyHashSet;
+    use std::collections::hash_map::DefaultHasher;
     use std::collections::HashSet;
+    use std::hash::BuildHasherDefault;
+
+    type FixedState = BuildHasherDefault<DefaultHasher>;
 
     #[test]
     fn test_err() -> anyhow::Result<()> {
@@ -158,4 +230,57 @@ mod test {
         assert_eq!(set.difference(&set_origin).next(), Some(&2));
         Ok(())
     }
+
+    #[test]
+    fn test_max_min_reduce() -> Result<(), Error<HashSet<i32>>> {
+        let set = HashSet::from([2, 3, 1]);
+        assert_eq!(NonEmptyHashSet::new(set.clone())?.max(), 3);
+        assert_eq!(NonEmptyHashSet::new(set.clone())?.min(), 1);
+        assert_eq!(NonEmptyHashSet::new(set)?.reduce(|a, b| a + b), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend() -> Result<(), Error<HashSet<i32>>> {
+        let set = NonEmptyHashSet::new(HashSet::from([1]))?.extend(vec![1, 2]);
+        assert_eq!(set.into_value(), HashSet::from([1, 2]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pop() -> Result<(), Error<HashSet<i32>>> {
+        let (rest, element) = NonEmptyHashSet::new(HashSet::from([1, 2]))?.pop();
+        assert_eq!(rest.len(), 1);
+        assert!(!rest.contains(&element));
+
+        let (rest, element) = NonEmptyHashSet::new(HashSet::from([1]))?.pop();
+        assert_eq!((rest, element), (HashSet::new(), 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pop_keeps_hasher() -> Result<(), Error<HashSet<i32, FixedState>>> {
+        let set = HashSet::from_iter([1, 2, 3]);
+        let (rest, element) = NonEmptyHashSet::<i32, FixedState>::new(set)?.pop();
+        assert_eq!(rest.len(), 2);
+        assert!(!rest.contains(&element));
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_each() -> Result<(), Error<HashSet<i32>>> {
+        let set = NonEmptyHashSet::new(HashSet::from([1, -1, 2]))?.map_each(|n: i32| n.abs());
+        assert_eq!(set.into_value(), HashSet::from([1, 2]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_map() -> Result<(), Error<HashSet<i32>>> {
+        let set: NonEmptyHashSet<i32> = NonEmptyHashSet::new(HashSet::from([1, 2]))?
+            .into_iter()
+            .map(|n| n * 10)
+            .collect();
+        assert_eq!(set.into_value(), HashSet::from([10, 20]));
+        Ok(())
+    }
 }
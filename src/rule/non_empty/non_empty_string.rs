@@ -119,7 +119,7 @@ mod test {
             NonEmptyStringRule::validate("".to_string())
                 .unwrap_err()
                 .to_string(),
-            r#""" does not satisfy Not<refined_type::rule::empty::EmptyRule<alloc::string::String>>"#
+            r#""" does not satisfy Not<EmptyRule<String>>"#
         );
     }
 
@@ -128,7 +128,7 @@ mod test {
         assert!(NonEmptyStrRule::validate("hello").is_ok());
         assert_eq!(
             NonEmptyStrRule::validate("").unwrap_err().to_string(),
-            r#""" does not satisfy Not<refined_type::rule::empty::EmptyRule<&str>>"#
+            r#""" does not satisfy Not<EmptyRule<&str>>"#
         );
     }
 
@@ -1,7 +1,7 @@
 use crate::rule::{NonEmpty, NonEmptyRule};
 use crate::Refined;
 use std::fmt::Debug;
-
+use std::num::NonZeroUsize;
 use std::ops::Add;
 
 /// A type that holds a value satisfying the `NonEmptyVecRule`
@@ -25,7 +25,7 @@ impl<T: Debug> NonEmptyVec<T> {
     }
 
     #[allow(clippy::should_implement_trait)]
-    pub fn iter(&self) -> NonEmpty<std::slice::Iter<T>> {
+    pub fn iter(&self) -> NonEmpty<std::slice::Iter<'_, T>> {
         Refined::new_unchecked(self.value().iter())
     }
 
@@ -46,6 +46,122 @@ impl<T: Debug> NonEmptyVec<T> {
         result.push(value);
         Refined::new_unchecked(result)
     }
+
+    pub fn first(&self) -> &T {
+        self.value()
+            .first()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn last(&self) -> &T {
+        self.value()
+            .last()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn split_first(&self) -> (&T, &[T]) {
+        self.value()
+            .split_first()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn split_last(&self) -> (&T, &[T]) {
+        self.value()
+            .split_last()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn max(self) -> T
+    where
+        T: Ord,
+    {
+        self.into_value()
+            .into_iter()
+            .max()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn min(self) -> T
+    where
+        T: Ord,
+    {
+        self.into_value()
+            .into_iter()
+            .min()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.into_value()
+            .into_iter()
+            .reduce(f)
+            .expect("This error is always unreachable")
+    }
+
+    /// Applies `f` to every element, keeping the order
+    ///
+    /// It is not named `map`, which is the adapter of the non-empty iterators given by `into_iter`.
+    pub fn map_each<U, F>(self, f: F) -> NonEmptyVec<U>
+    where
+        U: Debug,
+        F: FnMut(T) -> U,
+    {
+        Refined::new_unchecked(self.into_value().into_iter().map(f).collect())
+    }
+
+    /// Removes the last element and returns it along with the rest, which may be empty
+    pub fn pop(self) -> (Vec<T>, T) {
+        let mut result = self.into_value();
+        let last = result.pop().expect("This error is always unreachable");
+        (result, last)
+    }
+
+    pub fn sort(self) -> Self
+    where
+        T: Ord,
+    {
+        let mut result = self.into_value();
+        result.sort();
+        Refined::new_unchecked(result)
+    }
+
+    pub fn dedup(self) -> Self
+    where
+        T: PartialEq,
+    {
+        let mut result = self.into_value();
+        result.dedup();
+        Refined::new_unchecked(result)
+    }
+
+    pub fn truncate(self, len: NonZeroUsize) -> Self {
+        let mut result = self.into_value();
+        result.truncate(len.get());
+        Refined::new_unchecked(result)
+    }
+
+    pub fn extend<I>(self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut result = self.into_value();
+        result.extend(iter);
+        Refined::new_unchecked(result)
+    }
+
+    /// Inserts an element at `index`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`, like `Vec::insert`.
+    pub fn insert(self, index: usize, element: T) -> Self {
+        let mut result = self.into_value();
+        result.insert(index, element);
+        Refined::new_unchecked(result)
+    }
 }
 
 impl<T: Debug> Add for NonEmptyVec<T> {
@@ -64,6 +180,7 @@ mod test {
     use crate::rule::non_empty::non_empty_vec_deque::NonEmptyVecDeque;
     use crate::rule::non_empty::NonEmptyVecRule;
     use crate::rule::{NonEmptyVec, Rule};
+    use std::num::NonZeroUsize;
 
     #[test]
     fn test_non_empty_vec() {
@@ -131,4 +248,88 @@ mod test {
         assert!(!vec.is_empty());
         Ok(())
     }
+
+    #[test]
+    fn test_first_last() -> Result<(), Error<Vec<i32>>> {
+        let vec = NonEmptyVec::new(vec![1, 2, 3])?;
+        assert_eq!(vec.first(), &1);
+        assert_eq!(vec.last(), &3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_split() -> Result<(), Error<Vec<i32>>> {
+        let vec = NonEmptyVec::new(vec![1, 2, 3])?;
+        assert_eq!(vec.split_first(), (&1, &[2, 3][..]));
+        assert_eq!(vec.split_last(), (&3, &[1, 2][..]));
+
+        let vec = NonEmptyVec::new(vec![1])?;
+        assert_eq!(vec.split_first(), (&1, &[][..]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_min() -> Result<(), Error<Vec<i32>>> {
+        let vec = NonEmptyVec::new(vec![2, 3, 1])?;
+        assert_eq!(vec.max(), 3);
+
+        let vec = NonEmptyVec::new(vec![2, 3, 1])?;
+        assert_eq!(vec.min(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reduce() -> Result<(), Error<Vec<i32>>> {
+        let vec = NonEmptyVec::new(vec![1, 2, 3])?;
+        assert_eq!(vec.reduce(|a, b| a + b), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pop() -> Result<(), Error<Vec<i32>>> {
+        let (rest, last) = NonEmptyVec::new(vec![1, 2])?.pop();
+        assert_eq!((rest, last), (vec![1], 2));
+
+        let (rest, last) = NonEmptyVec::new(vec![1])?.pop();
+        assert_eq!((rest, last), (vec![], 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_dedup() -> Result<(), Error<Vec<i32>>> {
+        let vec = NonEmptyVec::new(vec![3, 1, 3, 2, 1])?.sort().dedup();
+        assert_eq!(vec.into_value(), vec![1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncate() -> Result<(), Error<Vec<i32>>> {
+        let vec = NonEmptyVec::new(vec![1, 2, 3])?.truncate(NonZeroUsize::MIN);
+        assert_eq!(vec.into_value(), vec![1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend_insert() -> Result<(), Error<Vec<i32>>> {
+        let vec = NonEmptyVec::new(vec![1])?.extend(vec![3, 4]).insert(1, 2);
+        assert_eq!(vec.into_value(), vec![1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_each() -> Result<(), Error<Vec<i32>>> {
+        let vec = NonEmptyVec::new(vec![1, 2])?.map_each(|n| n * 10);
+        assert_eq!(vec.into_value(), vec![10, 20]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map() -> Result<(), Error<Vec<i32>>> {
+        let vec: NonEmptyVec<String> = NonEmptyVec::new(vec![1, 2])?
+            .into_iter()
+            .map(|n| n.to_string())
+            .collect();
+        assert_eq!(vec.into_value(), vec!["1".to_string(), "2".to_string()]);
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@ use crate::rule::{NonEmpty, NonEmptyRule};
 use crate::Refined;
 use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::num::NonZeroUsize;
 use std::ops::Add;
 
 /// A type that holds a value satisfying the `NonEmptyVecDequeRule`
@@ -29,7 +30,7 @@ impl<T: Debug> NonEmptyVecDeque<T> {
     }
 
     #[allow(clippy::should_implement_trait)]
-    pub fn iter(&self) -> NonEmpty<std::collections::vec_deque::Iter<T>> {
+    pub fn iter(&self) -> NonEmpty<std::collections::vec_deque::Iter<'_, T>> {
         Refined::new_unchecked(self.value().iter())
     }
 
@@ -56,6 +57,119 @@ impl<T: Debug> NonEmptyVecDeque<T> {
         result.push_back(value);
         Refined::new_unchecked(result)
     }
+
+    pub fn front(&self) -> &T {
+        self.value()
+            .front()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn back(&self) -> &T {
+        self.value()
+            .back()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn max(self) -> T
+    where
+        T: Ord,
+    {
+        self.into_value()
+            .into_iter()
+            .max()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn min(self) -> T
+    where
+        T: Ord,
+    {
+        self.into_value()
+            .into_iter()
+            .min()
+            .expect("This error is always unreachable")
+    }
+
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.into_value()
+            .into_iter()
+            .reduce(f)
+            .expect("This error is always unreachable")
+    }
+
+    /// Applies `f` to every element, keeping the order
+    ///
+    /// It is not named `map`, which is the adapter of the non-empty iterators given by `into_iter`.
+    pub fn map_each<U, F>(self, f: F) -> NonEmptyVecDeque<U>
+    where
+        U: Debug,
+        F: FnMut(T) -> U,
+    {
+        Refined::new_unchecked(self.into_value().into_iter().map(f).collect())
+    }
+
+    /// Removes the first element and returns it along with the rest, which may be empty
+    pub fn pop_front(self) -> (VecDeque<T>, T) {
+        let mut result = self.into_value();
+        let front = result
+            .pop_front()
+            .expect("This error is always unreachable");
+        (result, front)
+    }
+
+    /// Removes the last element and returns it along with the rest, which may be empty
+    pub fn pop_back(self) -> (VecDeque<T>, T) {
+        let mut result = self.into_value();
+        let back = result.pop_back().expect("This error is always unreachable");
+        (result, back)
+    }
+
+    pub fn sort(self) -> Self
+    where
+        T: Ord,
+    {
+        let mut result = self.into_value();
+        result.make_contiguous().sort();
+        Refined::new_unchecked(result)
+    }
+
+    pub fn dedup(self) -> Self
+    where
+        T: PartialEq,
+    {
+        let mut result = Vec::from(self.into_value());
+        result.dedup();
+        Refined::new_unchecked(VecDeque::from(result))
+    }
+
+    pub fn truncate(self, len: NonZeroUsize) -> Self {
+        let mut result = self.into_value();
+        result.truncate(len.get());
+        Refined::new_unchecked(result)
+    }
+
+    pub fn extend<I>(self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut result = self.into_value();
+        result.extend(iter);
+        Refined::new_unchecked(result)
+    }
+
+    /// Inserts an element at `index`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`, like `VecDeque::insert`.
+    pub fn insert(self, index: usize, value: T) -> Self {
+        let mut result = self.into_value();
+        result.insert(index, value);
+        Refined::new_unchecked(result)
+    }
 }
 
 impl<T: Debug> Add for NonEmptyVecDeque<T> {
@@ -74,6 +188,7 @@ mod test {
     use crate::rule::non_empty::non_empty_vec_deque::NonEmptyVecDeque;
     use crate::rule::NonEmptyVec;
     use std::collections::VecDeque;
+    use std::num::NonZeroUsize;
 
     #[test]
     fn test_collect_to_vec() -> Result<(), Error<VecDeque<i32>>> {
@@ -120,4 +235,60 @@ mod test {
         assert!(!deque.is_empty());
         Ok(())
     }
+
+    #[test]
+    fn test_front_back() -> Result<(), Error<VecDeque<i32>>> {
+        let deque = NonEmptyVecDeque::new(VecDeque::from([1, 2, 3]))?;
+        assert_eq!(deque.front(), &1);
+        assert_eq!(deque.back(), &3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_min_reduce() -> Result<(), Error<VecDeque<i32>>> {
+        assert_eq!(NonEmptyVecDeque::new(VecDeque::from([2, 3, 1]))?.max(), 3);
+        assert_eq!(NonEmptyVecDeque::new(VecDeque::from([2, 3, 1]))?.min(), 1);
+        assert_eq!(
+            NonEmptyVecDeque::new(VecDeque::from([2, 3, 1]))?.reduce(|a, b| a * b),
+            6
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pop() -> Result<(), Error<VecDeque<i32>>> {
+        let (rest, front) = NonEmptyVecDeque::new(VecDeque::from([1, 2]))?.pop_front();
+        assert_eq!((rest, front), (VecDeque::from([2]), 1));
+
+        let (rest, back) = NonEmptyVecDeque::new(VecDeque::from([1]))?.pop_back();
+        assert_eq!((rest, back), (VecDeque::new(), 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_each() -> Result<(), Error<VecDeque<i32>>> {
+        let deque = NonEmptyVecDeque::new(VecDeque::from([1, 2]))?.map_each(|n| n.to_string());
+        assert_eq!(deque.into_value(), vec!["1".to_string(), "2".to_string()]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_dedup_truncate() -> Result<(), Error<VecDeque<i32>>> {
+        let deque = NonEmptyVecDeque::new(VecDeque::from([3, 1, 3, 2, 1]))?
+            .push_front(4)
+            .sort()
+            .dedup()
+            .truncate(NonZeroUsize::new(3).unwrap());
+        assert_eq!(deque.into_value(), vec![1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend_insert() -> Result<(), Error<VecDeque<i32>>> {
+        let deque = NonEmptyVecDeque::new(VecDeque::from([1]))?
+            .extend(vec![3, 4])
+            .insert(1, 2);
+        assert_eq!(deque.into_value(), vec![1, 2, 3, 4]);
+        Ok(())
+    }
 }
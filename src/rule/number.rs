@@ -1,5 +1,6 @@
 mod equal;
 mod even;
+mod float;
 mod greater;
 mod greater_equal;
 mod less;
@@ -10,6 +11,7 @@ mod range;
 
 pub use equal::*;
 pub use even::*;
+pub use float::*;
 pub use greater::*;
 pub use greater_equal::*;
 pub use less::*;
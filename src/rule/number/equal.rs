@@ -10,13 +10,40 @@ macro_rules! define_equal_rule {
                 type Item = $t;
 
                 fn validate(target: Self::Item) -> Result<Self::Item, $crate::result::Error<Self::Item>> {
-                    if target == EQUAL {
-                        Ok(target)
+                    match <Self as $crate::rule::RuleRef>::validate_ref(&target) {
+                        Ok(()) => Ok(target),
+                        Err(tree) => Err($crate::result::Error::from_tree(target, *tree)),
+                    }
+                }
+            }
+
+            impl<const EQUAL: $t> $crate::rule::RuleRef for [<EqualRule $t:camel>]<EQUAL> {
+                fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    if *target == EQUAL {
+                        Ok(())
                     } else {
-                        Err($crate::result::Error::new(target, format!("the value must be equal to {EQUAL}, but received {target}")))
+                        let tree = $crate::result::ErrorTree::new(format!("the value must be equal to {EQUAL}, but received {target}"))
+                            .with_rule(stringify!([<EqualRule $t:camel>]))
+                            .with_expected(format!("equal to {EQUAL}"))
+                            .with_actual(target.to_string());
+                        Err(Box::new(tree))
                     }
                 }
             }
+
+            impl<const EQUAL: $t> $crate::rule::RuleDescription for [<EqualRule $t:camel>]<EQUAL> {
+                fn description() -> String {
+                    format!("equal to {EQUAL}")
+                }
+
+                fn json_schema() -> $crate::__private::serde_json::Value {
+                    $crate::rule::description::typed_schema($crate::rule::JsonType::Integer, [("const", $crate::rule::description::json_number(EQUAL))])
+                }
+            }
+
+            impl<const EQUAL: $t, const NEGATIVE: bool, const ABS: u128> $crate::rule::ConstRule<$crate::rule::IntLiteral<NEGATIVE, ABS>> for [<EqualRule $t:camel>]<EQUAL> {
+                const VALID: bool = $crate::rule::IntLiteral::<NEGATIVE, ABS>::compare(EQUAL as i128, EQUAL as u128, <$t>::MIN != 0).is_eq();
+            }
         }
     };
     ($t: ty, $($ts: ty),+) => {
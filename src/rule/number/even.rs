@@ -14,13 +14,40 @@ macro_rules! even_rule {
                 type Item = $t;
 
                 fn validate(target: Self::Item) -> Result<Self::Item, $crate::result::Error<Self::Item>> {
-                    if target % 2 == 0 {
-                        Ok(target)
+                    match <Self as $crate::rule::RuleRef>::validate_ref(&target) {
+                        Ok(()) => Ok(target),
+                        Err(tree) => Err($crate::result::Error::from_tree(target, *tree)),
+                    }
+                }
+            }
+
+            impl $crate::rule::RuleRef for [<EvenRule $t:upper>] {
+                fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    if *target % 2 == 0 {
+                        Ok(())
                     } else {
-                        Err($crate::result::Error::new(target, format!("the value must be even, but received {target}")))
+                        let tree = $crate::result::ErrorTree::new(format!("the value must be even, but received {target}"))
+                            .with_rule(stringify!([<EvenRule $t:upper>]))
+                            .with_expected("even")
+                            .with_actual(target.to_string());
+                        Err(Box::new(tree))
                     }
                 }
             }
+
+            impl $crate::rule::RuleDescription for [<EvenRule $t:upper>] {
+                fn description() -> String {
+                    "even".to_string()
+                }
+
+                fn json_schema() -> $crate::__private::serde_json::Value {
+                    $crate::rule::description::typed_schema($crate::rule::JsonType::Integer, [("multipleOf", $crate::__private::serde_json::json!(2))])
+                }
+            }
+
+            impl<const NEGATIVE: bool, const ABS: u128> $crate::rule::ConstRule<$crate::rule::IntLiteral<NEGATIVE, ABS>> for [<EvenRule $t:upper>] {
+                const VALID: bool = $crate::rule::IntLiteral::<NEGATIVE, ABS>::is_even();
+            }
         }
     };
     ($t: ty, $($ts: ty),+) => {
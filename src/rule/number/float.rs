@@ -0,0 +1,21 @@
+mod finite;
+mod greater;
+mod greater_equal;
+mod less;
+mod less_equal;
+mod min_max;
+mod non_nan;
+mod positive;
+mod range;
+mod unit_interval;
+
+pub use finite::*;
+pub use greater::*;
+pub use greater_equal::*;
+pub use less::*;
+pub use less_equal::*;
+pub use min_max::*;
+pub use non_nan::*;
+pub use positive::*;
+pub use range::*;
+pub use unit_interval::*;
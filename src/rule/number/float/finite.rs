@@ -0,0 +1,71 @@
+macro_rules! define_finite_float_rule {
+    ($t: ty) => {
+        $crate::paste::item! {
+            /// A type that holds a value satisfying the `FiniteRule`
+            pub type [<Finite $t:camel>] = $crate::Refined<[<FiniteRule $t:camel>]>;
+
+            /// Rule where the target value must be finite
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+            pub struct [<FiniteRule $t:camel>];
+
+            impl $crate::rule::Rule for [<FiniteRule $t:camel>] {
+                type Item = $t;
+
+                fn validate(target: Self::Item) -> Result<Self::Item, $crate::result::Error<Self::Item>> {
+                    match <Self as $crate::rule::RuleRef>::validate_ref(&target) {
+                        Ok(()) => Ok(target),
+                        Err(tree) => Err($crate::result::Error::from_tree(target, *tree)),
+                    }
+                }
+            }
+
+            impl $crate::rule::RuleRef for [<FiniteRule $t:camel>] {
+                fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    if target.is_finite() {
+                        Ok(())
+                    } else {
+                        let tree = $crate::result::ErrorTree::new(format!("the value must be finite, but received {target}"))
+                            .with_rule(stringify!([<FiniteRule $t:camel>]))
+                            .with_expected("finite")
+                            .with_actual(target.to_string());
+                        Err(Box::new(tree))
+                    }
+                }
+            }
+
+            impl $crate::rule::RuleDescription for [<FiniteRule $t:camel>] {
+                fn description() -> String {
+                    "finite".to_string()
+                }
+
+                fn json_schema() -> $crate::__private::serde_json::Value {
+                    $crate::rule::JsonType::Number.schema()
+                }
+            }
+        }
+    };
+    ($t: ty, $($ts: ty),+) => {
+        define_finite_float_rule!($t);
+        define_finite_float_rule!($($ts), +);
+    };
+}
+
+define_finite_float_rule!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use crate::rule::FiniteF64;
+
+    #[test]
+    fn test_finite_f64_ok() {
+        assert!(FiniteF64::new(1.5).is_ok());
+        assert!(FiniteF64::new(f64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_finite_f64_err() {
+        assert!(FiniteF64::new(f64::INFINITY).is_err());
+        assert!(FiniteF64::new(f64::NEG_INFINITY).is_err());
+        assert!(FiniteF64::new(f64::NAN).is_err());
+    }
+}
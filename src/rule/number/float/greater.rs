@@ -0,0 +1,85 @@
+macro_rules! define_greater_float_rule {
+    ($t: ty) => {
+        $crate::paste::item! {
+            /// A type that holds a value satisfying the `GreaterRule`
+            pub type [<Greater $t:camel>]<const NUM: i64, const DEN: u64 = 1> = $crate::Refined<[<GreaterRule $t:camel>]<NUM, DEN>>;
+
+            /// Rule where the target value must be greater than `NUM / DEN`
+            ///
+            /// `DEN` must not be zero, which is checked at compile time.
+            /// ```compile_fail
+            #[doc = concat!("let _ = refined_type::rule::", stringify!([<Greater $t:camel>]), "::<1, 0>::new(1.0);")]
+            /// ```
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+            pub struct [<GreaterRule $t:camel>]<const NUM: i64, const DEN: u64 = 1>;
+
+            impl<const NUM: i64, const DEN: u64> $crate::rule::Rule for [<GreaterRule $t:camel>]<NUM, DEN> {
+                type Item = $t;
+
+                fn validate(target: Self::Item) -> Result<Self::Item, $crate::result::Error<Self::Item>> {
+                    match <Self as $crate::rule::RuleRef>::validate_ref(&target) {
+                        Ok(()) => Ok(target),
+                        Err(tree) => Err($crate::result::Error::from_tree(target, *tree)),
+                    }
+                }
+            }
+
+            impl<const NUM: i64, const DEN: u64> $crate::rule::RuleRef for [<GreaterRule $t:camel>]<NUM, DEN> {
+                fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    const { assert!(DEN != 0, "the denominator must not be zero") };
+                    let than = NUM as $t / DEN as $t;
+                    if *target > than {
+                        Ok(())
+                    } else {
+                        let tree = $crate::result::ErrorTree::new(format!("the value must be greater than {than}, but received {target}"))
+                            .with_rule(stringify!([<GreaterRule $t:camel>]))
+                            .with_expected(format!("greater than {than}"))
+                            .with_actual(target.to_string());
+                        Err(Box::new(tree))
+                    }
+                }
+            }
+
+            impl<const NUM: i64, const DEN: u64> $crate::rule::RuleDescription for [<GreaterRule $t:camel>]<NUM, DEN> {
+                fn description() -> String {
+                    const { assert!(DEN != 0, "the denominator must not be zero") };
+                    format!("greater than {}", NUM as $t / DEN as $t)
+                }
+
+                fn json_schema() -> $crate::__private::serde_json::Value {
+                    const { assert!(DEN != 0, "the denominator must not be zero") };
+                    $crate::rule::description::typed_schema($crate::rule::JsonType::Number, [("exclusiveMinimum", $crate::rule::description::json_float(NUM as f64 / DEN as f64))])
+                }
+            }
+        }
+    };
+    ($t: ty, $($ts: ty),+) => {
+        define_greater_float_rule!($t);
+        define_greater_float_rule!($($ts), +);
+    };
+}
+
+define_greater_float_rule!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use crate::rule::GreaterF64;
+
+    #[test]
+    fn test_greater_f64_ok() {
+        let result = GreaterF64::<1, 2>::new(0.6);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_greater_f64_err() {
+        let result = GreaterF64::<1, 2>::new(0.5);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_greater_f64_err_nan() {
+        let result = GreaterF64::<1, 2>::new(f64::NAN);
+        assert!(result.is_err());
+    }
+}
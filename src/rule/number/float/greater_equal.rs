@@ -0,0 +1,85 @@
+macro_rules! define_greater_equal_float_rule {
+    ($t: ty) => {
+        $crate::paste::item! {
+            /// A type that holds a value satisfying the `GreaterEqualRule`
+            pub type [<GreaterEqual $t:camel>]<const NUM: i64, const DEN: u64 = 1> = $crate::Refined<[<GreaterEqualRule $t:camel>]<NUM, DEN>>;
+
+            /// Rule where the target value must be greater than or equal to `NUM / DEN`
+            ///
+            /// `DEN` must not be zero, which is checked at compile time.
+            /// ```compile_fail
+            #[doc = concat!("let _ = refined_type::rule::", stringify!([<GreaterEqual $t:camel>]), "::<1, 0>::new(1.0);")]
+            /// ```
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+            pub struct [<GreaterEqualRule $t:camel>]<const NUM: i64, const DEN: u64 = 1>;
+
+            impl<const NUM: i64, const DEN: u64> $crate::rule::Rule for [<GreaterEqualRule $t:camel>]<NUM, DEN> {
+                type Item = $t;
+
+                fn validate(target: Self::Item) -> Result<Self::Item, $crate::result::Error<Self::Item>> {
+                    match <Self as $crate::rule::RuleRef>::validate_ref(&target) {
+                        Ok(()) => Ok(target),
+                        Err(tree) => Err($crate::result::Error::from_tree(target, *tree)),
+                    }
+                }
+            }
+
+            impl<const NUM: i64, const DEN: u64> $crate::rule::RuleRef for [<GreaterEqualRule $t:camel>]<NUM, DEN> {
+                fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    const { assert!(DEN != 0, "the denominator must not be zero") };
+                    let than = NUM as $t / DEN as $t;
+                    if *target >= than {
+                        Ok(())
+                    } else {
+                        let tree = $crate::result::ErrorTree::new(format!("the value must be greater than or equal to {than}, but received {target}"))
+                            .with_rule(stringify!([<GreaterEqualRule $t:camel>]))
+                            .with_expected(format!("greater than or equal to {than}"))
+                            .with_actual(target.to_string());
+                        Err(Box::new(tree))
+                    }
+                }
+            }
+
+            impl<const NUM: i64, const DEN: u64> $crate::rule::RuleDescription for [<GreaterEqualRule $t:camel>]<NUM, DEN> {
+                fn description() -> String {
+                    const { assert!(DEN != 0, "the denominator must not be zero") };
+                    format!("greater than or equal to {}", NUM as $t / DEN as $t)
+                }
+
+                fn json_schema() -> $crate::__private::serde_json::Value {
+                    const { assert!(DEN != 0, "the denominator must not be zero") };
+                    $crate::rule::description::typed_schema($crate::rule::JsonType::Number, [("minimum", $crate::rule::description::json_float(NUM as f64 / DEN as f64))])
+                }
+            }
+        }
+    };
+    ($t: ty, $($ts: ty),+) => {
+        define_greater_equal_float_rule!($t);
+        define_greater_equal_float_rule!($($ts), +);
+    };
+}
+
+define_greater_equal_float_rule!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use crate::rule::GreaterEqualF64;
+
+    #[test]
+    fn test_greater_equal_f64_ok() {
+        let result = GreaterEqualF64::<1, 2>::new(0.5);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_greater_equal_f64_err() {
+        let result = GreaterEqualF64::<1, 2>::new(0.4);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_greater_equal_f64_err_nan() {
+        let result = GreaterEqualF64::<1, 2>::new(f64::NAN);
+        assert!(result.is_err());
+    }
+}
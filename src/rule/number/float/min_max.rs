@@ -0,0 +1,38 @@
+macro_rules! define_min_max_float_rule {
+    ($t: ty) => {
+        $crate::paste::item! {
+            /// A type that holds a value satisfying the `MinMaxRule`
+            pub type [<MinMax $t:camel>]<const MIN: i64, const MAX: i64, const DEN: u64 = 1> = $crate::Refined<[<MinMaxRule $t:camel>]<MIN, MAX, DEN>>;
+
+            /// Rule where the target value must be greater than or equal to `MIN / DEN` and less than or equal to `MAX / DEN`
+            pub type [<MinMaxRule $t:camel>]<const MIN: i64, const MAX: i64, const DEN: u64 = 1> = $crate::And![
+                $crate::rule::[<GreaterEqualRule $t:camel>]<MIN, DEN>,
+                $crate::rule::[<LessEqualRule $t:camel>]<MAX, DEN>
+            ];
+        }
+    };
+    ($t: ty, $($ts: ty),+) => {
+        define_min_max_float_rule!($t);
+        define_min_max_float_rule!($($ts), +);
+    };
+}
+
+define_min_max_float_rule!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use crate::rule::MinMaxF64;
+
+    #[test]
+    fn test_min_max_f64_ok() {
+        assert!(MinMaxF64::<1, 10>::new(1.0).is_ok());
+        assert!(MinMaxF64::<1, 10>::new(10.0).is_ok());
+    }
+
+    #[test]
+    fn test_min_max_f64_err() {
+        assert!(MinMaxF64::<1, 10>::new(0.5).is_err());
+        assert!(MinMaxF64::<1, 10>::new(10.5).is_err());
+        assert!(MinMaxF64::<1, 10>::new(f64::NAN).is_err());
+    }
+}
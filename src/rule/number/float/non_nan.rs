@@ -0,0 +1,70 @@
+macro_rules! define_non_nan_float_rule {
+    ($t: ty) => {
+        $crate::paste::item! {
+            /// A type that holds a value satisfying the `NonNanRule`
+            pub type [<NonNan $t:camel>] = $crate::Refined<[<NonNanRule $t:camel>]>;
+
+            /// Rule where the target value must not be NaN
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+            pub struct [<NonNanRule $t:camel>];
+
+            impl $crate::rule::Rule for [<NonNanRule $t:camel>] {
+                type Item = $t;
+
+                fn validate(target: Self::Item) -> Result<Self::Item, $crate::result::Error<Self::Item>> {
+                    match <Self as $crate::rule::RuleRef>::validate_ref(&target) {
+                        Ok(()) => Ok(target),
+                        Err(tree) => Err($crate::result::Error::from_tree(target, *tree)),
+                    }
+                }
+            }
+
+            impl $crate::rule::RuleRef for [<NonNanRule $t:camel>] {
+                fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    if !target.is_nan() {
+                        Ok(())
+                    } else {
+                        let tree = $crate::result::ErrorTree::new(format!("the value must not be NaN, but received {target}"))
+                            .with_rule(stringify!([<NonNanRule $t:camel>]))
+                            .with_expected("not NaN")
+                            .with_actual(target.to_string());
+                        Err(Box::new(tree))
+                    }
+                }
+            }
+
+            impl $crate::rule::RuleDescription for [<NonNanRule $t:camel>] {
+                fn description() -> String {
+                    "not NaN".to_string()
+                }
+
+                fn json_schema() -> $crate::__private::serde_json::Value {
+                    $crate::rule::JsonType::Number.schema()
+                }
+            }
+        }
+    };
+    ($t: ty, $($ts: ty),+) => {
+        define_non_nan_float_rule!($t);
+        define_non_nan_float_rule!($($ts), +);
+    };
+}
+
+define_non_nan_float_rule!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use crate::rule::{NonNanF32, NonNanF64};
+
+    #[test]
+    fn test_non_nan_f64_ok() {
+        assert!(NonNanF64::new(f64::INFINITY).is_ok());
+        assert!(NonNanF64::new(-1.0).is_ok());
+    }
+
+    #[test]
+    fn test_non_nan_err() {
+        assert!(NonNanF64::new(f64::NAN).is_err());
+        assert!(NonNanF32::new(f32::NAN).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+macro_rules! define_positive_float_rule {
+    ($t: ty) => {
+        $crate::paste::item! {
+            /// A type that holds a value satisfying the `PositiveRule`
+            pub type [<Positive $t:camel>] = $crate::Refined<[<PositiveRule $t:camel>]>;
+
+            /// Rule where the target value must be greater than 0
+            pub type [<PositiveRule $t:camel>] = $crate::rule::[<GreaterRule $t:camel>]<0>;
+        }
+    };
+    ($t: ty, $($ts: ty),+) => {
+        define_positive_float_rule!($t);
+        define_positive_float_rule!($($ts), +);
+    };
+}
+
+define_positive_float_rule!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use crate::rule::PositiveF64;
+
+    #[test]
+    fn test_positive_f64_ok() {
+        assert!(PositiveF64::new(f64::MIN_POSITIVE).is_ok());
+        assert!(PositiveF64::new(f64::INFINITY).is_ok());
+    }
+
+    #[test]
+    fn test_positive_f64_err() {
+        assert!(PositiveF64::new(0.0).is_err());
+        assert!(PositiveF64::new(-0.0).is_err());
+        assert!(PositiveF64::new(f64::NAN).is_err());
+    }
+}
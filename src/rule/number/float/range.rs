@@ -0,0 +1,45 @@
+macro_rules! define_range_float_rule {
+    ($t: ty) => {
+        $crate::paste::item! {
+            /// A type that holds a value satisfying the `RangeRule`
+            pub type [<Range $t:camel>]<const FROM: i64, const UNTIL: i64, const DEN: u64 = 1> = $crate::Refined<[<RangeRule $t:camel>]<FROM, UNTIL, DEN>>;
+
+            /// Rule where the target value must be greater than or equal to `FROM / DEN` and less than `UNTIL / DEN`
+            pub type [<RangeRule $t:camel>]<const FROM: i64, const UNTIL: i64, const DEN: u64 = 1> = $crate::And![
+                $crate::rule::[<GreaterEqualRule $t:camel>]<FROM, DEN>,
+                $crate::rule::[<LessRule $t:camel>]<UNTIL, DEN>
+            ];
+        }
+    };
+    ($t: ty, $($ts: ty),+) => {
+        define_range_float_rule!($t);
+        define_range_float_rule!($($ts), +);
+    };
+}
+
+define_range_float_rule!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use crate::rule::{RangeF32, RangeF64};
+
+    #[test]
+    fn test_range_f64_ok() {
+        assert!(RangeF64::<-1, 1>::new(-1.0).is_ok());
+        assert!(RangeF64::<-1, 1>::new(0.999).is_ok());
+    }
+
+    #[test]
+    fn test_range_f64_err() {
+        assert!(RangeF64::<-1, 1>::new(1.0).is_err());
+        assert!(RangeF64::<-1, 1>::new(-1.001).is_err());
+        assert!(RangeF64::<-1, 1>::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_range_f32_with_denominator() {
+        // [0.25, 0.75)
+        assert!(RangeF32::<1, 3, 4>::new(0.25).is_ok());
+        assert!(RangeF32::<1, 3, 4>::new(0.75).is_err());
+    }
+}
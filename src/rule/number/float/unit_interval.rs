@@ -0,0 +1,36 @@
+macro_rules! define_unit_interval_float_rule {
+    ($t: ty) => {
+        $crate::paste::item! {
+            /// A type that holds a value satisfying the `UnitIntervalRule`
+            pub type [<UnitInterval $t:camel>] = $crate::Refined<[<UnitIntervalRule $t:camel>]>;
+
+            /// Rule where the target value must be greater than or equal to 0 and less than or equal to 1
+            pub type [<UnitIntervalRule $t:camel>] = $crate::rule::[<MinMaxRule $t:camel>]<0, 1>;
+        }
+    };
+    ($t: ty, $($ts: ty),+) => {
+        define_unit_interval_float_rule!($t);
+        define_unit_interval_float_rule!($($ts), +);
+    };
+}
+
+define_unit_interval_float_rule!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use crate::rule::UnitIntervalF64;
+
+    #[test]
+    fn test_unit_interval_f64_ok() {
+        assert!(UnitIntervalF64::new(0.0).is_ok());
+        assert!(UnitIntervalF64::new(0.5).is_ok());
+        assert!(UnitIntervalF64::new(1.0).is_ok());
+    }
+
+    #[test]
+    fn test_unit_interval_f64_err() {
+        assert!(UnitIntervalF64::new(-0.1).is_err());
+        assert!(UnitIntervalF64::new(1.1).is_err());
+        assert!(UnitIntervalF64::new(f64::NAN).is_err());
+    }
+}
@@ -12,13 +12,40 @@ macro_rules! define_less_rule {
                 type Item = $t;
 
                 fn validate(target: Self::Item) -> Result<Self::Item, $crate::result::Error<Self::Item>> {
-                    if target < THAN {
-                        Ok(target)
+                    match <Self as $crate::rule::RuleRef>::validate_ref(&target) {
+                        Ok(()) => Ok(target),
+                        Err(tree) => Err($crate::result::Error::from_tree(target, *tree)),
+                    }
+                }
+            }
+
+            impl<const THAN: $t> $crate::rule::RuleRef for [<LessRule $t:camel>]<THAN> {
+                fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    if *target < THAN {
+                        Ok(())
                     } else {
-                        Err($crate::result::Error::new(target, format!("the value must be less than {THAN}, but received {target}")))
+                        let tree = $crate::result::ErrorTree::new(format!("the value must be less than {THAN}, but received {target}"))
+                            .with_rule(stringify!([<LessRule $t:camel>]))
+                            .with_expected(format!("less than {THAN}"))
+                            .with_actual(target.to_string());
+                        Err(Box::new(tree))
                     }
                 }
             }
+
+            impl<const THAN: $t> $crate::rule::RuleDescription for [<LessRule $t:camel>]<THAN> {
+                fn description() -> String {
+                    format!("less than {THAN}")
+                }
+
+                fn json_schema() -> $crate::__private::serde_json::Value {
+                    $crate::rule::description::typed_schema($crate::rule::JsonType::Integer, [("exclusiveMaximum", $crate::rule::description::json_number(THAN))])
+                }
+            }
+
+            impl<const THAN: $t, const NEGATIVE: bool, const ABS: u128> $crate::rule::ConstRule<$crate::rule::IntLiteral<NEGATIVE, ABS>> for [<LessRule $t:camel>]<THAN> {
+                const VALID: bool = $crate::rule::IntLiteral::<NEGATIVE, ABS>::compare(THAN as i128, THAN as u128, <$t>::MIN != 0).is_lt();
+            }
         }
     };
     ($t: ty, $($ts: ty),+) => {
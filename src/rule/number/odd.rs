@@ -12,13 +12,40 @@ macro_rules! odd_rule {
                 type Item = $t;
 
                 fn validate(target: Self::Item) -> Result<Self::Item, $crate::result::Error<Self::Item>> {
-                    if target % 2 == 1 {
-                        Ok(target)
+                    match <Self as $crate::rule::RuleRef>::validate_ref(&target) {
+                        Ok(()) => Ok(target),
+                        Err(tree) => Err($crate::result::Error::from_tree(target, *tree)),
+                    }
+                }
+            }
+
+            impl $crate::rule::RuleRef for [<OddRule $t:upper>] {
+                fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    if *target % 2 != 0 {
+                        Ok(())
                     } else {
-                        Err($crate::result::Error::new(target, format!("the value must be odd, but received {target}")))
+                        let tree = $crate::result::ErrorTree::new(format!("the value must be odd, but received {target}"))
+                            .with_rule(stringify!([<OddRule $t:upper>]))
+                            .with_expected("odd")
+                            .with_actual(target.to_string());
+                        Err(Box::new(tree))
                     }
                 }
             }
+
+            impl $crate::rule::RuleDescription for [<OddRule $t:upper>] {
+                fn description() -> String {
+                    "odd".to_string()
+                }
+
+                fn json_schema() -> $crate::__private::serde_json::Value {
+                    $crate::rule::description::typed_schema($crate::rule::JsonType::Integer, [("not", $crate::__private::serde_json::json!({ "multipleOf": 2 }))])
+                }
+            }
+
+            impl<const NEGATIVE: bool, const ABS: u128> $crate::rule::ConstRule<$crate::rule::IntLiteral<NEGATIVE, ABS>> for [<OddRule $t:upper>] {
+                const VALID: bool = !$crate::rule::IntLiteral::<NEGATIVE, ABS>::is_even();
+            }
         }
     };
     ($t: ty, $($ts: ty),+) => {
@@ -31,7 +58,7 @@ odd_rule!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
 #[cfg(test)]
 mod test {
-    use crate::rule::OddU8;
+    use crate::rule::{OddI8, OddU8};
 
     #[test]
     fn test_odd_u8_ok() {
@@ -46,4 +73,10 @@ mod test {
         let even_result = OddU8::new(n);
         assert!(even_result.is_err())
     }
+
+    #[test]
+    fn test_odd_i8_negative_ok() {
+        let odd_result = OddI8::new(-7);
+        assert!(odd_result.is_ok())
+    }
 }
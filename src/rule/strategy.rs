@@ -0,0 +1,279 @@
+pub(crate) mod collection;
+mod composer;
+mod item;
+mod length;
+mod number;
+pub(crate) mod string;
+
+use crate::rule::{Invalid, Rule, Valid};
+use proptest::strategy::{BoxedStrategy, LazyJust, Strategy};
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+pub use string::regex_strategy;
+
+/// This is a `trait` that generates values satisfying a `Rule`, for property-based testing with `proptest`
+///
+/// The strategy of a composer is built from those of its sub-rules rather than by filtering random values,
+/// e.g. `And` generates values from its first rule within the `Bounds` of both rules,
+/// so `RangeI32<0, 100>` only generates integers from 0 to 99.
+/// A rule that has no dedicated strategy can fall back on `filtered_strategy`.
+/// # Example
+/// ```rust
+/// use proptest::prelude::*;
+/// use refined_type::rule::{NonEmptyString, RangeI32};
+///
+/// proptest!(|(percent in any::<RangeI32<0, 100>>(), name in any::<NonEmptyString>())| {
+///     prop_assert!((0..100).contains(percent.value()));
+///     prop_assert!(!name.is_empty());
+/// });
+/// ```
+pub trait RuleStrategy: Rule<Item: Debug + 'static> + 'static {
+    /// Whether the strategy draws arbitrary values within the bounds, filtering them by the rule if needed,
+    /// in which case `And` generates values from the strategy of the other rule when that one is not arbitrary
+    #[doc(hidden)]
+    const ARBITRARY: bool = false;
+
+    /// The bounds that every value satisfying the rule falls within
+    fn bounds() -> Bounds<Self::Item> {
+        Bounds::unbounded()
+    }
+
+    /// Generates values that satisfy the rule, within `bounds` as far as possible
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item>;
+
+    /// Generates values that satisfy the rule
+    fn strategy() -> BoxedStrategy<Self::Item> {
+        Self::strategy_within(&Self::bounds())
+    }
+}
+
+/// The inclusive ranges that the value and the length of a generated value fall within
+///
+/// `None` means that the rule does not bound it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bounds<T> {
+    value: Option<RangeInclusive<T>>,
+    length: Option<RangeInclusive<usize>>,
+}
+
+impl<T> Bounds<T> {
+    pub fn unbounded() -> Self {
+        Self {
+            value: None,
+            length: None,
+        }
+    }
+
+    pub fn value(range: RangeInclusive<T>) -> Self {
+        Self {
+            value: Some(range),
+            length: None,
+        }
+    }
+
+    pub fn length(range: RangeInclusive<usize>) -> Self {
+        Self {
+            value: None,
+            length: Some(range),
+        }
+    }
+
+    pub fn value_range(&self) -> Option<&RangeInclusive<T>> {
+        self.value.as_ref()
+    }
+
+    pub fn length_range(&self) -> Option<&RangeInclusive<usize>> {
+        self.length.as_ref()
+    }
+
+    /// The length bounds that both `self` and `other` allow, for the items whose values are not ordered
+    pub fn intersect_length(&self, other: &Self) -> Self {
+        Self {
+            value: None,
+            length: intersect(self.length.as_ref(), other.length.as_ref()),
+        }
+    }
+}
+
+impl<T: Clone + PartialOrd> Bounds<T> {
+    /// The bounds that both `self` and `other` allow
+    pub fn intersect(&self, other: &Self) -> Self {
+        Self {
+            value: intersect(self.value.as_ref(), other.value.as_ref()),
+            length: intersect(self.length.as_ref(), other.length.as_ref()),
+        }
+    }
+
+    /// The smallest bounds that contain both `self` and `other`
+    pub fn hull(&self, other: &Self) -> Self {
+        Self {
+            value: hull(self.value.as_ref(), other.value.as_ref()),
+            length: hull(self.length.as_ref(), other.length.as_ref()),
+        }
+    }
+
+    /// Returns `true` if no value falls within the bounds
+    pub fn is_empty(&self) -> bool {
+        self.value.as_ref().is_some_and(RangeInclusive::is_empty)
+            || self.length.as_ref().is_some_and(RangeInclusive::is_empty)
+    }
+}
+
+fn intersect<T: Clone + PartialOrd>(
+    range1: Option<&RangeInclusive<T>>,
+    range2: Option<&RangeInclusive<T>>,
+) -> Option<RangeInclusive<T>> {
+    match (range1, range2) {
+        (Some(range1), Some(range2)) => {
+            let start = partial_max(range1.start(), range2.start());
+            let end = partial_min(range1.end(), range2.end());
+            Some(start.clone()..=end.clone())
+        }
+        (range, None) | (None, range) => range.cloned(),
+    }
+}
+
+fn hull<T: Clone + PartialOrd>(
+    range1: Option<&RangeInclusive<T>>,
+    range2: Option<&RangeInclusive<T>>,
+) -> Option<RangeInclusive<T>> {
+    let (range1, range2) = (range1?, range2?);
+    let start = partial_min(range1.start(), range2.start());
+    let end = partial_max(range1.end(), range2.end());
+    Some(start.clone()..=end.clone())
+}
+
+fn partial_max<'a, T: PartialOrd>(value1: &'a T, value2: &'a T) -> &'a T {
+    if value1 < value2 {
+        value2
+    } else {
+        value1
+    }
+}
+
+fn partial_min<'a, T: PartialOrd>(value1: &'a T, value2: &'a T) -> &'a T {
+    if value2 < value1 {
+        value2
+    } else {
+        value1
+    }
+}
+
+/// This is a `trait` for the types of values that can be generated within `Bounds`
+///
+/// Integers, floats and `char` honor the value range, and strings and collections honor the length range.
+pub trait ArbitraryWithin: Sized + Debug + 'static {
+    fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self>;
+
+    /// Generates values without bounds
+    fn arbitrary_unbounded() -> BoxedStrategy<Self> {
+        Self::arbitrary_within(&Bounds::unbounded())
+    }
+}
+
+/// Generates the values of `strategy` that satisfy `RULE`, discarding the others
+///
+/// This is the strategy for a rule that has no dedicated one, such as a custom rule,
+/// so `strategy` should mostly generate valid values to avoid too many rejections.
+/// # Example
+/// ```rust
+/// use proptest::prelude::*;
+/// use refined_type::define_rule;
+/// use refined_type::rule::filtered_strategy;
+///
+/// define_rule![pub Port, u16, |port| *port != 0, "the port must not be zero"];
+///
+/// proptest!(|(port in filtered_strategy::<PortRule>(any::<u16>()))| {
+///     prop_assert!(Port::new(port).is_ok());
+/// });
+/// ```
+pub fn filtered_strategy<RULE>(
+    strategy: impl Strategy<Value = RULE::Item> + 'static,
+) -> BoxedStrategy<RULE::Item>
+where
+    RULE: Rule<Item: Debug + 'static> + 'static,
+{
+    strategy
+        .prop_filter_map("the value does not satisfy the rule", |value| {
+            RULE::validate(value).ok()
+        })
+        .boxed()
+}
+
+impl<T: ArbitraryWithin> RuleStrategy for Valid<T> {
+    const ARBITRARY: bool = true;
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        T::arbitrary_within(bounds)
+    }
+}
+
+impl<T: ArbitraryWithin> RuleStrategy for Invalid<T> {
+    const ARBITRARY: bool = true;
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        filtered_strategy::<Self>(T::arbitrary_within(bounds))
+    }
+}
+
+/// A strategy for bounds that no value falls within, which fails the test with the reason
+pub(crate) fn unsatisfiable<T: Debug + 'static>(
+    value: impl Fn() -> T + Clone + 'static,
+) -> BoxedStrategy<T> {
+    LazyJust::new(value)
+        .prop_filter("no value satisfies the rule within the bounds", |_| false)
+        .boxed()
+}
+
+/// Generates strings or collections by `strategy` with a length from `generated_length`
+pub(crate) fn sized_strategy<T, S>(
+    bounds: &Bounds<T>,
+    strategy: impl FnOnce(RangeInclusive<usize>) -> S,
+) -> BoxedStrategy<T>
+where
+    T: Debug + Default + 'static,
+    S: Strategy<Value = T> + 'static,
+{
+    let length = generated_length(bounds);
+    if length.is_empty() {
+        unsatisfiable(T::default)
+    } else {
+        strategy(length).boxed()
+    }
+}
+
+/// The range of lengths to generate, which is at most 8 longer than the shortest length allowed
+pub(crate) fn generated_length<T>(bounds: &Bounds<T>) -> RangeInclusive<usize> {
+    const EXTRA_LENGTH: usize = 8;
+    let (start, end) = bounds
+        .length
+        .clone()
+        .map(RangeInclusive::into_inner)
+        .unwrap_or((0, usize::MAX));
+    start..=end.min(start.saturating_add(EXTRA_LENGTH))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::strategy::{generated_length, Bounds};
+
+    #[test]
+    fn test_bounds_intersect_and_hull() {
+        let bounds1 = Bounds::value(0..=10);
+        let bounds2 = Bounds::value(5..=20);
+        assert_eq!(bounds1.intersect(&bounds2), Bounds::value(5..=10));
+        assert_eq!(bounds1.hull(&bounds2), Bounds::value(0..=20));
+        assert_eq!(bounds1.intersect(&Bounds::unbounded()), bounds1);
+        assert_eq!(bounds1.hull(&Bounds::unbounded()), Bounds::unbounded());
+        assert!(Bounds::value(0..=10)
+            .intersect(&Bounds::value(11..=20))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_generated_length() {
+        assert_eq!(generated_length(&Bounds::<String>::unbounded()), 0..=8);
+        assert_eq!(generated_length(&Bounds::<String>::length(3..=5)), 3..=5);
+        assert_eq!(generated_length(&Bounds::<String>::length(3..=100)), 3..=11);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::rule::composer::Not;
+use crate::rule::strategy::{
+    filtered_strategy, sized_strategy, ArbitraryWithin, Bounds, RuleStrategy,
+};
+use crate::rule::{
+    CountEqualRule, CountGreaterRule, CountLessRule, IndexRuleString, IndexRuleVec,
+    IndexRuleVecDeque, Iterable, IterableItems, ReverseRule, SkipOption, SkipRule,
+};
+use proptest::arbitrary::any;
+use proptest::collection;
+use proptest::strategy::{BoxedStrategy, Strategy};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// The number of extra items generated beside the ones that a count rule requires
+pub(crate) const EXTRA_COUNT: usize = 4;
+
+/// Every item is generated by the strategy of `RULE`, so the items skipped by `OPTION` are valid too
+impl<RULE, ITERABLE, OPTION> RuleStrategy for SkipRule<RULE, ITERABLE, OPTION>
+where
+    RULE: RuleStrategy,
+    ITERABLE:
+        IterableItems<Item = RULE::Item> + FromIterator<RULE::Item> + Default + Debug + 'static,
+    OPTION: SkipOption<Item = RULE::Item> + 'static,
+{
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        sized_strategy(bounds, |length| {
+            collection::vec(RULE::strategy(), length).prop_map(ITERABLE::from_iter)
+        })
+    }
+}
+
+impl<const INDEX: usize, RULE, T> RuleStrategy for IndexRuleVec<INDEX, RULE>
+where
+    RULE: RuleStrategy<Item = T>,
+    T: ArbitraryWithin,
+{
+    fn bounds() -> Bounds<Self::Item> {
+        Bounds::length(INDEX.saturating_add(1)..=usize::MAX)
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        sized_strategy(&Self::bounds().intersect_length(bounds), |length| {
+            (
+                collection::vec(T::arbitrary_unbounded(), length),
+                RULE::strategy(),
+            )
+                .prop_map(|(mut target, item)| {
+                    target[INDEX] = item;
+                    target
+                })
+        })
+    }
+}
+
+impl<const INDEX: usize, RULE, T> RuleStrategy for IndexRuleVecDeque<INDEX, RULE>
+where
+    RULE: RuleStrategy<Item = T>,
+    T: ArbitraryWithin,
+{
+    fn bounds() -> Bounds<Self::Item> {
+        Bounds::length(INDEX.saturating_add(1)..=usize::MAX)
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        sized_strategy(&Self::bounds().intersect_length(bounds), |length| {
+            (
+                collection::vec_deque(T::arbitrary_unbounded(), length),
+                RULE::strategy(),
+            )
+                .prop_map(|(mut target, item)| {
+                    target[INDEX] = item;
+                    target
+                })
+        })
+    }
+}
+
+impl<const INDEX: usize, RULE> RuleStrategy for IndexRuleString<INDEX, RULE>
+where
+    RULE: RuleStrategy<Item = char>,
+{
+    fn bounds() -> Bounds<Self::Item> {
+        Bounds::length(INDEX.saturating_add(1)..=usize::MAX)
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        sized_strategy(&Self::bounds().intersect_length(bounds), |length| {
+            let (start, end) = length.into_inner();
+            (
+                collection::vec(any::<char>(), INDEX),
+                RULE::strategy(),
+                collection::vec(any::<char>(), start - INDEX - 1..=end - INDEX - 1),
+            )
+                .prop_map(|(init, item, tail)| {
+                    init.into_iter()
+                        .chain(std::iter::once(item))
+                        .chain(tail)
+                        .collect()
+                })
+        })
+    }
+}
+
+impl<RULE, ITERABLE> RuleStrategy for ReverseRule<RULE>
+where
+    RULE: RuleStrategy<Item = ITERABLE>,
+    ITERABLE: Iterable + FromIterator<ITERABLE::Item> + Debug + 'static,
+{
+    fn bounds() -> Bounds<Self::Item> {
+        Bounds::unbounded().intersect_length(&RULE::bounds())
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        RULE::strategy_within(&Bounds::unbounded().intersect_length(bounds))
+            .prop_map(|target| ITERABLE::from_iter(target.into_iterator().rev()))
+            .boxed()
+    }
+}
+
+/// Generates `valid` items satisfying `RULE` and `invalid` items not satisfying it in random order
+pub(crate) fn counted_strategy<RULE, ITERABLE>(
+    valid: RangeInclusive<usize>,
+    invalid: RangeInclusive<usize>,
+) -> BoxedStrategy<ITERABLE>
+where
+    RULE: RuleStrategy<Item: ArbitraryWithin>,
+    ITERABLE: FromIterator<RULE::Item> + Debug + 'static,
+{
+    (
+        collection::vec(RULE::strategy(), valid),
+        collection::vec(Not::<RULE>::strategy(), invalid),
+    )
+        .prop_map(|(valid, invalid)| valid.into_iter().chain(invalid).collect::<VecDeque<_>>())
+        .prop_shuffle()
+        .prop_map(ITERABLE::from_iter)
+        .boxed()
+}
+
+impl<const N: usize, RULE, ITERABLE> RuleStrategy for CountEqualRule<N, RULE, ITERABLE>
+where
+    RULE: RuleStrategy<Item: ArbitraryWithin>,
+    ITERABLE: IterableItems<Item = RULE::Item> + FromIterator<RULE::Item> + Debug + 'static,
+{
+    fn strategy_within(_bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        filtered_strategy::<Self>(counted_strategy::<RULE, ITERABLE>(N..=N, 0..=EXTRA_COUNT))
+    }
+}
+
+impl<const N: usize, RULE, ITERABLE> RuleStrategy for CountGreaterRule<N, RULE, ITERABLE>
+where
+    RULE: RuleStrategy<Item: ArbitraryWithin>,
+    ITERABLE: IterableItems<Item = RULE::Item> + FromIterator<RULE::Item> + Debug + 'static,
+{
+    fn strategy_within(_bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        let valid = N.saturating_add(1)..=N.saturating_add(1 + EXTRA_COUNT);
+        filtered_strategy::<Self>(counted_strategy::<RULE, ITERABLE>(valid, 0..=EXTRA_COUNT))
+    }
+}
+
+impl<const N: usize, RULE, ITERABLE> RuleStrategy for CountLessRule<N, RULE, ITERABLE>
+where
+    RULE: RuleStrategy<Item: ArbitraryWithin>,
+    ITERABLE: IterableItems<Item = RULE::Item> + FromIterator<RULE::Item> + Debug + 'static,
+{
+    fn strategy_within(_bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        let valid = 0..=N.saturating_sub(1).min(EXTRA_COUNT);
+        filtered_strategy::<Self>(counted_strategy::<RULE, ITERABLE>(valid, 0..=EXTRA_COUNT))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{
+        Bounds, CountEqualVec, CountGreaterEqualVec, CountLessString, EqualRuleU8, EvenRuleI32,
+        ExistsVec, ForAllString, ForAllVec, ForAllVecDeque, ForAllVecRule, HeadString, HeadVec,
+        IndexVec, InitVec, LastVecDeque, LengthMinMaxRule, NonEmptyStringRule, NothingVec,
+        RangeRuleU8, RuleStrategy, TailVec,
+    };
+    use crate::{define_rule, And, Refined};
+    use proptest::prelude::*;
+
+    define_rule![
+        Vowel,
+        char,
+        |c| "aeiou".contains(*c),
+        "{value} is not a vowel"
+    ];
+
+    impl RuleStrategy for VowelRule {
+        fn strategy_within(_bounds: &Bounds<char>) -> BoxedStrategy<char> {
+            proptest::sample::select(vec!['a', 'e', 'i', 'o', 'u']).boxed()
+        }
+    }
+
+    fn is_vowel(c: char) -> bool {
+        "aeiou".contains(c)
+    }
+
+    proptest! {
+        #[test]
+        fn test_for_all_strategy(
+            tags in any::<ForAllVec<NonEmptyStringRule>>(),
+            levels in any::<ForAllVecDeque<RangeRuleU8<1, 10>>>(),
+            vowels in any::<ForAllString<VowelRule>>(),
+            vowel in any::<Vowel>(),
+        ) {
+            prop_assert!(tags.iter().all(|tag| !tag.is_empty()));
+            prop_assert!(levels.iter().all(|level| (1..10).contains(level)));
+            prop_assert!(vowels.chars().all(is_vowel));
+            prop_assert!(is_vowel(vowel.into_value()));
+        }
+
+        #[test]
+        fn test_for_all_strategy_within_length(
+            tags in any::<Refined<And![LengthMinMaxRule<2, 3, Vec<String>>, ForAllVecRule<NonEmptyStringRule>]>>(),
+        ) {
+            prop_assert!((2..=3).contains(&tags.len()));
+            prop_assert!(tags.iter().all(|tag| !tag.is_empty()));
+        }
+
+        #[test]
+        fn test_index_strategy(
+            list in any::<IndexVec<2, EqualRuleU8<7>>>(),
+            head in any::<HeadVec<NonEmptyStringRule>>(),
+            last in any::<LastVecDeque<EvenRuleI32>>(),
+            initial in any::<HeadString<VowelRule>>(),
+        ) {
+            prop_assert_eq!(list[2], 7);
+            prop_assert!(!head[0].is_empty());
+            prop_assert!(last.back().unwrap() % 2 == 0);
+            prop_assert!(initial.starts_with(is_vowel));
+        }
+
+        #[test]
+        fn test_skip_strategy(tail in any::<TailVec<EvenRuleI32>>(), init in any::<InitVec<EvenRuleI32>>()) {
+            prop_assert!(tail.iter().skip(1).all(|value| value % 2 == 0));
+            prop_assert!(init.iter().rev().skip(1).all(|value| value % 2 == 0));
+        }
+
+        #[test]
+        fn test_exists_and_nothing_strategy(exists in any::<ExistsVec<EvenRuleI32>>(), nothing in any::<NothingVec<EvenRuleI32>>()) {
+            prop_assert!(exists.iter().any(|value| value % 2 == 0));
+            prop_assert!(nothing.iter().all(|value| value % 2 != 0));
+        }
+
+        #[test]
+        fn test_count_strategy(
+            equal in any::<CountEqualVec<2, EvenRuleI32>>(),
+            greater_equal in any::<CountGreaterEqualVec<3, EvenRuleI32>>(),
+            less in any::<CountLessString<2, VowelRule>>(),
+        ) {
+            prop_assert_eq!(equal.iter().filter(|value| *value % 2 == 0).count(), 2);
+            prop_assert!(greater_equal.iter().filter(|value| *value % 2 == 0).count() >= 3);
+            prop_assert!(less.chars().filter(|c| is_vowel(*c)).count() < 2);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::rule::composer::{And, AndAll, Normalized, Not, Or};
+use crate::rule::strategy::{filtered_strategy, ArbitraryWithin, Bounds, RuleStrategy};
+use crate::rule::{Rule, Transform};
+use proptest::strategy::{BoxedStrategy, Strategy, Union};
+use std::fmt::Debug;
+
+/// Generates values from the strategy of one rule within the bounds of both, and filters them by the other rule
+fn and_strategy<T, RULE1, RULE2>(bounds: &Bounds<T>) -> BoxedStrategy<T>
+where
+    T: Debug + Clone + PartialOrd + 'static,
+    RULE1: RuleStrategy<Item = T>,
+    RULE2: RuleStrategy<Item = T>,
+{
+    let bounds = bounds
+        .intersect(&RULE1::bounds())
+        .intersect(&RULE2::bounds());
+    if RULE1::ARBITRARY && !RULE2::ARBITRARY {
+        filtered_strategy::<RULE1>(RULE2::strategy_within(&bounds))
+    } else {
+        filtered_strategy::<RULE2>(RULE1::strategy_within(&bounds))
+    }
+}
+
+impl<T, RULE1, RULE2> RuleStrategy for And<RULE1, RULE2>
+where
+    T: Debug + Clone + PartialOrd + 'static,
+    RULE1: RuleStrategy<Item = T>,
+    RULE2: RuleStrategy<Item = T>,
+{
+    const ARBITRARY: bool = RULE1::ARBITRARY && RULE2::ARBITRARY;
+
+    fn bounds() -> Bounds<Self::Item> {
+        RULE1::bounds().intersect(&RULE2::bounds())
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        and_strategy::<T, RULE1, RULE2>(bounds)
+    }
+}
+
+impl<T, RULE1, RULE2> RuleStrategy for AndAll<RULE1, RULE2>
+where
+    T: Debug + Clone + PartialOrd + 'static,
+    RULE1: RuleStrategy<Item = T>,
+    RULE2: RuleStrategy<Item = T>,
+{
+    const ARBITRARY: bool = RULE1::ARBITRARY && RULE2::ARBITRARY;
+
+    fn bounds() -> Bounds<Self::Item> {
+        RULE1::bounds().intersect(&RULE2::bounds())
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        and_strategy::<T, RULE1, RULE2>(bounds)
+    }
+}
+
+/// Generates values from the strategies of the rules whose bounds overlap `bounds`
+impl<T, RULE1, RULE2> RuleStrategy for Or<RULE1, RULE2>
+where
+    T: Debug + Clone + PartialOrd + 'static,
+    RULE1: RuleStrategy<Item = T>,
+    RULE2: RuleStrategy<Item = T>,
+{
+    const ARBITRARY: bool = RULE1::ARBITRARY && RULE2::ARBITRARY;
+
+    fn bounds() -> Bounds<Self::Item> {
+        RULE1::bounds().hull(&RULE2::bounds())
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        let bounds1 = bounds.intersect(&RULE1::bounds());
+        let bounds2 = bounds.intersect(&RULE2::bounds());
+        match (bounds1.is_empty(), bounds2.is_empty()) {
+            (false, true) => RULE1::strategy_within(&bounds1),
+            (true, false) => RULE2::strategy_within(&bounds2),
+            _ => Union::new([
+                RULE1::strategy_within(&bounds1),
+                RULE2::strategy_within(&bounds2),
+            ])
+            .boxed(),
+        }
+    }
+}
+
+impl<T, RULE> RuleStrategy for Not<RULE>
+where
+    T: ArbitraryWithin,
+    RULE: Rule<Item = T> + 'static,
+{
+    const ARBITRARY: bool = true;
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        filtered_strategy::<Self>(T::arbitrary_within(bounds))
+    }
+}
+
+/// Generates the values of `RULE` normalized by `TRANSFORM`, which are what `Refined` stores
+impl<T, TRANSFORM, RULE> RuleStrategy for Normalized<TRANSFORM, RULE>
+where
+    TRANSFORM: Transform<Item = T> + 'static,
+    RULE: RuleStrategy<Item = T>,
+{
+    fn bounds() -> Bounds<Self::Item> {
+        RULE::bounds()
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        filtered_strategy::<Self>(RULE::strategy_within(bounds).prop_map(TRANSFORM::transform))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::composer::{Normalized, Xor};
+    use crate::rule::{
+        EmailStringRule, LengthLessStringRule, LowercaseTransform, NonEmptyString,
+        NonEmptyStringRule, NonEmptyVec, Rule, RuleStrategy,
+    };
+    use crate::rule::{EvenRuleI32, GreaterRuleI32, LessRuleI32};
+    use crate::{And, Or, Refined};
+    use proptest::prelude::*;
+
+    type ShortEmail = And![
+        LengthLessStringRule<40>,
+        NonEmptyStringRule,
+        EmailStringRule
+    ];
+    type Extreme = Or![LessRuleI32<-100>, GreaterRuleI32<100>];
+
+    proptest! {
+        #[test]
+        fn test_and_strategy(email in any::<Refined<ShortEmail>>()) {
+            prop_assert!(email.chars().count() < 40);
+            prop_assert!(email.contains('@'));
+        }
+
+        #[test]
+        fn test_or_strategy(value in any::<Refined<Extreme>>()) {
+            prop_assert!(!(-100..=100).contains(value.value()));
+        }
+
+        #[test]
+        fn test_or_strategy_within_and(value in any::<Refined<And![Extreme, LessRuleI32<0>]>>()) {
+            prop_assert!(*value < -100);
+        }
+
+        #[test]
+        fn test_xor_strategy(value in <Xor<EvenRuleI32, GreaterRuleI32<0>>>::strategy()) {
+            prop_assert!(Xor::<EvenRuleI32, GreaterRuleI32<0>>::validate(value).is_ok());
+        }
+
+        #[test]
+        fn test_not_strategy(name in any::<NonEmptyString>(), list in any::<NonEmptyVec<u8>>()) {
+            prop_assert!(!name.is_empty());
+            prop_assert!(!list.is_empty());
+        }
+
+        #[test]
+        fn test_normalized_strategy(email in any::<Refined<Normalized<LowercaseTransform, EmailStringRule>>>()) {
+            prop_assert_eq!(email.to_lowercase(), email.into_value());
+        }
+    }
+}
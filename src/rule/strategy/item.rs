@@ -0,0 +1,140 @@
+use crate::rule::strategy::{sized_strategy, unsatisfiable, ArbitraryWithin, Bounds};
+use proptest::arbitrary::any;
+use proptest::collection;
+use proptest::strategy::{BoxedStrategy, Strategy};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::Hash;
+
+macro_rules! impl_integer {
+    ($($t: ty),+) => {$(
+        impl ArbitraryWithin for $t {
+            fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+                match bounds.value_range() {
+                    Some(range) if range.is_empty() => unsatisfiable({
+                        let start = *range.start();
+                        move || start
+                    }),
+                    Some(range) => range.clone().boxed(),
+                    None => any::<$t>().boxed(),
+                }
+            }
+        }
+    )+};
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float {
+    ($($t: ident),+) => {$(
+        impl ArbitraryWithin for $t {
+            fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+                match bounds.value_range().cloned().map(|range| range.into_inner()) {
+                    Some((start, end)) if start.partial_cmp(&end).is_none_or(Ordering::is_gt) => {
+                        unsatisfiable(move || start)
+                    }
+                    Some((start, end)) if (end - start).is_finite() => (start..=end).boxed(),
+                    Some((start, end)) => any::<$t>()
+                        .prop_filter("the value is out of bounds", move |value| {
+                            start <= *value && *value <= end
+                        })
+                        .boxed(),
+                    None => proptest::num::$t::ANY.boxed(),
+                }
+            }
+        }
+    )+};
+}
+
+impl_float!(f32, f64);
+
+impl ArbitraryWithin for bool {
+    fn arbitrary_within(_bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+        any::<bool>().boxed()
+    }
+}
+
+impl ArbitraryWithin for char {
+    fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+        match bounds.value_range() {
+            Some(range) if range.is_empty() => unsatisfiable({
+                let start = *range.start();
+                move || start
+            }),
+            Some(range) => proptest::char::range(*range.start(), *range.end()).boxed(),
+            None => any::<char>().boxed(),
+        }
+    }
+}
+
+impl ArbitraryWithin for String {
+    fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+        sized_strategy(bounds, |length| {
+            collection::vec(any::<char>(), length).prop_map(String::from_iter)
+        })
+    }
+}
+
+impl<T: ArbitraryWithin> ArbitraryWithin for Vec<T> {
+    fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+        sized_strategy(bounds, |length| {
+            collection::vec(T::arbitrary_unbounded(), length)
+        })
+    }
+}
+
+impl<T: ArbitraryWithin> ArbitraryWithin for VecDeque<T> {
+    fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+        sized_strategy(bounds, |length| {
+            collection::vec_deque(T::arbitrary_unbounded(), length)
+        })
+    }
+}
+
+impl<T: ArbitraryWithin> ArbitraryWithin for LinkedList<T> {
+    fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+        sized_strategy(bounds, |length| {
+            collection::linked_list(T::arbitrary_unbounded(), length)
+        })
+    }
+}
+
+impl<T: ArbitraryWithin + Hash + Eq> ArbitraryWithin for HashSet<T> {
+    fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+        sized_strategy(bounds, |length| {
+            collection::hash_set(T::arbitrary_unbounded(), length)
+        })
+    }
+}
+
+impl<T: ArbitraryWithin + Ord> ArbitraryWithin for BTreeSet<T> {
+    fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+        sized_strategy(bounds, |length| {
+            collection::btree_set(T::arbitrary_unbounded(), length)
+        })
+    }
+}
+
+impl<K, V> ArbitraryWithin for HashMap<K, V>
+where
+    K: ArbitraryWithin + Hash + Eq,
+    V: ArbitraryWithin,
+{
+    fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+        sized_strategy(bounds, |length| {
+            collection::hash_map(K::arbitrary_unbounded(), V::arbitrary_unbounded(), length)
+        })
+    }
+}
+
+impl<K, V> ArbitraryWithin for BTreeMap<K, V>
+where
+    K: ArbitraryWithin + Ord,
+    V: ArbitraryWithin,
+{
+    fn arbitrary_within(bounds: &Bounds<Self>) -> BoxedStrategy<Self> {
+        sized_strategy(bounds, |length| {
+            collection::btree_map(K::arbitrary_unbounded(), V::arbitrary_unbounded(), length)
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use crate::rule::strategy::{filtered_strategy, ArbitraryWithin, Bounds, RuleStrategy};
+use crate::rule::{
+    EmptyDefinition, EmptyRule, LengthDefinition, LengthEqualRule, LengthGreaterRule,
+    LengthLessRule,
+};
+use proptest::strategy::{BoxedStrategy, LazyJust, Strategy};
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+impl<const THAN: usize, ITEM> RuleStrategy for LengthGreaterRule<THAN, ITEM>
+where
+    ITEM: ArbitraryWithin + LengthDefinition,
+{
+    const ARBITRARY: bool = true;
+
+    fn bounds() -> Bounds<Self::Item> {
+        Bounds::length(THAN.saturating_add(1)..=usize::MAX)
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        filtered_strategy::<Self>(ITEM::arbitrary_within(
+            &Self::bounds().intersect_length(bounds),
+        ))
+    }
+}
+
+impl<const LENGTH: usize, ITEM> RuleStrategy for LengthEqualRule<LENGTH, ITEM>
+where
+    ITEM: ArbitraryWithin + LengthDefinition,
+{
+    const ARBITRARY: bool = true;
+
+    fn bounds() -> Bounds<Self::Item> {
+        Bounds::length(LENGTH..=LENGTH)
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        filtered_strategy::<Self>(ITEM::arbitrary_within(
+            &Self::bounds().intersect_length(bounds),
+        ))
+    }
+}
+
+impl<const THAN: usize, ITEM> RuleStrategy for LengthLessRule<THAN, ITEM>
+where
+    ITEM: ArbitraryWithin + LengthDefinition,
+{
+    const ARBITRARY: bool = true;
+
+    fn bounds() -> Bounds<Self::Item> {
+        match THAN.checked_sub(1) {
+            Some(end) => Bounds::length(0..=end),
+            None => Bounds::length(RangeInclusive::new(1, 0)),
+        }
+    }
+
+    fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        filtered_strategy::<Self>(ITEM::arbitrary_within(
+            &Self::bounds().intersect_length(bounds),
+        ))
+    }
+}
+
+impl<T> RuleStrategy for EmptyRule<T>
+where
+    T: Default + Debug + EmptyDefinition + 'static,
+{
+    fn bounds() -> Bounds<Self::Item> {
+        Bounds::length(0..=0)
+    }
+
+    fn strategy_within(_bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        LazyJust::new(T::default).boxed()
+    }
+}
@@ -0,0 +1,205 @@
+use crate::rule::strategy::{filtered_strategy, ArbitraryWithin, Bounds, RuleStrategy};
+use proptest::strategy::BoxedStrategy;
+
+macro_rules! impl_integer_rule_strategy {
+    ($($t: ty),+) => {$(
+        $crate::paste::item! {
+            impl<const THAN: $t> RuleStrategy for $crate::rule::[<GreaterRule $t:camel>]<THAN> {
+                const ARBITRARY: bool = true;
+
+                fn bounds() -> Bounds<Self::Item> {
+                    match THAN.checked_add(1) {
+                        Some(start) => Bounds::value(start..=<$t>::MAX),
+                        None => Bounds::value(std::ops::RangeInclusive::new(<$t>::MAX, <$t>::MIN)),
+                    }
+                }
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    <$t>::arbitrary_within(&Self::bounds().intersect(bounds))
+                }
+            }
+
+            impl<const THAN: $t> RuleStrategy for $crate::rule::[<LessRule $t:camel>]<THAN> {
+                const ARBITRARY: bool = true;
+
+                fn bounds() -> Bounds<Self::Item> {
+                    match THAN.checked_sub(1) {
+                        Some(end) => Bounds::value(<$t>::MIN..=end),
+                        None => Bounds::value(std::ops::RangeInclusive::new(<$t>::MAX, <$t>::MIN)),
+                    }
+                }
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    <$t>::arbitrary_within(&Self::bounds().intersect(bounds))
+                }
+            }
+
+            impl<const EQUAL: $t> RuleStrategy for $crate::rule::[<EqualRule $t:camel>]<EQUAL> {
+                const ARBITRARY: bool = true;
+
+                fn bounds() -> Bounds<Self::Item> {
+                    Bounds::value(EQUAL..=EQUAL)
+                }
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    <$t>::arbitrary_within(&Self::bounds().intersect(bounds))
+                }
+            }
+
+            impl RuleStrategy for $crate::rule::[<EvenRule $t:upper>] {
+                const ARBITRARY: bool = true;
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    filtered_strategy::<Self>(<$t>::arbitrary_within(bounds))
+                }
+            }
+
+            impl RuleStrategy for $crate::rule::[<OddRule $t:upper>] {
+                const ARBITRARY: bool = true;
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    filtered_strategy::<Self>(<$t>::arbitrary_within(bounds))
+                }
+            }
+        }
+    )+};
+}
+
+impl_integer_rule_strategy!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// The float rules bound the generated values, and filter out the bound itself for the exclusive ones
+macro_rules! impl_float_rule_strategy {
+    ($($t: ty),+) => {$(
+        $crate::paste::item! {
+            impl<const NUM: i64, const DEN: u64> RuleStrategy for $crate::rule::[<GreaterRule $t:camel>]<NUM, DEN> {
+                const ARBITRARY: bool = true;
+
+                fn bounds() -> Bounds<Self::Item> {
+                    Bounds::value(NUM as $t / DEN as $t..=<$t>::INFINITY)
+                }
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    filtered_strategy::<Self>(<$t>::arbitrary_within(&Self::bounds().intersect(bounds)))
+                }
+            }
+
+            impl<const NUM: i64, const DEN: u64> RuleStrategy for $crate::rule::[<GreaterEqualRule $t:camel>]<NUM, DEN> {
+                const ARBITRARY: bool = true;
+
+                fn bounds() -> Bounds<Self::Item> {
+                    Bounds::value(NUM as $t / DEN as $t..=<$t>::INFINITY)
+                }
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    filtered_strategy::<Self>(<$t>::arbitrary_within(&Self::bounds().intersect(bounds)))
+                }
+            }
+
+            impl<const NUM: i64, const DEN: u64> RuleStrategy for $crate::rule::[<LessRule $t:camel>]<NUM, DEN> {
+                const ARBITRARY: bool = true;
+
+                fn bounds() -> Bounds<Self::Item> {
+                    Bounds::value(<$t>::NEG_INFINITY..=NUM as $t / DEN as $t)
+                }
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    filtered_strategy::<Self>(<$t>::arbitrary_within(&Self::bounds().intersect(bounds)))
+                }
+            }
+
+            impl<const NUM: i64, const DEN: u64> RuleStrategy for $crate::rule::[<LessEqualRule $t:camel>]<NUM, DEN> {
+                const ARBITRARY: bool = true;
+
+                fn bounds() -> Bounds<Self::Item> {
+                    Bounds::value(<$t>::NEG_INFINITY..=NUM as $t / DEN as $t)
+                }
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    filtered_strategy::<Self>(<$t>::arbitrary_within(&Self::bounds().intersect(bounds)))
+                }
+            }
+
+            impl RuleStrategy for $crate::rule::[<FiniteRule $t:camel>] {
+                const ARBITRARY: bool = true;
+
+                fn bounds() -> Bounds<Self::Item> {
+                    Bounds::value(<$t>::MIN..=<$t>::MAX)
+                }
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    filtered_strategy::<Self>(<$t>::arbitrary_within(&Self::bounds().intersect(bounds)))
+                }
+            }
+
+            impl RuleStrategy for $crate::rule::[<NonNanRule $t:camel>] {
+                const ARBITRARY: bool = true;
+
+                fn bounds() -> Bounds<Self::Item> {
+                    Bounds::value(<$t>::NEG_INFINITY..=<$t>::INFINITY)
+                }
+
+                fn strategy_within(bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+                    filtered_strategy::<Self>(<$t>::arbitrary_within(&Self::bounds().intersect(bounds)))
+                }
+            }
+        }
+    )+};
+}
+
+impl_float_rule_strategy!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{
+        GreaterEqualI8, GreaterRuleI8, GreaterU8, LessU8, MinMaxI32, OddI64, PositiveF64, RangeI32,
+        Rule, RuleStrategy, UnitIntervalF64,
+    };
+    use proptest::prelude::*;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    proptest! {
+        #[test]
+        fn test_range_strategy(value in any::<RangeI32<-10, 10>>()) {
+            prop_assert!((-10..10).contains(value.value()));
+        }
+
+        #[test]
+        fn test_min_max_strategy(value in any::<MinMaxI32<-1, 1>>()) {
+            prop_assert!((-1..=1).contains(value.value()));
+        }
+
+        #[test]
+        fn test_greater_equal_strategy(value in any::<GreaterEqualI8<126>>()) {
+            prop_assert!(*value >= 126);
+        }
+
+        #[test]
+        fn test_odd_strategy(value in any::<OddI64>()) {
+            prop_assert!(value.into_value() % 2 != 0);
+        }
+
+        #[test]
+        fn test_float_strategy(positive in any::<PositiveF64>(), unit in any::<UnitIntervalF64>()) {
+            prop_assert!(*positive > 0.0);
+            prop_assert!((0.0..=1.0).contains(unit.value()));
+        }
+    }
+
+    #[test]
+    fn test_unsatisfiable_integer_strategy() {
+        let mut runner = TestRunner::default();
+        assert!(GreaterU8::<255>::arbitrary().new_tree(&mut runner).is_err());
+        assert!(LessU8::<0>::arbitrary().new_tree(&mut runner).is_err());
+    }
+
+    #[test]
+    fn test_greater_i8_strategy_is_bounded() {
+        let strategy = GreaterRuleI8::<120>::strategy();
+        let mut runner = TestRunner::default();
+        for _ in 0..100 {
+            let value = strategy.new_tree(&mut runner).unwrap().current();
+            assert!(GreaterRuleI8::<120>::validate(value).is_ok());
+        }
+    }
+}
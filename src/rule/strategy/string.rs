@@ -0,0 +1,165 @@
+use crate::rule::strategy::{filtered_strategy, ArbitraryWithin, Bounds, RuleStrategy};
+use crate::rule::{Ipv4AddrRule, Ipv6AddrRule, PrivateIpv4AddrRule, PublicIpv4AddrRule, Rule};
+use proptest::arbitrary::any;
+use proptest::prop_oneof;
+use proptest::strategy::{BoxedStrategy, Strategy};
+use regex_syntax::hir::{Capture, Hir, HirKind, Repetition};
+use std::fmt::Debug;
+use std::net;
+
+/// The number of extra repetitions generated for an unbounded repetition such as `+` or `*`
+const EXTRA_REPETITION: u32 = 4;
+
+/// Generates strings that match `regex` and satisfy `RULE`
+///
+/// Anchors such as `^` and `$` are ignored while generating, and unbounded repetitions are kept short,
+/// so that the strings fit the length rules that are usually combined with a regex.
+/// Every rule declared by `declare_regex_rule!` generates its values in this way.
+/// # Example
+/// ```rust
+/// use proptest::prelude::*;
+/// use refined_type::rule::{regex_strategy, Rule};
+/// use refined_type::define_rule;
+///
+/// define_rule![pub Zip, String, |zip| zip.len() == 8 && zip.as_bytes()[3] == b'-', "invalid zip code"];
+///
+/// proptest!(|(zip in regex_strategy::<ZipRule>(r"^[0-9]{3}-[0-9]{4}$"))| {
+///     prop_assert!(ZipRule::validate(zip).is_ok());
+/// });
+/// ```
+pub fn regex_strategy<RULE>(regex: &str) -> BoxedStrategy<RULE::Item>
+where
+    RULE: Rule<Item: From<String> + Debug + 'static> + 'static,
+{
+    match regex_string_strategy(regex) {
+        Some(strategy) => filtered_strategy::<RULE>(strategy.prop_map(RULE::Item::from)),
+        None => filtered_strategy::<RULE>(
+            String::arbitrary_within(&Bounds::unbounded()).prop_map(RULE::Item::from),
+        ),
+    }
+}
+
+/// Generates strings that match `regex`, or returns `None` if `regex` is not supported
+pub(crate) fn regex_string_strategy(regex: &str) -> Option<impl Strategy<Value = String>> {
+    regex_syntax::Parser::new()
+        .parse(regex)
+        .ok()
+        .and_then(|hir| proptest::string::string_regex_parsed(&generated_hir(&hir)).ok())
+}
+
+/// Removes the look-around assertions and bounds the repetitions of `hir`
+fn generated_hir(hir: &Hir) -> Hir {
+    match hir.kind() {
+        HirKind::Look(_) => Hir::empty(),
+        HirKind::Repetition(repetition) => Hir::repetition(Repetition {
+            max: Some(
+                repetition
+                    .max
+                    .unwrap_or(repetition.min.saturating_add(EXTRA_REPETITION)),
+            ),
+            sub: Box::new(generated_hir(&repetition.sub)),
+            ..repetition.clone()
+        }),
+        HirKind::Capture(capture) => Hir::capture(Capture {
+            sub: Box::new(generated_hir(&capture.sub)),
+            ..capture.clone()
+        }),
+        HirKind::Concat(hirs) => Hir::concat(hirs.iter().map(generated_hir).collect()),
+        HirKind::Alternation(hirs) => Hir::alternation(hirs.iter().map(generated_hir).collect()),
+        _ => hir.clone(),
+    }
+}
+
+impl<T> RuleStrategy for Ipv4AddrRule<T>
+where
+    T: AsRef<str> + From<String> + Debug + 'static,
+{
+    fn strategy_within(_bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        any::<net::Ipv4Addr>()
+            .prop_map(|ip| T::from(ip.to_string()))
+            .boxed()
+    }
+}
+
+impl<T> RuleStrategy for PublicIpv4AddrRule<T>
+where
+    T: AsRef<str> + From<String> + Debug + 'static,
+{
+    fn strategy_within(_bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        any::<net::Ipv4Addr>()
+            .prop_filter("the address is private", |ip| !ip.is_private())
+            .prop_map(|ip| T::from(ip.to_string()))
+            .boxed()
+    }
+}
+
+impl<T> RuleStrategy for PrivateIpv4AddrRule<T>
+where
+    T: AsRef<str> + From<String> + Debug + 'static,
+{
+    fn strategy_within(_bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        prop_oneof![
+            any::<[u8; 3]>().prop_map(|[b, c, d]| net::Ipv4Addr::new(10, b, c, d)),
+            (16..=31u8, any::<[u8; 2]>()).prop_map(|(b, [c, d])| net::Ipv4Addr::new(172, b, c, d)),
+            any::<[u8; 2]>().prop_map(|[c, d]| net::Ipv4Addr::new(192, 168, c, d)),
+        ]
+        .prop_map(|ip| T::from(ip.to_string()))
+        .boxed()
+    }
+}
+
+impl<T> RuleStrategy for Ipv6AddrRule<T>
+where
+    T: AsRef<str> + From<String> + Debug + 'static,
+{
+    fn strategy_within(_bounds: &Bounds<Self::Item>) -> BoxedStrategy<Self::Item> {
+        any::<net::Ipv6Addr>()
+            .prop_map(|ip| T::from(ip.to_string()))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::strategy::string::generated_hir;
+    use crate::rule::{
+        AlphaDigit, Digit, EmailString, Ipv4Addr, Ipv6Addr, Katakana, PrivateIpv4Addr,
+        PublicIpv4Addr, UnicodeDigit,
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_generated_hir() {
+        let hir = regex_syntax::Parser::new().parse(r"^a+b*$").unwrap();
+        let expected = regex_syntax::Parser::new().parse(r"a{1,5}b{0,4}").unwrap();
+        assert_eq!(generated_hir(&hir), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn test_regex_strategy(email in any::<EmailString>(), digit in any::<Digit<String>>(), alpha_digit in any::<AlphaDigit<String>>()) {
+            prop_assert!(email.contains('@'));
+            prop_assert!(digit.chars().all(|c| c.is_ascii_digit()));
+            prop_assert!(alpha_digit.chars().all(|c| c.is_ascii_alphanumeric()));
+        }
+
+        #[test]
+        fn test_unicode_regex_strategy(digit in any::<UnicodeDigit<String>>(), katakana in any::<Katakana<String>>()) {
+            prop_assert!(digit.chars().all(char::is_numeric));
+            prop_assert!(!katakana.chars().any(|c| ('ぁ'..='ゖ').contains(&c) || c.is_ascii()));
+        }
+
+        #[test]
+        fn test_ip_addr_strategy(
+            ipv4 in any::<Ipv4Addr<String>>(),
+            ipv6 in any::<Ipv6Addr<String>>(),
+            public in any::<PublicIpv4Addr<String>>(),
+            private in any::<PrivateIpv4Addr<String>>(),
+        ) {
+            prop_assert!(ipv4.parse::<std::net::Ipv4Addr>().is_ok());
+            prop_assert!(ipv6.parse::<std::net::Ipv6Addr>().is_ok());
+            prop_assert!(!public.parse::<std::net::Ipv4Addr>().unwrap().is_private());
+            prop_assert!(private.parse::<std::net::Ipv4Addr>().unwrap().is_private());
+        }
+    }
+}
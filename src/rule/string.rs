@@ -4,7 +4,9 @@ mod digit;
 mod email;
 mod ipv4;
 mod ipv6;
+mod japanese;
 mod regex;
+mod unicode;
 
 pub use alpha_digit::*;
 pub use alphabet::*;
@@ -12,4 +14,6 @@ pub use digit::*;
 pub use email::*;
 pub use ipv4::*;
 pub use ipv6::*;
+pub use japanese::*;
 pub use regex::*;
+pub use unicode::*;
@@ -1,6 +1,7 @@
-use crate::result::Error;
-use crate::rule::Rule;
+use crate::result::{Error, ErrorTree};
+use crate::rule::{Rule, RuleBorrow, RuleDescription, RuleRef};
 use crate::Refined;
+use serde_json::{json, Value};
 use std::str::FromStr;
 
 /// A type that holds a value satisfying the `Ipv4AddrRule`
@@ -16,16 +17,43 @@ impl<T: AsRef<str>> Rule for Ipv4AddrRule<T> {
     type Item = T;
 
     fn validate(target: Self::Item) -> crate::Result<Self::Item> {
-        let target_as_ref = target.as_ref();
+        match Self::validate_ref(&target) {
+            Ok(()) => Ok(target),
+            Err(tree) => Err(Error::from_tree(target, *tree)),
+        }
+    }
+}
+
+impl<T: AsRef<str>> RuleRef for Ipv4AddrRule<T> {
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        <Self as RuleBorrow<str>>::validate_borrow(target.as_ref())
+    }
+}
+
+impl<T: AsRef<str>> RuleBorrow<str> for Ipv4AddrRule<T> {
+    fn validate_borrow(target_as_ref: &str) -> Result<(), Box<ErrorTree>> {
         if std::net::Ipv4Addr::from_str(target_as_ref).is_ok() {
-            Ok(target)
+            Ok(())
         } else {
-            let message = format!("{} is not a valid IPv4 address", target_as_ref);
-            Err(Error::new(target, message))
+            let tree = ErrorTree::new(format!("{} is not a valid IPv4 address", target_as_ref))
+                .with_rule("Ipv4AddrRule")
+                .with_expected("a valid IPv4 address")
+                .with_actual(target_as_ref);
+            Err(Box::new(tree))
         }
     }
 }
 
+impl<T: AsRef<str>> RuleDescription for Ipv4AddrRule<T> {
+    fn description() -> String {
+        "a valid IPv4 address".to_string()
+    }
+
+    fn json_schema() -> Value {
+        json!({ "type": "string", "format": "ipv4" })
+    }
+}
+
 /// A type that holds a value satisfying the `PublicIpv4AddrRule`
 pub type PublicIpv4Addr<T> = Refined<PublicIpv4AddrRule<T>>;
 
@@ -37,17 +65,34 @@ impl<T: AsRef<str>> Rule for PublicIpv4AddrRule<T> {
     type Item = T;
 
     fn validate(target: Self::Item) -> crate::Result<Self::Item> {
-        let target_as_ref = target.as_ref();
+        match Self::validate_ref(&target) {
+            Ok(()) => Ok(target),
+            Err(tree) => Err(Error::from_tree(target, *tree)),
+        }
+    }
+}
+
+impl<T: AsRef<str>> RuleRef for PublicIpv4AddrRule<T> {
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        <Self as RuleBorrow<str>>::validate_borrow(target.as_ref())
+    }
+}
+
+impl<T: AsRef<str>> RuleBorrow<str> for PublicIpv4AddrRule<T> {
+    fn validate_borrow(target_as_ref: &str) -> Result<(), Box<ErrorTree>> {
         let ipv4_result = std::net::Ipv4Addr::from_str(target_as_ref);
         if let Ok(ipv4) = ipv4_result {
             if !ipv4.is_private() {
-                Ok(target)
+                Ok(())
             } else {
-                let message = format!("{} is a private IP address", target_as_ref);
-                Err(Error::new(target, message))
+                let tree = ErrorTree::new(format!("{} is a private IP address", target_as_ref))
+                    .with_rule("PublicIpv4AddrRule")
+                    .with_expected("a public IPv4 address")
+                    .with_actual(target_as_ref);
+                Err(Box::new(tree))
             }
         } else {
-            Ok(target)
+            Ok(())
         }
     }
 }
@@ -63,17 +108,37 @@ impl<T: AsRef<str>> Rule for PrivateIpv4AddrRule<T> {
     type Item = T;
 
     fn validate(target: Self::Item) -> crate::Result<Self::Item> {
-        let target_as_ref = target.as_ref();
+        match Self::validate_ref(&target) {
+            Ok(()) => Ok(target),
+            Err(tree) => Err(Error::from_tree(target, *tree)),
+        }
+    }
+}
+
+impl<T: AsRef<str>> RuleRef for PrivateIpv4AddrRule<T> {
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        <Self as RuleBorrow<str>>::validate_borrow(target.as_ref())
+    }
+}
+
+impl<T: AsRef<str>> RuleBorrow<str> for PrivateIpv4AddrRule<T> {
+    fn validate_borrow(target_as_ref: &str) -> Result<(), Box<ErrorTree>> {
         if let Ok(ipv4) = std::net::Ipv4Addr::from_str(target_as_ref) {
             if ipv4.is_private() {
-                Ok(target)
+                Ok(())
             } else {
-                let message = format!("{} is a public IP address", target_as_ref);
-                Err(Error::new(target, message))
+                let tree = ErrorTree::new(format!("{} is a public IP address", target_as_ref))
+                    .with_rule("PrivateIpv4AddrRule")
+                    .with_expected("a private IPv4 address")
+                    .with_actual(target_as_ref);
+                Err(Box::new(tree))
             }
         } else {
-            let message = format!("{} is not a valid IPv4 address", target_as_ref);
-            Err(Error::new(target, message))
+            let tree = ErrorTree::new(format!("{} is not a valid IPv4 address", target_as_ref))
+                .with_rule("PrivateIpv4AddrRule")
+                .with_expected("a valid IPv4 address")
+                .with_actual(target_as_ref);
+            Err(Box::new(tree))
         }
     }
 }
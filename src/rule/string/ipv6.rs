@@ -1,6 +1,7 @@
-use crate::result::Error;
-use crate::rule::Rule;
+use crate::result::{Error, ErrorTree};
+use crate::rule::{Rule, RuleBorrow, RuleDescription, RuleRef};
 use crate::Refined;
+use serde_json::{json, Value};
 use std::str::FromStr;
 
 /// A type that holds a value satisfying the `Ipv6AddrRule`
@@ -16,16 +17,43 @@ impl<T: AsRef<str>> Rule for Ipv6AddrRule<T> {
     type Item = T;
 
     fn validate(target: Self::Item) -> crate::Result<Self::Item> {
-        let target_as_ref = target.as_ref();
+        match Self::validate_ref(&target) {
+            Ok(()) => Ok(target),
+            Err(tree) => Err(Error::from_tree(target, *tree)),
+        }
+    }
+}
+
+impl<T: AsRef<str>> RuleRef for Ipv6AddrRule<T> {
+    fn validate_ref(target: &Self::Item) -> Result<(), Box<ErrorTree>> {
+        <Self as RuleBorrow<str>>::validate_borrow(target.as_ref())
+    }
+}
+
+impl<T: AsRef<str>> RuleBorrow<str> for Ipv6AddrRule<T> {
+    fn validate_borrow(target_as_ref: &str) -> Result<(), Box<ErrorTree>> {
         if std::net::Ipv6Addr::from_str(target_as_ref).is_ok() {
-            Ok(target)
+            Ok(())
         } else {
-            let message = format!("{} is not a valid IPv6 address", target_as_ref);
-            Err(Error::new(target, message))
+            let tree = ErrorTree::new(format!("{} is not a valid IPv6 address", target_as_ref))
+                .with_rule("Ipv6AddrRule")
+                .with_expected("a valid IPv6 address")
+                .with_actual(target_as_ref);
+            Err(Box::new(tree))
         }
     }
 }
 
+impl<T: AsRef<str>> RuleDescription for Ipv6AddrRule<T> {
+    fn description() -> String {
+        "a valid IPv6 address".to_string()
+    }
+
+    fn json_schema() -> Value {
+        json!({ "type": "string", "format": "ipv6" })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::rule::{Ipv6AddrRule, Rule};
@@ -0,0 +1,54 @@
+use crate::{declare_regex_rule, Refined};
+
+/// A type that holds a value satisfying the `HiraganaRule`
+///
+/// The prolonged sound mark `ー` is accepted as well, as it is written in hiragana words.
+/// # Example
+/// ```rust
+/// # use refined_type::rule::Hiragana;
+///
+/// assert!(Hiragana::new("やまだ").is_ok());
+/// assert!(Hiragana::new("ヤマダ").is_err());
+/// ```
+pub type Hiragana<STRING> = Refined<HiraganaRule<STRING>>;
+
+/// A type that holds a value satisfying the `KatakanaRule`
+///
+/// The prolonged sound mark `ー` and the halfwidth katakana, with their sound marks, are accepted as well.
+pub type Katakana<STRING> = Refined<KatakanaRule<STRING>>;
+
+/// A type that holds a value satisfying the `KanjiRule`
+///
+/// Any Han character is accepted, including the iteration mark `々`.
+pub type Kanji<STRING> = Refined<KanjiRule<STRING>>;
+
+declare_regex_rule![
+    (pub HiraganaRule, r"^[\p{Hiragana}ー]*$"),
+    (pub KatakanaRule, r"^[\p{Katakana}ーｰﾞﾟ]*$"),
+    (pub KanjiRule, r"^\p{Han}*$")
+];
+
+#[cfg(test)]
+mod test {
+    use crate::rule::{Hiragana, Kanji, Katakana};
+
+    #[test]
+    fn test_hiragana() {
+        assert!(Hiragana::new("らーめん".to_string()).is_ok());
+        assert!(Hiragana::new("ラーメン".to_string()).is_err());
+        assert!(Hiragana::new("やまだ。".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_katakana() {
+        assert!(Katakana::new("ラーメン".to_string()).is_ok());
+        assert!(Katakana::new("ﾗｰﾒﾝﾀﾞｲｽｷ".to_string()).is_ok());
+        assert!(Katakana::new("らーめん".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_kanji() {
+        assert!(Kanji::new("佐々木".to_string()).is_ok());
+        assert!(Kanji::new("佐々木さん".to_string()).is_err());
+    }
+}
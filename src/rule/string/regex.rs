@@ -2,6 +2,8 @@ pub use regex::Regex;
 
 #[macro_export]
 /// A macro to declare a rule that validates a `String` based on a regex pattern
+///
+/// The pattern is compiled once, on the first validation, and shared by every later call.
 /// ```rust
 /// use refined_type::rule::Rule;
 /// use refined_type::rule::Regex;
@@ -32,23 +34,94 @@ macro_rules! declare_regex_rule {
                 type Item = STRING;
 
                 fn validate(target: Self::Item) -> Result<Self::Item, $crate::result::Error<Self::Item>> {
-                    let target_as_ref = target.as_ref();
-                    let regex = $crate::rule::Regex::new($regex).expect("invalid regex pattern");
+                    match <Self as $crate::rule::RuleRef>::validate_ref(&target) {
+                        Ok(()) => Ok(target),
+                        Err(tree) => Err($crate::result::Error::from_tree(target, *tree)),
+                    }
+                }
+            }
+
+            impl<STRING: AsRef<str>> $crate::rule::RuleRef for $rule<STRING> {
+                fn validate_ref(target: &Self::Item) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    <Self as $crate::rule::RuleBorrow<str>>::validate_borrow(target.as_ref())
+                }
+            }
+
+            impl<STRING: AsRef<str>> $crate::rule::RuleBorrow<str> for $rule<STRING> {
+                fn validate_borrow(target_as_ref: &str) -> Result<(), Box<$crate::result::ErrorTree>> {
+                    static REGEX: std::sync::OnceLock<$crate::rule::Regex> = std::sync::OnceLock::new();
+                    let regex = REGEX.get_or_init(|| $crate::rule::Regex::new($regex).expect("invalid regex pattern"));
                     if regex.is_match(target_as_ref) {
-                        Ok(target)
+                        Ok(())
                     } else {
-                        let message = format!("\"{target_as_ref}\" does not match the regex pattern {regex}");
-                        Err($crate::result::Error::new(target, message))
+                        let tree = $crate::result::ErrorTree::new(format!("\"{target_as_ref}\" does not match the regex pattern {regex}"))
+                            .with_rule(stringify!($rule))
+                            .with_expected(format!("match the regex pattern {regex}"))
+                            .with_actual(target_as_ref);
+                        Err(Box::new(tree))
                     }
                 }
             }
+
+            impl<STRING: AsRef<str>> $crate::rule::RuleDescription for $rule<STRING> {
+                fn description() -> String {
+                    format!("match the regex pattern {}", $regex)
+                }
+
+                fn json_schema() -> $crate::__private::serde_json::Value {
+                    $crate::__private::serde_json::json!({ "type": "string", "pattern": $regex })
+                }
+            }
+
+            $crate::__impl_regex_rule_strategy!($rule, $regex);
         }
     };
     ($(($vis:vis $rule:ident, $regex:literal)),+) => {
-        $(declare_regex_rule!($rule, $regex);)+
+        $($crate::declare_regex_rule!($vis $rule, $regex);)+
     };
 }
 
+/// Implements `RuleStrategy` and `RuleViolation` for a rule declared by `declare_regex_rule!` when the `proptest` feature is enabled
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_regex_rule_strategy {
+    ($rule:ident, $regex:literal) => {
+        impl<STRING> $crate::rule::RuleStrategy for $rule<STRING>
+        where
+            STRING: AsRef<str> + From<String> + std::fmt::Debug + 'static,
+        {
+            fn strategy_within(
+                _bounds: &$crate::rule::Bounds<Self::Item>,
+            ) -> $crate::__private::proptest::strategy::BoxedStrategy<Self::Item> {
+                $crate::rule::regex_strategy::<Self>($regex)
+            }
+        }
+
+        impl<STRING> $crate::testing::RuleViolation for $rule<STRING>
+        where
+            STRING: AsRef<str> + From<String> + std::fmt::Debug + 'static,
+        {
+            fn boundary_violation(
+            ) -> $crate::__private::proptest::strategy::BoxedStrategy<Self::Item> {
+                $crate::testing::regex_boundary_violation::<Self>($regex)
+            }
+
+            fn arbitrary_violation(
+            ) -> $crate::__private::proptest::strategy::BoxedStrategy<Self::Item> {
+                $crate::testing::string_violation::<Self>()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_regex_rule_strategy {
+    ($rule:ident, $regex:literal) => {};
+}
+
 #[cfg(test)]
 mod tests {
     use crate::rule::Rule;
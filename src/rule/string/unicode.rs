@@ -0,0 +1,55 @@
+use crate::{declare_regex_rule, Refined};
+
+/// A type that holds a value satisfying the `UnicodeAlphabetRule`
+///
+/// Unlike `Alphabet`, it accepts the letters of every script, along with their combining marks.
+/// # Example
+/// ```rust
+/// # use refined_type::rule::UnicodeAlphabet;
+///
+/// assert!(UnicodeAlphabet::new("Zoë").is_ok());
+/// assert!(UnicodeAlphabet::new("やまだ").is_ok());
+/// assert!(UnicodeAlphabet::new("R2D2").is_err());
+/// ```
+pub type UnicodeAlphabet<STRING> = Refined<UnicodeAlphabetRule<STRING>>;
+
+/// A type that holds a value satisfying the `UnicodeDigitRule`
+///
+/// It accepts the decimal digits of every script, e.g. `"١٢٣"` and `"１２３"`.
+pub type UnicodeDigit<STRING> = Refined<UnicodeDigitRule<STRING>>;
+
+/// A type that holds a value satisfying the `UnicodeAlphaDigitRule`
+pub type UnicodeAlphaDigit<STRING> = Refined<UnicodeAlphaDigitRule<STRING>>;
+
+declare_regex_rule![
+    (pub UnicodeAlphabetRule, r"^[\p{L}\p{M}]*$"),
+    (pub UnicodeDigitRule, r"^\p{Nd}*$"),
+    (pub UnicodeAlphaDigitRule, r"^[\p{L}\p{M}\p{Nd}]*$")
+];
+
+#[cfg(test)]
+mod test {
+    use crate::rule::{UnicodeAlphaDigit, UnicodeAlphabet, UnicodeDigit};
+
+    #[test]
+    fn test_unicode_alphabet() {
+        assert!(UnicodeAlphabet::new("山田太郎".to_string()).is_ok());
+        assert!(UnicodeAlphabet::new("Jose\u{301}".to_string()).is_ok());
+        assert!(UnicodeAlphabet::new("".to_string()).is_ok());
+        assert!(UnicodeAlphabet::new("山田 太郎".to_string()).is_err());
+        assert!(UnicodeAlphabet::new("１".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_unicode_digit() {
+        assert!(UnicodeDigit::new("０１２3٤".to_string()).is_ok());
+        assert!(UnicodeDigit::new("一二三".to_string()).is_err());
+        assert!(UnicodeDigit::new("½".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_unicode_alpha_digit() {
+        assert!(UnicodeAlphaDigit::new("東京２０２０".to_string()).is_ok());
+        assert!(UnicodeAlphaDigit::new("東京-2020".to_string()).is_err());
+    }
+}
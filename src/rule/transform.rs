@@ -0,0 +1,92 @@
+mod collapse_whitespace;
+mod email_domain;
+mod lowercase;
+#[cfg(feature = "unicode")]
+mod nfc;
+mod trim;
+
+use crate::result::rule_name;
+pub use collapse_whitespace::CollapseWhitespaceTransform;
+pub use email_domain::LowercaseEmailDomainTransform;
+pub use lowercase::LowercaseTransform;
+#[cfg(feature = "unicode")]
+pub use nfc::NfcTransform;
+pub use trim::TrimTransform;
+
+/// This is a `trait` that canonicalizes a value before a `Rule` checks it, see `Normalized`
+///
+/// A pair `(TRANSFORM1, TRANSFORM2)` applies `TRANSFORM1` and then `TRANSFORM2`.
+/// With the `unicode` feature, `NfcTransform` composes a string into Unicode Normalization Form C,
+/// and other normalizations can be plugged in by implementing this trait.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{LowercaseTransform, Transform, TrimTransform};
+///
+/// type TrimLowercase = (TrimTransform, LowercaseTransform);
+///
+/// assert_eq!(TrimLowercase::transform("  Bob ".to_string()), "bob");
+/// assert_eq!(TrimLowercase::description(), "trimming whitespace and lowercasing");
+/// ```
+pub trait Transform {
+    type Item;
+
+    fn transform(target: Self::Item) -> Self::Item;
+
+    /// Describes the transformation, e.g. `trimming whitespace`, for the `RuleDescription` of `Normalized`
+    fn description() -> String {
+        format!("applying {}", rule_name::<Self>())
+    }
+}
+
+impl<T, TRANSFORM1, TRANSFORM2> Transform for (TRANSFORM1, TRANSFORM2)
+where
+    TRANSFORM1: Transform<Item = T>,
+    TRANSFORM2: Transform<Item = T>,
+{
+    type Item = T;
+
+    fn transform(target: Self::Item) -> Self::Item {
+        TRANSFORM2::transform(TRANSFORM1::transform(target))
+    }
+
+    fn description() -> String {
+        format!(
+            "{} and {}",
+            TRANSFORM1::description(),
+            TRANSFORM2::description()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{CollapseWhitespaceTransform, LowercaseTransform, Transform};
+
+    struct Reverse;
+
+    impl Transform for Reverse {
+        type Item = String;
+
+        fn transform(target: String) -> String {
+            target.chars().rev().collect()
+        }
+    }
+
+    #[test]
+    fn test_transform_pair() {
+        type Canonical = (CollapseWhitespaceTransform, LowercaseTransform);
+        assert_eq!(
+            Canonical::transform(" Hello \t World ".to_string()),
+            "hello world"
+        );
+    }
+
+    #[test]
+    fn test_default_description() {
+        assert_eq!(Reverse::description(), "applying Reverse");
+        assert_eq!(
+            <(Reverse, LowercaseTransform)>::description(),
+            "applying Reverse and lowercasing"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::rule::Transform;
+
+/// Transform that trims a `String` and replaces every run of whitespace inside it with a single space
+/// # Example
+/// ```rust
+/// use refined_type::rule::{CollapseWhitespaceTransform, Transform};
+///
+/// assert_eq!(
+///     CollapseWhitespaceTransform::transform("  Bob \t\n Smith ".to_string()),
+///     "Bob Smith"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CollapseWhitespaceTransform;
+
+impl Transform for CollapseWhitespaceTransform {
+    type Item = String;
+
+    fn transform(target: Self::Item) -> Self::Item {
+        target.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn description() -> String {
+        "collapsing whitespace".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{CollapseWhitespaceTransform, Transform};
+
+    #[test]
+    fn test_collapse_whitespace_transform() {
+        assert_eq!(
+            CollapseWhitespaceTransform::transform("a  b\u{3000}c".to_string()),
+            "a b c"
+        );
+        assert_eq!(
+            CollapseWhitespaceTransform::transform(" \n ".to_string()),
+            ""
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use crate::rule::Transform;
+
+/// Transform that converts the domain of an email address, after its last `@`, to lowercase
+///
+/// The local part before the `@` is kept as it is, since it may be case-sensitive.
+/// A string without `@` is not changed.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{LowercaseEmailDomainTransform, Transform};
+///
+/// assert_eq!(
+///     LowercaseEmailDomainTransform::transform("Bob@Example.COM".to_string()),
+///     "Bob@example.com"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LowercaseEmailDomainTransform;
+
+impl Transform for LowercaseEmailDomainTransform {
+    type Item = String;
+
+    fn transform(target: Self::Item) -> Self::Item {
+        match target.rsplit_once('@') {
+            Some((local, domain)) => format!("{local}@{}", domain.to_lowercase()),
+            None => target,
+        }
+    }
+
+    fn description() -> String {
+        "lowercasing the email domain".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{LowercaseEmailDomainTransform, Transform};
+
+    #[test]
+    fn test_lowercase_email_domain_transform() {
+        assert_eq!(
+            LowercaseEmailDomainTransform::transform("\"A@B\"@Mail.Example.ORG".to_string()),
+            "\"A@B\"@mail.example.org"
+        );
+        assert_eq!(
+            LowercaseEmailDomainTransform::transform("NoDomain".to_string()),
+            "NoDomain"
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use crate::rule::Transform;
+
+/// Transform that converts a `String` to lowercase
+/// # Example
+/// ```rust
+/// use refined_type::rule::{LowercaseTransform, Transform};
+///
+/// assert_eq!(LowercaseTransform::transform("Bob".to_string()), "bob");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LowercaseTransform;
+
+impl Transform for LowercaseTransform {
+    type Item = String;
+
+    fn transform(target: Self::Item) -> Self::Item {
+        target.to_lowercase()
+    }
+
+    fn description() -> String {
+        "lowercasing".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{LowercaseTransform, Transform};
+
+    #[test]
+    fn test_lowercase_transform() {
+        assert_eq!(
+            LowercaseTransform::transform("HeLLo ÄÖ".to_string()),
+            "hello äö"
+        );
+    }
+}
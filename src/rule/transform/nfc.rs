@@ -0,0 +1,49 @@
+use crate::rule::Transform;
+use unicode_normalization::UnicodeNormalization;
+
+/// Transform that converts a `String` into Unicode Normalization Form C
+///
+/// A character and its combining marks are composed into a single character where possible,
+/// so that `"e\u{301}"` and `"é"` are stored alike.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{NfcTransform, Transform};
+///
+/// assert_eq!(NfcTransform::transform("Jose\u{301}".to_string()), "José");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NfcTransform;
+
+impl Transform for NfcTransform {
+    type Item = String;
+
+    fn transform(target: Self::Item) -> Self::Item {
+        if unicode_normalization::is_nfc(&target) {
+            target
+        } else {
+            target.nfc().collect()
+        }
+    }
+
+    fn description() -> String {
+        "normalizing to NFC".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{NfcTransform, Transform};
+
+    #[test]
+    fn test_nfc_transform() {
+        assert_eq!(NfcTransform::transform("e\u{301}".to_string()), "\u{e9}");
+        assert_eq!(
+            NfcTransform::transform("Jos\u{e9}".to_string()),
+            "Jos\u{e9}"
+        );
+        assert_eq!(
+            NfcTransform::transform("\u{304b}\u{3099}".to_string()),
+            "\u{304c}"
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use crate::rule::Transform;
+
+/// Transform that removes the leading and trailing whitespace of a `String`
+/// # Example
+/// ```rust
+/// use refined_type::rule::{Transform, TrimTransform};
+///
+/// assert_eq!(TrimTransform::transform("  Bob \n".to_string()), "Bob");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TrimTransform;
+
+impl Transform for TrimTransform {
+    type Item = String;
+
+    fn transform(target: Self::Item) -> Self::Item {
+        match target.trim() {
+            trimmed if trimmed.len() == target.len() => target,
+            trimmed => trimmed.to_string(),
+        }
+    }
+
+    fn description() -> String {
+        "trimming whitespace".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{Transform, TrimTransform};
+
+    #[test]
+    fn test_trim_transform() {
+        assert_eq!(
+            TrimTransform::transform("\t Bob Smith  ".to_string()),
+            "Bob Smith"
+        );
+        assert_eq!(TrimTransform::transform("Bob".to_string()), "Bob");
+        assert_eq!(TrimTransform::transform("   ".to_string()), "");
+    }
+}
@@ -0,0 +1,16 @@
+//! Lazy validation of the items of an `Iterator` or a `Stream`, without collecting them
+//!
+//! `RefinedIter` validates each item by a rule as it is yielded, and keeps a `Tally` of the items that satisfy it,
+//! so that a rule on the whole collection such as `ExistsVecRule` or `CountLessEqualVecRule` can be checked
+//! at the end by `TallyRule`.
+//! With the `stream` feature, `RefinedStream` does the same for a `futures_core::Stream`.
+
+mod refined_iter;
+#[cfg(feature = "stream")]
+mod refined_stream;
+mod tally;
+
+pub use refined_iter::{RefineIterator, RefinedIter};
+#[cfg(feature = "stream")]
+pub use refined_stream::{RefineStream, RefinedStream};
+pub use tally::{Tally, TallyRule};
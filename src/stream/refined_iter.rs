@@ -0,0 +1,110 @@
+use crate::result::Error;
+use crate::rule::Rule;
+use crate::stream::{Tally, TallyRule};
+use crate::Refined;
+use std::marker::PhantomData;
+
+/// An `Iterator` that validates each item of `I` by `RULE` as it is yielded
+///
+/// An item that does not satisfy `RULE` is yielded as an `Error` located by its index,
+/// and the iteration goes on, so that every invalid line of a large import can be reported.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{ExistsVecRule, NonEmptyStringRule};
+/// use refined_type::stream::RefineIterator;
+///
+/// let lines = vec!["alice".to_string(), "".to_string(), "bob".to_string()];
+/// let mut names = lines.into_iter().refine::<NonEmptyStringRule>();
+///
+/// let valid = names.by_ref().filter_map(Result::ok).collect::<Vec<_>>();
+/// assert_eq!(valid.len(), 2);
+/// assert_eq!(names.tally().invalid(), 1);
+/// assert!(names.check::<ExistsVecRule<NonEmptyStringRule>>().is_ok());
+/// ```
+pub struct RefinedIter<RULE, I> {
+    iter: I,
+    tally: Tally,
+    _rule: PhantomData<fn() -> RULE>,
+}
+
+impl<RULE, I> RefinedIter<RULE, I> {
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            tally: Tally::default(),
+            _rule: PhantomData,
+        }
+    }
+
+    /// The tally of the items yielded so far
+    pub fn tally(&self) -> Tally {
+        self.tally
+    }
+
+    /// Checks `COLLECTION` against the items yielded so far, usually once the iterator is exhausted
+    pub fn check<COLLECTION: TallyRule<RULE>>(&self) -> crate::Result<Tally> {
+        COLLECTION::check_tally(self.tally)
+    }
+
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<RULE, I> Iterator for RefinedIter<RULE, I>
+where
+    RULE: Rule,
+    I: Iterator<Item = RULE::Item>,
+{
+    type Item = Result<Refined<RULE>, Error<RULE::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.iter.next()?;
+        Some(self.tally.refine::<RULE>(value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// This is a `trait` that adds `refine` to every `Iterator`
+pub trait RefineIterator: Iterator + Sized {
+    /// Validates each item by `RULE` lazily, see `RefinedIter`
+    fn refine<RULE: Rule<Item = Self::Item>>(self) -> RefinedIter<RULE, Self> {
+        RefinedIter::new(self)
+    }
+}
+
+impl<I: Iterator> RefineIterator for I {}
+
+#[cfg(test)]
+mod tests {
+    use crate::result::Location;
+    use crate::rule::{CountLessEqualVecRule, EvenRuleI32, ForAllVecRule, NonEmptyStringRule};
+    use crate::stream::RefineIterator;
+
+    #[test]
+    fn test_refined_iter() {
+        let mut numbers = [2, 3, 4, 5].into_iter().refine::<EvenRuleI32>();
+        assert_eq!(numbers.next().unwrap().unwrap().into_value(), 2);
+
+        let err = numbers.next().unwrap().unwrap_err();
+        assert_eq!(err.tree().location(), Some(&Location::Index(1)));
+        assert_eq!(err.into_value(), 3);
+
+        assert_eq!(numbers.by_ref().count(), 2);
+        assert_eq!(numbers.tally().valid(), 2);
+        assert!(numbers
+            .check::<CountLessEqualVecRule<2, EvenRuleI32>>()
+            .is_ok());
+        assert!(numbers.check::<ForAllVecRule<EvenRuleI32>>().is_err());
+    }
+
+    #[test]
+    fn test_refined_iter_is_lazy() {
+        let mut names = std::iter::repeat_with(String::new).refine::<NonEmptyStringRule>();
+        assert!(names.next().unwrap().is_err());
+        assert_eq!(names.tally().total(), 1);
+    }
+}
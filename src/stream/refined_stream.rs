@@ -0,0 +1,132 @@
+use crate::result::Error;
+use crate::rule::Rule;
+use crate::stream::{Tally, TallyRule};
+use crate::Refined;
+use futures_core::Stream;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pin_project_lite::pin_project! {
+    /// A `Stream` that validates each item of `S` by `RULE` as it is yielded, like `RefinedIter`
+    /// # Example
+    /// ```rust
+    /// use futures_core::Stream;
+    /// use refined_type::rule::{EvenRuleI32, ForAllVecRule};
+    /// use refined_type::stream::{RefineStream, RefinedStream};
+    ///
+    /// fn even_numbers<S: Stream<Item = i32>>(numbers: S) -> RefinedStream<EvenRuleI32, S> {
+    ///     numbers.refine::<EvenRuleI32>()
+    /// }
+    /// ```
+    pub struct RefinedStream<RULE, S> {
+        #[pin]
+        stream: S,
+        tally: Tally,
+        _rule: PhantomData<fn() -> RULE>,
+    }
+}
+
+impl<RULE, S> RefinedStream<RULE, S> {
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            tally: Tally::default(),
+            _rule: PhantomData,
+        }
+    }
+
+    /// The tally of the items yielded so far
+    pub fn tally(&self) -> Tally {
+        self.tally
+    }
+
+    /// Checks `COLLECTION` against the items yielded so far, usually once the stream is exhausted
+    pub fn check<COLLECTION: TallyRule<RULE>>(&self) -> crate::Result<Tally> {
+        COLLECTION::check_tally(self.tally)
+    }
+}
+
+impl<RULE, S> Stream for RefinedStream<RULE, S>
+where
+    RULE: Rule,
+    S: Stream<Item = RULE::Item>,
+{
+    type Item = Result<Refined<RULE>, Error<RULE::Item>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream
+            .poll_next(cx)
+            .map(|value| Some(this.tally.refine::<RULE>(value?)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+/// This is a `trait` that adds `refine` to every `Stream`
+pub trait RefineStream: Stream + Sized {
+    /// Validates each item by `RULE` lazily, see `RefinedStream`
+    fn refine<RULE: Rule<Item = Self::Item>>(self) -> RefinedStream<RULE, Self> {
+        RefinedStream::new(self)
+    }
+}
+
+impl<S: Stream> RefineStream for S {}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{EvenRuleI32, ExistsVecRule, NothingVecRule};
+    use crate::stream::RefineStream;
+    use futures_core::Stream;
+    use std::pin::Pin;
+    use std::task::{Context, Poll, Waker};
+
+    /// A stream that yields the items of a `Vec`, each after one pending poll
+    struct Delayed {
+        items: Vec<i32>,
+        ready: bool,
+    }
+
+    impl Stream for Delayed {
+        type Item = i32;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<i32>> {
+            self.ready = !self.ready;
+            if !self.ready {
+                Poll::Pending
+            } else if self.items.is_empty() {
+                Poll::Ready(None)
+            } else {
+                Poll::Ready(Some(self.items.remove(0)))
+            }
+        }
+    }
+
+    #[test]
+    fn test_refined_stream() {
+        let stream = Delayed {
+            items: vec![1, 2, 3],
+            ready: true,
+        };
+        let mut stream = Box::pin(stream.refine::<EvenRuleI32>());
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut results = Vec::new();
+        loop {
+            match stream.as_mut().poll_next(&mut cx) {
+                Poll::Ready(Some(result)) => results.push(result.map(|even| even.into_value())),
+                Poll::Ready(None) => break,
+                Poll::Pending => assert_eq!(stream.tally().total(), results.len()),
+            }
+        }
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[1].as_ref().ok(), Some(&2));
+        assert_eq!(stream.tally().invalid(), 2);
+        assert!(stream.check::<ExistsVecRule<EvenRuleI32>>().is_ok());
+        assert!(stream.check::<NothingVecRule<EvenRuleI32>>().is_err());
+    }
+}
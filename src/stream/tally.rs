@@ -0,0 +1,241 @@
+use crate::result::{rule_name, rule_type_name, Error, ErrorTree, Location};
+use crate::rule::composer::{And, AndAll, Not, Or};
+use crate::rule::{
+    CountEqualRule, CountGreaterRule, CountLessRule, Iterable, NoSkip, Rule, SkipRule,
+};
+use crate::Refined;
+
+/// The number of items validated so far, and of those that satisfied the rule
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Tally {
+    total: usize,
+    valid: usize,
+}
+
+impl Tally {
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    pub fn valid(&self) -> usize {
+        self.valid
+    }
+
+    pub fn invalid(&self) -> usize {
+        self.total - self.valid
+    }
+
+    /// Validates the next item by `RULE` and counts it, locating the error by the index of the item
+    pub(crate) fn refine<RULE: Rule>(
+        &mut self,
+        value: RULE::Item,
+    ) -> Result<Refined<RULE>, Error<RULE::Item>> {
+        let index = self.total;
+        self.total += 1;
+        match Refined::new(value) {
+            Ok(refined) => {
+                self.valid += 1;
+                Ok(refined)
+            }
+            Err(err) => {
+                let (value, tree) = err.into_parts();
+                Err(Error::from_tree(
+                    value,
+                    tree.with_location(Location::Index(index)),
+                ))
+            }
+        }
+    }
+}
+
+/// This is a `trait` for the rules on a collection that can be checked from the `Tally` of its items by `RULE`
+///
+/// It is implemented by `ForAll`, `Nothing` and the count rules, and by their compositions,
+/// e.g. `Exists` and `CountLessEqual`, so they can be checked at the end of a stream without buffering it.
+/// `Nothing<RULE>` is also `ForAll<Not<RULE>>`, so `RULE` is usually given by `RefinedIter::check`.
+/// # Example
+/// ```rust
+/// use refined_type::rule::{CountLessEqualVecRule, EvenRuleI32, ExistsVecRule};
+/// use refined_type::stream::RefineIterator;
+///
+/// let mut numbers = (1..=5).refine::<EvenRuleI32>();
+/// assert_eq!(numbers.by_ref().filter(Result::is_ok).count(), 2);
+///
+/// assert!(numbers.check::<ExistsVecRule<EvenRuleI32>>().is_ok());
+/// assert!(numbers.check::<CountLessEqualVecRule<1, EvenRuleI32>>().is_err());
+/// ```
+pub trait TallyRule<RULE> {
+    /// Returns `true` if a collection whose items are counted by `tally` satisfies the rule
+    fn accepts(tally: &Tally) -> bool;
+
+    /// Checks the rule against `tally`, returning it back on success
+    fn check_tally(tally: Tally) -> crate::Result<Tally> {
+        if Self::accepts(&tally) {
+            Ok(tally)
+        } else {
+            let tree = ErrorTree::new(format!(
+                "the stream does not satisfy {}",
+                rule_name::<Self>()
+            ))
+            .with_rule(rule_type_name::<Self>())
+            .with_actual(format!(
+                "{} of {} items satisfy {}",
+                tally.valid,
+                tally.total,
+                rule_name::<RULE>()
+            ));
+            Err(Error::from_tree(tally, tree))
+        }
+    }
+}
+
+impl<RULE, ITERABLE> TallyRule<RULE> for SkipRule<RULE, ITERABLE, NoSkip<RULE::Item>>
+where
+    RULE: Rule,
+{
+    fn accepts(tally: &Tally) -> bool {
+        tally.valid == tally.total
+    }
+}
+
+/// `Nothing<RULE>` is `ForAll<Not<RULE>>`, which is checked from the tally by `RULE`
+impl<RULE, ITERABLE> TallyRule<RULE> for SkipRule<Not<RULE>, ITERABLE, NoSkip<RULE::Item>>
+where
+    RULE: Rule,
+    Not<RULE>: Rule<Item = RULE::Item>,
+{
+    fn accepts(tally: &Tally) -> bool {
+        tally.valid == 0
+    }
+}
+
+impl<const N: usize, RULE, ITERABLE> TallyRule<RULE> for CountEqualRule<N, RULE, ITERABLE>
+where
+    RULE: Rule,
+    ITERABLE: Iterable<Item = RULE::Item>,
+{
+    fn accepts(tally: &Tally) -> bool {
+        tally.valid == N
+    }
+}
+
+impl<const N: usize, RULE, ITERABLE> TallyRule<RULE> for CountGreaterRule<N, RULE, ITERABLE>
+where
+    RULE: Rule,
+    ITERABLE: Iterable<Item = RULE::Item>,
+{
+    fn accepts(tally: &Tally) -> bool {
+        tally.valid > N
+    }
+}
+
+impl<const N: usize, RULE, ITERABLE> TallyRule<RULE> for CountLessRule<N, RULE, ITERABLE>
+where
+    RULE: Rule,
+    ITERABLE: Iterable<Item = RULE::Item>,
+{
+    fn accepts(tally: &Tally) -> bool {
+        tally.valid < N
+    }
+}
+
+impl<RULE, RULE1, RULE2> TallyRule<RULE> for And<RULE1, RULE2>
+where
+    RULE1: TallyRule<RULE>,
+    RULE2: TallyRule<RULE>,
+{
+    fn accepts(tally: &Tally) -> bool {
+        RULE1::accepts(tally) && RULE2::accepts(tally)
+    }
+}
+
+impl<RULE, RULE1, RULE2> TallyRule<RULE> for AndAll<RULE1, RULE2>
+where
+    RULE1: TallyRule<RULE>,
+    RULE2: TallyRule<RULE>,
+{
+    fn accepts(tally: &Tally) -> bool {
+        RULE1::accepts(tally) && RULE2::accepts(tally)
+    }
+}
+
+impl<RULE, RULE1, RULE2> TallyRule<RULE> for Or<RULE1, RULE2>
+where
+    RULE1: TallyRule<RULE>,
+    RULE2: TallyRule<RULE>,
+{
+    fn accepts(tally: &Tally) -> bool {
+        RULE1::accepts(tally) || RULE2::accepts(tally)
+    }
+}
+
+impl<RULE, RULE1> TallyRule<RULE> for Not<RULE1>
+where
+    RULE1: TallyRule<RULE>,
+{
+    fn accepts(tally: &Tally) -> bool {
+        !RULE1::accepts(tally)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::result::Location;
+    use crate::rule::{
+        CountEqualVecRule, CountGreaterEqualVecRule, CountLessVecRule, EvenRuleI32, ExistsVecRule,
+        ForAllVecRule, NothingVecRule,
+    };
+    use crate::stream::{Tally, TallyRule};
+
+    fn tally(values: &[i32]) -> Tally {
+        let mut tally = Tally::default();
+        for value in values {
+            let _ = tally.refine::<EvenRuleI32>(*value);
+        }
+        tally
+    }
+
+    fn accepts<COLLECTION: TallyRule<EvenRuleI32>>(tally: &Tally) -> bool {
+        COLLECTION::accepts(tally)
+    }
+
+    #[test]
+    fn test_tally_refine() {
+        let mut tally = Tally::default();
+        assert!(tally.refine::<EvenRuleI32>(2).is_ok());
+        let err = tally.refine::<EvenRuleI32>(3).unwrap_err();
+        assert_eq!(err.tree().location(), Some(&Location::Index(1)));
+        assert_eq!((tally.total(), tally.valid(), tally.invalid()), (2, 1, 1));
+    }
+
+    #[test]
+    fn test_tally_rule() {
+        let all_even = tally(&[2, 4]);
+        let some_even = tally(&[1, 2, 4]);
+        let no_even = tally(&[1, 3]);
+
+        assert!(accepts::<ForAllVecRule<EvenRuleI32>>(&all_even));
+        assert!(!accepts::<ForAllVecRule<EvenRuleI32>>(&some_even));
+        assert!(accepts::<ExistsVecRule<EvenRuleI32>>(&some_even));
+        assert!(!accepts::<ExistsVecRule<EvenRuleI32>>(&no_even));
+        assert!(accepts::<NothingVecRule<EvenRuleI32>>(&no_even));
+        assert!(accepts::<CountEqualVecRule<2, EvenRuleI32>>(&some_even));
+        assert!(accepts::<CountGreaterEqualVecRule<2, EvenRuleI32>>(
+            &all_even
+        ));
+        assert!(!accepts::<CountLessVecRule<2, EvenRuleI32>>(&all_even));
+    }
+
+    #[test]
+    fn test_check_tally() {
+        let no_even = tally(&[1, 3]);
+        let err = <ExistsVecRule<EvenRuleI32> as TallyRule<EvenRuleI32>>::check_tally(no_even)
+            .unwrap_err();
+        assert_eq!(
+            err.tree().actual(),
+            Some("0 of 2 items satisfy EvenRuleI32")
+        );
+        assert_eq!(err.tree().rule(), Some("Not"));
+        assert_eq!(err.into_value(), no_even);
+    }
+}
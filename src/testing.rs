@@ -0,0 +1,185 @@
+//! Utilities for testing that the values violating a rule are rejected
+//!
+//! Every built-in rule implements `RuleViolation`, which generates the values that do not satisfy it,
+//! starting from the boundary ones such as `THAN` for `GreaterRule<THAN>` or a string one char too long for `LengthLessRule`.
+//! They are available with the `proptest` feature.
+
+mod collection;
+mod composer;
+mod length;
+mod number;
+mod string;
+
+use crate::rule::{ArbitraryWithin, Invalid, Rule, RuleStrategy, Valid};
+use proptest::strategy::{BoxedStrategy, Strategy, Union};
+use proptest::test_runner::TestRunner;
+use std::fmt::Debug;
+#[doc(hidden)]
+pub use string::{regex_boundary_violation, string_violation};
+
+/// This is a `trait` that generates values violating a `Rule`, to check that the invalid input is rejected
+///
+/// The violations of a composer are built from those of its sub-rules,
+/// e.g. a value violates `And` if it violates either rule, and `Or` if it violates both,
+/// so `MinMaxI32<0, 10>` has the boundary violations `-1` and `11`.
+/// # Example
+/// ```rust
+/// use proptest::prelude::*;
+/// use refined_type::rule::{LengthLessStringRule, RangeRuleU8};
+/// use refined_type::testing::RuleViolation;
+/// use refined_type::Refined;
+///
+/// proptest!(|(age in RangeRuleU8::<18, 80>::violation(), name in LengthLessStringRule::<8>::boundary_violation())| {
+///     prop_assert!(age < 18 || age >= 80);
+///     prop_assert_eq!(name.chars().count(), 8);
+///     prop_assert!(Refined::<LengthLessStringRule<8>>::new(name).is_err());
+/// });
+/// ```
+pub trait RuleViolation: Rule<Item: Debug + 'static> + 'static {
+    /// Generates the values just outside the rule, such as the bound of a number rule or a collection one item too short
+    fn boundary_violation() -> BoxedStrategy<Self::Item>;
+
+    /// Generates any values that violate the rule
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item>;
+
+    /// Generates values that violate the rule, half of which are boundary violations
+    fn violation() -> BoxedStrategy<Self::Item> {
+        Union::new([Self::boundary_violation(), Self::arbitrary_violation()]).boxed()
+    }
+}
+
+/// Generates the values of `strategy` that violate `RULE`, discarding the others
+///
+/// This is the counterpart of `filtered_strategy`,
+/// so `strategy` should mostly generate invalid values to avoid too many rejections.
+/// # Example
+/// ```rust
+/// use proptest::prelude::*;
+/// use refined_type::define_rule;
+/// use refined_type::testing::violating_strategy;
+///
+/// define_rule![pub Port, u16, |port| *port != 0, "the port must not be zero"];
+///
+/// proptest!(|(port in violating_strategy::<PortRule>(0..10u16))| {
+///     prop_assert_eq!(port, 0);
+/// });
+/// ```
+pub fn violating_strategy<RULE>(
+    strategy: impl Strategy<Value = RULE::Item> + 'static,
+) -> BoxedStrategy<RULE::Item>
+where
+    RULE: Rule<Item: Debug + 'static> + 'static,
+{
+    strategy
+        .prop_filter_map("the value satisfies the rule", |value| {
+            RULE::validate(value).err().map(|error| error.into_value())
+        })
+        .boxed()
+}
+
+/// Panics unless `RULE` rejects every value generated by `RuleViolation::violation`
+/// # Example
+/// ```rust
+/// use refined_type::rule::{EmailStringRule, ForAllVecRule, MinMaxRuleI32};
+/// use refined_type::testing::assert_rejects;
+///
+/// assert_rejects::<MinMaxRuleI32<-10, 10>>();
+/// assert_rejects::<EmailStringRule>();
+/// assert_rejects::<ForAllVecRule<MinMaxRuleI32<0, 5>>>();
+/// ```
+pub fn assert_rejects<RULE: RuleViolation>() {
+    let result = TestRunner::default().run(&RULE::violation(), |value| {
+        let message = format!("{value:?} satisfies {}", std::any::type_name::<RULE>());
+        proptest::prop_assert!(RULE::validate(value).is_err(), "{}", message);
+        Ok(())
+    });
+    if let Err(error) = result {
+        panic!("{error}");
+    }
+}
+
+/// Panics unless `RULE` accepts every value generated by `RuleStrategy::strategy`
+pub fn assert_accepts<RULE: RuleStrategy>() {
+    let result = TestRunner::default().run(&RULE::strategy(), |value| {
+        let message = format!("{value:?} violates {}", std::any::type_name::<RULE>());
+        proptest::prop_assert!(RULE::validate(value).is_ok(), "{}", message);
+        Ok(())
+    });
+    if let Err(error) = result {
+        panic!("{error}");
+    }
+}
+
+/// No value violates `Valid`, so its strategies reject every value and fail the test
+impl<T: ArbitraryWithin> RuleViolation for Valid<T> {
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(T::arbitrary_unbounded())
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(T::arbitrary_unbounded())
+    }
+}
+
+impl<T: ArbitraryWithin> RuleViolation for Invalid<T> {
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        T::arbitrary_unbounded()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        T::arbitrary_unbounded()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{Invalid, Rule, Valid};
+    use crate::testing::{assert_accepts, assert_rejects, violating_strategy, RuleViolation};
+    use proptest::strategy::{BoxedStrategy, Strategy, ValueTree};
+    use proptest::test_runner::TestRunner;
+
+    #[test]
+    fn test_invalid_violation() {
+        assert_rejects::<Invalid<i32>>();
+        assert_accepts::<Valid<i32>>();
+    }
+
+    #[test]
+    fn test_valid_has_no_violation() {
+        let mut runner = TestRunner::default();
+        assert!(Valid::<u8>::violation().new_tree(&mut runner).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "satisfies")]
+    fn test_assert_rejects_panics_on_valid_value() {
+        struct Lenient;
+
+        impl Rule for Lenient {
+            type Item = u8;
+
+            fn validate(target: u8) -> crate::Result<u8> {
+                Ok(target)
+            }
+        }
+
+        impl RuleViolation for Lenient {
+            fn boundary_violation() -> BoxedStrategy<u8> {
+                (0..10u8).boxed()
+            }
+
+            fn arbitrary_violation() -> BoxedStrategy<u8> {
+                (0..10u8).boxed()
+            }
+        }
+
+        assert_rejects::<Lenient>();
+    }
+
+    #[test]
+    fn test_violating_strategy() {
+        let mut runner = TestRunner::default();
+        let strategy = violating_strategy::<Invalid<u8>>(0..10u8);
+        assert!(strategy.new_tree(&mut runner).unwrap().current() < 10);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::rule::strategy::collection::{counted_strategy, EXTRA_COUNT};
+use crate::rule::{
+    ArbitraryWithin, CountEqualRule, CountGreaterRule, CountLessRule, IndexRuleString,
+    IndexRuleVec, IndexRuleVecDeque, Iterable, IterableItems, ReverseRule, RuleStrategy,
+    SkipOption, SkipRule,
+};
+use crate::testing::{violating_strategy, RuleViolation};
+use proptest::collection;
+use proptest::strategy::{BoxedStrategy, Strategy, Union};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// Generates items satisfying `RULE` mixed with `invalid` items generated by `item` in random order
+fn mixed_strategy<RULE, ITERABLE>(
+    item: BoxedStrategy<RULE::Item>,
+    invalid: RangeInclusive<usize>,
+) -> BoxedStrategy<ITERABLE>
+where
+    RULE: RuleStrategy,
+    ITERABLE: FromIterator<RULE::Item> + Debug + 'static,
+{
+    (
+        collection::vec(RULE::strategy(), 0..=EXTRA_COUNT),
+        collection::vec(item, invalid),
+    )
+        .prop_map(|(valid, invalid)| valid.into_iter().chain(invalid).collect::<VecDeque<_>>())
+        .prop_shuffle()
+        .prop_map(ITERABLE::from_iter)
+        .boxed()
+}
+
+/// The boundary violations have a single item violating `RULE`, which is not skipped by `OPTION`
+impl<RULE, ITERABLE, OPTION> RuleViolation for SkipRule<RULE, ITERABLE, OPTION>
+where
+    RULE: RuleStrategy + RuleViolation,
+    ITERABLE: IterableItems<Item = RULE::Item> + FromIterator<RULE::Item> + Debug + 'static,
+    OPTION: SkipOption<Item = RULE::Item> + 'static,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(mixed_strategy::<RULE, ITERABLE>(
+            RULE::boundary_violation(),
+            1..=1,
+        ))
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(mixed_strategy::<RULE, ITERABLE>(RULE::violation(), 1..=3))
+    }
+}
+
+/// Generates vectors with a length within `short`, or whose item at `INDEX` is generated by `item`
+fn indexed_violation<const INDEX: usize, T: ArbitraryWithin>(
+    short: RangeInclusive<usize>,
+    item: BoxedStrategy<T>,
+) -> BoxedStrategy<Vec<T>> {
+    let longer = INDEX.saturating_add(1);
+    Union::new([
+        collection::vec(T::arbitrary_unbounded(), short).boxed(),
+        (
+            collection::vec(T::arbitrary_unbounded(), longer..=longer + EXTRA_COUNT),
+            item,
+        )
+            .prop_map(|(mut target, item)| {
+                target[INDEX] = item;
+                target
+            })
+            .boxed(),
+    ])
+    .boxed()
+}
+
+/// The boundary violations have exactly `INDEX` items, or a boundary violation of `RULE` at `INDEX`
+impl<const INDEX: usize, RULE, T> RuleViolation for IndexRuleVec<INDEX, RULE>
+where
+    RULE: RuleViolation<Item = T>,
+    T: ArbitraryWithin,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        indexed_violation::<INDEX, T>(INDEX..=INDEX, RULE::boundary_violation())
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        indexed_violation::<INDEX, T>(0..=INDEX, RULE::violation())
+    }
+}
+
+impl<const INDEX: usize, RULE, T> RuleViolation for IndexRuleVecDeque<INDEX, RULE>
+where
+    RULE: RuleViolation<Item = T>,
+    T: ArbitraryWithin,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        indexed_violation::<INDEX, T>(INDEX..=INDEX, RULE::boundary_violation())
+            .prop_map(VecDeque::from)
+            .boxed()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        indexed_violation::<INDEX, T>(0..=INDEX, RULE::violation())
+            .prop_map(VecDeque::from)
+            .boxed()
+    }
+}
+
+impl<const INDEX: usize, RULE> RuleViolation for IndexRuleString<INDEX, RULE>
+where
+    RULE: RuleViolation<Item = char>,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        indexed_violation::<INDEX, char>(INDEX..=INDEX, RULE::boundary_violation())
+            .prop_map(|target| target.into_iter().collect())
+            .boxed()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        indexed_violation::<INDEX, char>(0..=INDEX, RULE::violation())
+            .prop_map(|target| target.into_iter().collect())
+            .boxed()
+    }
+}
+
+impl<RULE, ITERABLE> RuleViolation for ReverseRule<RULE>
+where
+    RULE: RuleViolation<Item = ITERABLE>,
+    ITERABLE: Iterable + FromIterator<ITERABLE::Item> + Debug + 'static,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        RULE::boundary_violation()
+            .prop_map(|target| ITERABLE::from_iter(target.into_iterator().rev()))
+            .boxed()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        RULE::arbitrary_violation()
+            .prop_map(|target| ITERABLE::from_iter(target.into_iterator().rev()))
+            .boxed()
+    }
+}
+
+/// The boundary violations have one item more or less than `N` satisfying `RULE`
+impl<const N: usize, RULE, ITERABLE> RuleViolation for CountEqualRule<N, RULE, ITERABLE>
+where
+    RULE: RuleStrategy<Item: ArbitraryWithin>,
+    ITERABLE: IterableItems<Item = RULE::Item> + FromIterator<RULE::Item> + Debug + 'static,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        let more = N.saturating_add(1)..=N.saturating_add(1);
+        let fewer = N.saturating_sub(1)..=N.saturating_sub(1);
+        violating_strategy::<Self>(Union::new([
+            counted_strategy::<RULE, ITERABLE>(fewer, 0..=EXTRA_COUNT),
+            counted_strategy::<RULE, ITERABLE>(more, 0..=EXTRA_COUNT),
+        ]))
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        let more = N.saturating_add(1)..=N.saturating_add(1 + EXTRA_COUNT);
+        let fewer = 0..=N.saturating_sub(1);
+        violating_strategy::<Self>(Union::new([
+            counted_strategy::<RULE, ITERABLE>(fewer, 0..=EXTRA_COUNT),
+            counted_strategy::<RULE, ITERABLE>(more, 0..=EXTRA_COUNT),
+        ]))
+    }
+}
+
+impl<const N: usize, RULE, ITERABLE> RuleViolation for CountGreaterRule<N, RULE, ITERABLE>
+where
+    RULE: RuleStrategy<Item: ArbitraryWithin>,
+    ITERABLE: IterableItems<Item = RULE::Item> + FromIterator<RULE::Item> + Debug + 'static,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(counted_strategy::<RULE, ITERABLE>(N..=N, 0..=EXTRA_COUNT))
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(counted_strategy::<RULE, ITERABLE>(0..=N, 0..=EXTRA_COUNT))
+    }
+}
+
+impl<const N: usize, RULE, ITERABLE> RuleViolation for CountLessRule<N, RULE, ITERABLE>
+where
+    RULE: RuleStrategy<Item: ArbitraryWithin>,
+    ITERABLE: IterableItems<Item = RULE::Item> + FromIterator<RULE::Item> + Debug + 'static,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(counted_strategy::<RULE, ITERABLE>(N..=N, 0..=EXTRA_COUNT))
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        let valid = N..=N.saturating_add(EXTRA_COUNT);
+        violating_strategy::<Self>(counted_strategy::<RULE, ITERABLE>(valid, 0..=EXTRA_COUNT))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::define_rule;
+    use crate::rule::{
+        Bounds, CountEqualVecRule, CountGreaterEqualVecRule, CountLessStringRule, EqualRuleU8,
+        EvenRuleI32, ExistsVecRule, ForAllStringRule, ForAllVecDequeRule, ForAllVecRule,
+        HeadStringRule, HeadVecRule, IndexRuleVec, InitVecRule, LastVecDequeRule,
+        NonEmptyStringRule, NothingVecRule, RangeRuleU8, RuleStrategy, TailVecRule,
+    };
+    use crate::testing::{assert_rejects, RuleViolation};
+    use proptest::prelude::*;
+
+    define_rule![
+        Vowel,
+        char,
+        |c| "aeiou".contains(*c),
+        "{value} is not a vowel"
+    ];
+
+    impl RuleStrategy for VowelRule {
+        fn strategy_within(_bounds: &Bounds<char>) -> BoxedStrategy<char> {
+            proptest::sample::select(vec!['a', 'e', 'i', 'o', 'u']).boxed()
+        }
+    }
+
+    impl RuleViolation for VowelRule {
+        fn boundary_violation() -> BoxedStrategy<char> {
+            proptest::sample::select(vec!['b', 'y']).boxed()
+        }
+
+        fn arbitrary_violation() -> BoxedStrategy<char> {
+            proptest::char::range('b', 'z')
+                .prop_filter("a vowel", |c| !"aeiou".contains(*c))
+                .boxed()
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_for_all_boundary_violation(levels in ForAllVecRule::<RangeRuleU8<1, 10>>::boundary_violation()) {
+            let invalid = levels.iter().filter(|level| !(1..10).contains(*level)).collect::<Vec<_>>();
+            prop_assert!(invalid == [&0] || invalid == [&10]);
+        }
+
+        #[test]
+        fn test_index_boundary_violation(list in IndexRuleVec::<2, EqualRuleU8<7>>::boundary_violation()) {
+            prop_assert!(list.len() == 2 || list[2] == 6 || list[2] == 8);
+        }
+
+        #[test]
+        fn test_index_string_boundary_violation(initial in HeadStringRule::<VowelRule>::boundary_violation()) {
+            prop_assert!(initial.chars().next().is_none_or(|c| Vowel::new(c).is_err()));
+        }
+    }
+
+    #[test]
+    fn test_collection_violation() {
+        assert_rejects::<ForAllVecRule<NonEmptyStringRule>>();
+        assert_rejects::<ForAllVecDequeRule<RangeRuleU8<1, 10>>>();
+        assert_rejects::<ForAllStringRule<VowelRule>>();
+        assert_rejects::<HeadVecRule<NonEmptyStringRule>>();
+        assert_rejects::<HeadStringRule<VowelRule>>();
+        assert_rejects::<LastVecDequeRule<EvenRuleI32>>();
+        assert_rejects::<TailVecRule<EvenRuleI32>>();
+        assert_rejects::<InitVecRule<EvenRuleI32>>();
+        assert_rejects::<ExistsVecRule<EvenRuleI32>>();
+        assert_rejects::<NothingVecRule<EvenRuleI32>>();
+        assert_rejects::<CountEqualVecRule<2, EvenRuleI32>>();
+        assert_rejects::<CountGreaterEqualVecRule<3, EvenRuleI32>>();
+        assert_rejects::<CountLessStringRule<2, VowelRule>>();
+    }
+}
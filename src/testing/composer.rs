@@ -0,0 +1,119 @@
+use crate::rule::composer::{And, AndAll, Normalized, Not, Or};
+use crate::rule::{RuleStrategy, Transform};
+use crate::testing::{violating_strategy, RuleViolation};
+use proptest::strategy::{BoxedStrategy, Strategy, Union};
+use std::fmt::Debug;
+
+impl<T, RULE1, RULE2> RuleViolation for And<RULE1, RULE2>
+where
+    T: Debug + 'static,
+    RULE1: RuleViolation<Item = T>,
+    RULE2: RuleViolation<Item = T>,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        Union::new([RULE1::boundary_violation(), RULE2::boundary_violation()]).boxed()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        Union::new([RULE1::arbitrary_violation(), RULE2::arbitrary_violation()]).boxed()
+    }
+}
+
+impl<T, RULE1, RULE2> RuleViolation for AndAll<RULE1, RULE2>
+where
+    T: Debug + 'static,
+    RULE1: RuleViolation<Item = T>,
+    RULE2: RuleViolation<Item = T>,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        Union::new([RULE1::boundary_violation(), RULE2::boundary_violation()]).boxed()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        Union::new([RULE1::arbitrary_violation(), RULE2::arbitrary_violation()]).boxed()
+    }
+}
+
+/// Generates the violations of either rule that the other rule also rejects
+///
+/// The arbitrary violations include the boundary ones of both rules,
+/// since two arbitrary violations rarely overlap, e.g. for `Or![LessRuleI32<-100>, GreaterRuleI32<100>]`.
+impl<T, RULE1, RULE2> RuleViolation for Or<RULE1, RULE2>
+where
+    T: Debug + 'static,
+    RULE1: RuleViolation<Item = T>,
+    RULE2: RuleViolation<Item = T>,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(Union::new([
+            RULE1::boundary_violation(),
+            RULE2::boundary_violation(),
+        ]))
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(Union::new([RULE1::violation(), RULE2::violation()]))
+    }
+}
+
+/// The violations of `Not<RULE>` are the values satisfying `RULE`
+impl<RULE: RuleStrategy> RuleViolation for Not<RULE> {
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        RULE::strategy()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        RULE::strategy()
+    }
+}
+
+/// Generates the violations of `RULE` that are still rejected after `TRANSFORM`, normalized
+impl<T, TRANSFORM, RULE> RuleViolation for Normalized<TRANSFORM, RULE>
+where
+    T: Debug + 'static,
+    TRANSFORM: Transform<Item = T> + 'static,
+    RULE: RuleViolation<Item = T>,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(RULE::boundary_violation())
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(RULE::violation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::composer::{Nand, Normalized, Xor};
+    use crate::rule::{
+        EmailStringRule, EvenRuleI32, GreaterRuleI32, LengthLessStringRule, LessRuleI32,
+        NonEmptyStringRule, TrimTransform,
+    };
+    use crate::testing::{assert_rejects, RuleViolation};
+    use crate::{And, Or};
+    use proptest::prelude::*;
+
+    type ShortEmail = And![
+        LengthLessStringRule<40>,
+        NonEmptyStringRule,
+        EmailStringRule
+    ];
+    type Extreme = Or![LessRuleI32<-100>, GreaterRuleI32<100>];
+
+    proptest! {
+        #[test]
+        fn test_or_boundary_violation(value in Extreme::boundary_violation()) {
+            prop_assert!(value == -100 || value == 100);
+        }
+    }
+
+    #[test]
+    fn test_composer_violation() {
+        assert_rejects::<ShortEmail>();
+        assert_rejects::<Extreme>();
+        assert_rejects::<Xor<EvenRuleI32, GreaterRuleI32<0>>>();
+        assert_rejects::<Nand<EvenRuleI32, GreaterRuleI32<0>>>();
+        assert_rejects::<Normalized<TrimTransform, ShortEmail>>();
+    }
+}
@@ -0,0 +1,120 @@
+use crate::rule::{
+    ArbitraryWithin, Bounds, EmptyDefinition, EmptyRule, LengthDefinition, LengthEqualRule,
+    LengthGreaterRule, LengthLessRule,
+};
+use crate::testing::{violating_strategy, RuleViolation};
+use proptest::strategy::{BoxedStrategy, Strategy, Union};
+use std::ops::RangeInclusive;
+
+/// Generates the values of `ITEM` with a length within `length` that violate `RULE`
+fn sized_violation<RULE, ITEM>(length: RangeInclusive<usize>) -> BoxedStrategy<ITEM>
+where
+    RULE: RuleViolation<Item = ITEM>,
+    ITEM: ArbitraryWithin,
+{
+    violating_strategy::<RULE>(ITEM::arbitrary_within(&Bounds::length(length)))
+}
+
+impl<const THAN: usize, ITEM> RuleViolation for LengthGreaterRule<THAN, ITEM>
+where
+    ITEM: ArbitraryWithin + LengthDefinition,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        sized_violation::<Self, ITEM>(THAN..=THAN)
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        sized_violation::<Self, ITEM>(0..=THAN)
+    }
+}
+
+/// The boundary violations are one item shorter or longer than `LENGTH`
+impl<const LENGTH: usize, ITEM> RuleViolation for LengthEqualRule<LENGTH, ITEM>
+where
+    ITEM: ArbitraryWithin + LengthDefinition,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        let longer = LENGTH.saturating_add(1);
+        match LENGTH.checked_sub(1) {
+            Some(shorter) => Union::new([
+                sized_violation::<Self, ITEM>(shorter..=shorter),
+                sized_violation::<Self, ITEM>(longer..=longer),
+            ])
+            .boxed(),
+            None => sized_violation::<Self, ITEM>(longer..=longer),
+        }
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        let longer = sized_violation::<Self, ITEM>(LENGTH.saturating_add(1)..=usize::MAX);
+        match LENGTH.checked_sub(1) {
+            Some(shorter) => {
+                Union::new([sized_violation::<Self, ITEM>(0..=shorter), longer]).boxed()
+            }
+            None => longer,
+        }
+    }
+}
+
+impl<const THAN: usize, ITEM> RuleViolation for LengthLessRule<THAN, ITEM>
+where
+    ITEM: ArbitraryWithin + LengthDefinition,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        sized_violation::<Self, ITEM>(THAN..=THAN)
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        sized_violation::<Self, ITEM>(THAN..=usize::MAX)
+    }
+}
+
+/// The boundary violations of a string or a collection have a single item
+impl<T> RuleViolation for EmptyRule<T>
+where
+    T: ArbitraryWithin + EmptyDefinition,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        sized_violation::<Self, T>(1..=1)
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        violating_strategy::<Self>(T::arbitrary_unbounded())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{
+        EmptyRule, LengthEqualRule, LengthGreaterVecRule, LengthLessStringRule, LengthMinMaxRule,
+        NonEmptyStringRule, NonEmptyVecRule,
+    };
+    use crate::testing::{assert_rejects, RuleViolation};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_length_boundary_violation(
+            less in LengthLessStringRule::<3>::boundary_violation(),
+            equal in LengthEqualRule::<2, Vec<u8>>::boundary_violation(),
+            min_max in LengthMinMaxRule::<2, 4, String>::boundary_violation(),
+            empty in EmptyRule::<String>::boundary_violation(),
+        ) {
+            prop_assert_eq!(less.chars().count(), 3);
+            prop_assert!(equal.len() == 1 || equal.len() == 3);
+            prop_assert!([1, 5].contains(&min_max.chars().count()));
+            prop_assert_eq!(empty.chars().count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_length_violation() {
+        assert_rejects::<LengthLessStringRule<3>>();
+        assert_rejects::<LengthGreaterVecRule<2, i32>>();
+        assert_rejects::<LengthEqualRule<0, String>>();
+        assert_rejects::<LengthMinMaxRule<2, 4, Vec<u8>>>();
+        assert_rejects::<EmptyRule<Vec<u8>>>();
+        assert_rejects::<NonEmptyStringRule>();
+        assert_rejects::<NonEmptyVecRule<u8>>();
+    }
+}
@@ -0,0 +1,181 @@
+use crate::rule::ArbitraryWithin;
+use crate::testing::{violating_strategy, RuleViolation};
+use proptest::sample::select;
+use proptest::strategy::{BoxedStrategy, Just, Strategy};
+
+macro_rules! impl_integer_rule_violation {
+    ($($t: ty),+) => {$(
+        $crate::paste::item! {
+            impl<const THAN: $t> RuleViolation for $crate::rule::[<GreaterRule $t:camel>]<THAN> {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    Just(THAN).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    (<$t>::MIN..=THAN).boxed()
+                }
+            }
+
+            impl<const THAN: $t> RuleViolation for $crate::rule::[<LessRule $t:camel>]<THAN> {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    Just(THAN).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    (THAN..=<$t>::MAX).boxed()
+                }
+            }
+
+            impl<const EQUAL: $t> RuleViolation for $crate::rule::[<EqualRule $t:camel>]<EQUAL> {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    select([EQUAL.checked_sub(1), EQUAL.checked_add(1)].into_iter().flatten().collect::<Vec<_>>()).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    violating_strategy::<Self>(<$t>::arbitrary_unbounded())
+                }
+            }
+
+            impl RuleViolation for $crate::rule::[<EvenRule $t:upper>] {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    Just(1).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    violating_strategy::<Self>(<$t>::arbitrary_unbounded())
+                }
+            }
+
+            impl RuleViolation for $crate::rule::[<OddRule $t:upper>] {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    Just(0).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    violating_strategy::<Self>(<$t>::arbitrary_unbounded())
+                }
+            }
+        }
+    )+};
+}
+
+impl_integer_rule_violation!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// `NaN` violates every float rule, and the exclusive rules are violated by the bound itself
+macro_rules! impl_float_rule_violation {
+    ($($t: ty),+) => {$(
+        $crate::paste::item! {
+            impl<const NUM: i64, const DEN: u64> RuleViolation for $crate::rule::[<GreaterRule $t:camel>]<NUM, DEN> {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    select(vec![NUM as $t / DEN as $t, <$t>::NAN]).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    violating_strategy::<Self>(proptest::num::$t::ANY)
+                }
+            }
+
+            impl<const NUM: i64, const DEN: u64> RuleViolation for $crate::rule::[<GreaterEqualRule $t:camel>]<NUM, DEN> {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    select(vec![(NUM as $t / DEN as $t).next_down(), <$t>::NAN]).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    violating_strategy::<Self>(proptest::num::$t::ANY)
+                }
+            }
+
+            impl<const NUM: i64, const DEN: u64> RuleViolation for $crate::rule::[<LessRule $t:camel>]<NUM, DEN> {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    select(vec![NUM as $t / DEN as $t, <$t>::NAN]).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    violating_strategy::<Self>(proptest::num::$t::ANY)
+                }
+            }
+
+            impl<const NUM: i64, const DEN: u64> RuleViolation for $crate::rule::[<LessEqualRule $t:camel>]<NUM, DEN> {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    select(vec![(NUM as $t / DEN as $t).next_up(), <$t>::NAN]).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    violating_strategy::<Self>(proptest::num::$t::ANY)
+                }
+            }
+
+            impl RuleViolation for $crate::rule::[<FiniteRule $t:camel>] {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    select(vec![<$t>::INFINITY, <$t>::NEG_INFINITY, <$t>::NAN]).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    Self::boundary_violation()
+                }
+            }
+
+            impl RuleViolation for $crate::rule::[<NonNanRule $t:camel>] {
+                fn boundary_violation() -> BoxedStrategy<Self::Item> {
+                    Just(<$t>::NAN).boxed()
+                }
+
+                fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+                    Self::boundary_violation()
+                }
+            }
+        }
+    )+};
+}
+
+impl_float_rule_violation!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{
+        EqualRuleU8, EvenRuleI32, FiniteRuleF64, GreaterEqualRuleF64, GreaterRuleI8,
+        LessEqualRuleF32, MinMaxRuleI32, NonNanRuleF32, OddRuleU64, PositiveRuleF64, RangeRuleI32,
+        UnitIntervalRuleF64,
+    };
+    use crate::testing::{assert_rejects, RuleViolation};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_integer_boundary_violation(
+            greater in GreaterRuleI8::<10>::boundary_violation(),
+            min_max in MinMaxRuleI32::<-1, 1>::boundary_violation(),
+            range in RangeRuleI32::<-1, 1>::boundary_violation(),
+        ) {
+            prop_assert_eq!(greater, 10);
+            prop_assert!(min_max == -2 || min_max == 2);
+            prop_assert!(range == -2 || range == 1);
+        }
+
+        #[test]
+        fn test_float_boundary_violation(positive in PositiveRuleF64::boundary_violation()) {
+            prop_assert!(positive == 0.0 || positive.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_integer_violation() {
+        assert_rejects::<GreaterRuleI8<10>>();
+        assert_rejects::<EqualRuleU8<0>>();
+        assert_rejects::<EqualRuleU8<255>>();
+        assert_rejects::<EvenRuleI32>();
+        assert_rejects::<OddRuleU64>();
+        assert_rejects::<MinMaxRuleI32<-1, 1>>();
+        assert_rejects::<RangeRuleI32<0, 100>>();
+    }
+
+    #[test]
+    fn test_float_violation() {
+        assert_rejects::<PositiveRuleF64>();
+        assert_rejects::<UnitIntervalRuleF64>();
+        assert_rejects::<GreaterEqualRuleF64<1, 3>>();
+        assert_rejects::<LessEqualRuleF32<-5>>();
+        assert_rejects::<FiniteRuleF64>();
+        assert_rejects::<NonNanRuleF32>();
+    }
+}
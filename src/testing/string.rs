@@ -0,0 +1,183 @@
+use crate::rule::strategy::string::regex_string_strategy;
+use crate::rule::{
+    ArbitraryWithin, Ipv4AddrRule, Ipv6AddrRule, PrivateIpv4AddrRule, PublicIpv4AddrRule, Rule,
+    RuleStrategy,
+};
+use crate::testing::{violating_strategy, RuleViolation};
+use proptest::arbitrary::any;
+use proptest::prop_oneof;
+use proptest::sample::{select, Index};
+use proptest::strategy::{BoxedStrategy, Strategy, Union};
+use std::fmt::Debug;
+
+/// Generates strings that match `regex` but for one char inserted or removed, and that violate `RULE`
+///
+/// Every rule declared by `declare_regex_rule!` generates its boundary violations in this way.
+pub fn regex_boundary_violation<RULE>(regex: &str) -> BoxedStrategy<RULE::Item>
+where
+    RULE: Rule<Item: From<String> + Debug + 'static> + 'static,
+{
+    let Some(strategy) = regex_string_strategy(regex) else {
+        return string_violation::<RULE>();
+    };
+    let strategy = strategy.boxed();
+    violating_strategy::<RULE>(
+        prop_oneof![
+            (strategy.clone(), any::<Index>(), any::<char>()).prop_map(|(mut target, index, c)| {
+                let boundaries = char_boundaries(&target);
+                target.insert(boundaries[index.index(boundaries.len())], c);
+                target
+            }),
+            (strategy, any::<Index>()).prop_filter_map(
+                "the string is empty",
+                |(mut target, index)| {
+                    let mut boundaries = char_boundaries(&target);
+                    boundaries.pop();
+                    (!boundaries.is_empty()).then(|| {
+                        target.remove(boundaries[index.index(boundaries.len())]);
+                        target
+                    })
+                }
+            ),
+        ]
+        .prop_map(RULE::Item::from),
+    )
+}
+
+/// The byte indices of the chars of `target` followed by its length
+fn char_boundaries(target: &str) -> Vec<usize> {
+    target
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(target.len()))
+        .collect()
+}
+
+/// Generates arbitrary strings that violate `RULE`
+pub fn string_violation<RULE>() -> BoxedStrategy<RULE::Item>
+where
+    RULE: Rule<Item: From<String> + Debug + 'static> + 'static,
+{
+    violating_strategy::<RULE>(String::arbitrary_unbounded().prop_map(RULE::Item::from))
+}
+
+/// The boundary violations have an octet from 256 to 999
+impl<T> RuleViolation for Ipv4AddrRule<T>
+where
+    T: AsRef<str> + From<String> + Debug + 'static,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        (any::<[u8; 4]>(), 0..4usize, 256..1000u16)
+            .prop_map(|(octets, index, octet)| {
+                let mut octets = octets.map(|octet| octet.to_string());
+                octets[index] = octet.to_string();
+                T::from(octets.join("."))
+            })
+            .boxed()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        string_violation::<Self>()
+    }
+}
+
+/// The boundary violations are public addresses just outside the private ranges
+impl<T> RuleViolation for PrivateIpv4AddrRule<T>
+where
+    T: AsRef<str> + From<String> + Debug + 'static,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        select(vec![
+            "9.255.255.255",
+            "11.0.0.0",
+            "172.15.255.255",
+            "172.32.0.0",
+            "192.167.255.255",
+            "192.169.0.0",
+        ])
+        .prop_map(|ip| T::from(ip.to_string()))
+        .boxed()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        Union::new([
+            PublicIpv4AddrRule::<T>::strategy(),
+            string_violation::<Self>(),
+        ])
+        .boxed()
+    }
+}
+
+/// Only the private addresses violate `PublicIpv4AddrRule`, which accepts a string that is not an address
+impl<T> RuleViolation for PublicIpv4AddrRule<T>
+where
+    T: AsRef<str> + From<String> + Debug + 'static,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        select(vec![
+            "10.0.0.0",
+            "10.255.255.255",
+            "172.16.0.0",
+            "172.31.255.255",
+            "192.168.0.0",
+            "192.168.255.255",
+        ])
+        .prop_map(|ip| T::from(ip.to_string()))
+        .boxed()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        PrivateIpv4AddrRule::<T>::strategy()
+    }
+}
+
+/// The boundary violations have nine groups
+impl<T> RuleViolation for Ipv6AddrRule<T>
+where
+    T: AsRef<str> + From<String> + Debug + 'static,
+{
+    fn boundary_violation() -> BoxedStrategy<Self::Item> {
+        any::<[u16; 9]>()
+            .prop_map(|groups| {
+                let groups = groups.map(|group| format!("{group:x}"));
+                T::from(groups.join(":"))
+            })
+            .boxed()
+    }
+
+    fn arbitrary_violation() -> BoxedStrategy<Self::Item> {
+        string_violation::<Self>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::{
+        AlphaDigitRule, DigitRule, EmailStringRule, HiraganaRule, Ipv4AddrRule, Ipv6AddrRule,
+        KanjiRule, PrivateIpv4AddrRule, PublicIpv4AddrRule, UnicodeAlphabetRule,
+    };
+    use crate::testing::{assert_rejects, RuleViolation};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_ipv4_boundary_violation(ip in Ipv4AddrRule::<String>::boundary_violation()) {
+            prop_assert_eq!(ip.split('.').count(), 4);
+            prop_assert!(ip.split('.').any(|octet| octet.len() == 3 && octet > "255"));
+        }
+    }
+
+    #[test]
+    fn test_string_violation() {
+        assert_rejects::<EmailStringRule>();
+        assert_rejects::<DigitRule<String>>();
+        assert_rejects::<AlphaDigitRule<String>>();
+        assert_rejects::<Ipv4AddrRule<String>>();
+        assert_rejects::<Ipv6AddrRule<String>>();
+        assert_rejects::<PublicIpv4AddrRule<String>>();
+        assert_rejects::<PrivateIpv4AddrRule<String>>();
+        assert_rejects::<UnicodeAlphabetRule<String>>();
+        assert_rejects::<HiraganaRule<String>>();
+        assert_rejects::<KanjiRule<String>>();
+    }
+}
@@ -0,0 +1,165 @@
+use refined_type::result::{Error, Location};
+use refined_type::rule::{NonEmptyString, RangeU32, Rule};
+use refined_type::Refined;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Refined)]
+#[refined(rule = StartBeforeEnd, deserialize)]
+struct Period {
+    start: RangeU32<0, 100>,
+    end: RangeU32<0, 100>,
+}
+
+struct StartBeforeEnd;
+
+impl Rule for StartBeforeEnd {
+    type Item = Period;
+
+    fn validate(target: Self::Item) -> refined_type::Result<Self::Item> {
+        if target.start.value() <= target.end.value() {
+            Ok(target)
+        } else {
+            Err(Error::new(target, "start must not be after end"))
+        }
+    }
+}
+
+#[derive(Debug, Refined)]
+#[refined(rule = PasswordConfirmed, deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignUp {
+    name: NonEmptyString,
+    password: NonEmptyString,
+    confirm_password: NonEmptyString,
+    #[refined(skip)]
+    #[serde(default)]
+    newsletter: bool,
+}
+
+struct PasswordConfirmed;
+
+impl Rule for PasswordConfirmed {
+    type Item = SignUp;
+
+    fn validate(target: Self::Item) -> refined_type::Result<Self::Item> {
+        if target.password == target.confirm_password {
+            Ok(target)
+        } else {
+            Err(Error::new(target, "the passwords do not match"))
+        }
+    }
+}
+
+#[derive(Debug, Refined)]
+struct Pair(NonEmptyString, RangeU32<0, 10>);
+
+#[test]
+fn test_validate_ok() -> anyhow::Result<()> {
+    let period = Period::validate(1, 10)?;
+    assert_eq!(period.start.into_value(), 1);
+    assert_eq!(period.end.into_value(), 10);
+    Ok(())
+}
+
+#[test]
+fn test_validate_reports_every_field() {
+    let error = Period::validate(100, 200).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "start: the value must be less than 100, but received 100, end: the value must be less than 100, but received 200"
+    );
+
+    let tree = error.tree();
+    assert_eq!(tree.rule(), Some("Period"));
+    assert_eq!(
+        tree.children()
+            .iter()
+            .map(|child| child.location())
+            .collect::<Vec<_>>(),
+        vec![
+            Some(&Location::Key("start".to_string())),
+            Some(&Location::Key("end".to_string()))
+        ]
+    );
+    assert_eq!(error.into_value(), (100, 200));
+}
+
+#[test]
+fn test_validate_struct_rule() {
+    let error = Period::validate(10, 1).unwrap_err();
+    assert_eq!(error.to_string(), "start must not be after end");
+    assert_eq!(error.tree().children().len(), 1);
+    assert_eq!(error.into_value(), (10, 1));
+}
+
+#[test]
+fn test_validate_skipped_field() -> anyhow::Result<()> {
+    let sign_up = SignUp::validate(
+        "john".to_string(),
+        "secret".to_string(),
+        "secret".to_string(),
+        true,
+    )?;
+    assert!(sign_up.newsletter);
+
+    let error = SignUp::validate(
+        "john".to_string(),
+        "secret".to_string(),
+        "Secret".to_string(),
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(error.to_string(), "the passwords do not match");
+    Ok(())
+}
+
+#[test]
+fn test_validate_tuple_struct() {
+    assert!(Pair::validate("a".to_string(), 1).is_ok());
+
+    let error = Pair::validate("".to_string(), 1).unwrap_err();
+    assert_eq!(
+        error.tree().children()[0].location(),
+        Some(&Location::Index(0))
+    );
+    assert_eq!(error.into_value(), ("".to_string(), 1));
+}
+
+#[test]
+fn test_deserialize_ok() -> anyhow::Result<()> {
+    let json = json! {{ "start": 1, "end": 10 }}.to_string();
+    let actual = serde_json::from_str::<Period>(&json)?;
+    assert_eq!(actual, Period::validate(1, 10)?);
+    Ok(())
+}
+
+#[test]
+fn test_deserialize_enforces_struct_rules() {
+    let json = json! {{ "start": 10, "end": 1 }}.to_string();
+    let error = serde_json::from_str::<Period>(&json).unwrap_err();
+    assert_eq!(error.to_string(), "start must not be after end");
+
+    let json = json! {{ "start": 1, "end": 100 }}.to_string();
+    assert!(serde_json::from_str::<Period>(&json).is_err());
+}
+
+#[test]
+fn test_deserialize_with_serde_attributes() -> anyhow::Result<()> {
+    let json = json! {{
+        "name": "john",
+        "password": "secret",
+        "confirmPassword": "secret"
+    }}
+    .to_string();
+    let sign_up = serde_json::from_str::<SignUp>(&json)?;
+    assert!(!sign_up.newsletter);
+
+    let json = json! {{
+        "name": "john",
+        "password": "secret",
+        "confirmPassword": "Secret"
+    }}
+    .to_string();
+    assert!(serde_json::from_str::<SignUp>(&json).is_err());
+    Ok(())
+}
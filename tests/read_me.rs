@@ -1,17 +1,24 @@
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
 
 use refined_type::result::Error;
-use refined_type::rule::composer::{If, IfElse, Not};
+use refined_type::rule::composer::{If, IfElse, Normalized, Not};
 use refined_type::rule::{
-    CountEqualVec, CountGreaterEqualVec, CountGreaterVec, CountLessEqualVec, CountLessVec, EqualU8,
-    EvenRuleI8, ExistsVec, ForAllVec, GreaterEqualRuleI8, GreaterEqualU8, GreaterU8, HeadVec,
-    IndexRuleVec, IndexVec, InitVec, LastVec, LengthDefinition, LengthEqual, LengthEqualRule,
-    LengthGreater, LengthLess, LengthMinMax, LessEqualU8, LessU8, MinMaxU8, NonEmptyString,
-    NonEmptyStringRule, NonEmptyVec, NonEmptyVecDeque, OddRuleI8, RangeU8, Reverse, Rule,
-    SkipFirst, SkipVec, TailVec,
+    AdjacentVec, AlphabetRule, ByteLengthLess, CharLengthMinMaxRule, ContainsKey, CountEqualVec,
+    CountGreaterEqualVec, CountGreaterVec, CountLessEqualVec, CountLessEqualVecRule, CountLessVec,
+    DistinctVec, EmailStringRule, EqualRuleU16, EqualU8, EvenRuleI8, EvenU16, ExistsVec,
+    ExistsVecRule, FiniteF64, ForAllEntriesHashMap, ForAllVec, GreaterEqualRuleI8, GreaterEqualU8,
+    GreaterU8, HeadVec, IndexRuleVec, IndexVec, InitVec, Katakana, LastVec, LengthDefinition,
+    LengthEqual, LengthEqualRule, LengthGreater, LengthLess, LengthLessStringRule, LengthMinMax,
+    LessEqualU8, LessU8, LowercaseEmailDomainTransform, MinMaxU8, NonEmptyString,
+    NonEmptyStringRule, NonEmptyVec, NonEmptyVecDeque, OddRuleI8, OddU16, PositiveF64, RangeF64,
+    RangeRuleU16, RangeU16, RangeU8, Reverse, Rule, SkipFirst, SkipVec, SortedVec,
+    StrictlyIncreasingVec, TailVec, TrimTransform, UnicodeAlphabetRule, UnitIntervalF64,
+    WindowsVec,
 };
-use refined_type::{And, Or, Refined};
+use refined_type::stream::RefineIterator;
+use refined_type::{define_rule, refined, And, AndAll, Or, Refined, RefinedRef, RefinedStr};
 
 // define a struct for converting from JSON.
 #[derive(Debug, Deserialize)]
@@ -136,6 +143,50 @@ fn example_5() {
     assert!(rule_err.is_err());
 }
 
+#[test]
+fn and_all_example() {
+    type HelloCommaWorldRule = AndAll![ContainsHelloRule, ContainsCommaRule, ContainsWorldRule];
+
+    let rule_ok = Refined::<HelloCommaWorldRule>::new("Hello, World!".to_string());
+    assert!(rule_ok.is_ok());
+
+    let rule_err = Refined::<HelloCommaWorldRule>::new("hello world!".to_string())
+        .err()
+        .unwrap();
+    assert_eq!(
+        rule_err.to_string(),
+        "[hello world! does not contain `Hello` && hello world! does not contain `,` && hello world! does not contain `World`]"
+    );
+    assert_eq!(rule_err.into_value(), "hello world!");
+}
+
+#[test]
+fn normalized_example() -> anyhow::Result<()> {
+    type Name = Refined<Normalized<TrimTransform, AlphabetRule<String>>>;
+    type Email =
+        Refined<Normalized<(TrimTransform, LowercaseEmailDomainTransform), EmailStringRule>>;
+
+    #[derive(Debug, Deserialize)]
+    struct Account {
+        name: Name,
+        email: Email,
+    }
+
+    let json = json! {{
+        "name": "  Bob ",
+        "email": "Bob@Example.COM "
+    }}
+    .to_string();
+
+    let account = serde_json::from_str::<Account>(&json)?;
+    assert_eq!(account.name.into_value(), "Bob");
+    assert_eq!(account.email.into_value(), "Bob@example.com");
+
+    let name = Name::new(" Bob 2 ".to_string());
+    assert_eq!(name.unwrap_err().into_value(), "Bob 2");
+    Ok(())
+}
+
 #[test]
 fn example_6() {
     type HelloOrWorldRule = Or![ContainsHelloRule, ContainsWorldRule];
@@ -670,6 +721,69 @@ fn example_19() -> Result<(), Error<Vec<i32>>> {
     Ok(())
 }
 
+#[test]
+fn ordering_example() -> anyhow::Result<()> {
+    let (tags, pushed) = DistinctVec::new(vec!["rust", "web"])?.push("cli");
+    assert!(pushed);
+    let (tags, pushed) = tags.push("rust");
+    assert!(!pushed);
+    assert_eq!(tags.into_value(), vec!["rust", "web", "cli"]);
+    assert!(DistinctVec::new(vec!["rust", "rust"]).is_err());
+
+    let timestamps = SortedVec::new(vec![10, 20, 40])?.insert(30);
+    assert_eq!(timestamps.into_value(), vec![10, 20, 30, 40]);
+
+    assert!(StrictlyIncreasingVec::new(vec![1, 2, 3]).is_ok());
+    assert!(StrictlyIncreasingVec::new(vec![1, 2, 2]).is_err());
+    Ok(())
+}
+
+#[test]
+fn windows_example() {
+    define_rule![pub BoundedDelta, [f64; 2], |[prev, next]| (next - prev).abs() <= 5.0, "the delta of {value} exceeds 5"];
+    define_rule![pub NotEqual, [i32; 2], |[prev, next]| prev != next, "{value} are equal"];
+
+    let readings = vec![20.0, 23.5, 19.0, 22.0];
+    assert!(AdjacentVec::<BoundedDeltaRule>::new(readings).is_ok());
+
+    let readings = vec![20.0, 23.5, 30.0, 22.0];
+    let err = AdjacentVec::<BoundedDeltaRule>::new(readings).unwrap_err();
+    assert_eq!(err.tree().children().len(), 2);
+
+    assert!(WindowsVec::<2, NotEqualRule>::new(vec![1, 2, 1]).is_ok());
+    assert!(WindowsVec::<2, NotEqualRule>::new(vec![1, 1, 2]).is_err());
+}
+
+#[test]
+fn map_example() {
+    type Ports = ForAllEntriesHashMap<NonEmptyStringRule, RangeRuleU16<1, 1024>>;
+
+    let ports = HashMap::from([("http".to_string(), 80), ("ssh".to_string(), 22)]);
+    assert!(Ports::new(ports).is_ok());
+
+    let ports = HashMap::from([("".to_string(), 80), ("proxy".to_string(), 8080)]);
+    let err = Ports::new(ports).unwrap_err();
+    assert_eq!(err.tree().children().len(), 2);
+
+    let ports = BTreeMap::from([(80, "http"), (443, "https")]);
+    assert!(ContainsKey::<EqualRuleU16<443>, _>::new(ports).is_ok());
+}
+
+#[test]
+fn streaming_example() {
+    let lines = vec!["alice".to_string(), "".to_string(), "bob".to_string()];
+    let mut names = lines.into_iter().refine::<NonEmptyStringRule>();
+
+    let valid = names.by_ref().filter_map(Result::ok).collect::<Vec<_>>();
+    assert_eq!(valid.len(), 2);
+    assert_eq!(names.tally().invalid(), 1);
+
+    assert!(names.check::<ExistsVecRule<NonEmptyStringRule>>().is_ok());
+    assert!(names
+        .check::<CountLessEqualVecRule<1, NonEmptyStringRule>>()
+        .is_err());
+}
+
 #[test]
 fn example_22() -> Result<(), Error<Vec<i32>>> {
     let ne_vec = NonEmptyVec::new(vec![1, 2, 3])?;
@@ -733,6 +847,23 @@ fn length_equal_example() -> Result<(), Error<String>> {
     Ok(())
 }
 
+#[test]
+fn unicode_length_example() -> anyhow::Result<()> {
+    type UserName = Refined<And![CharLengthMinMaxRule<1, 10>, UnicodeAlphabetRule<String>]>;
+    type Column = ByteLengthLess<16>;
+    type Reading = Katakana<String>;
+
+    let name = UserName::new("山田太郎".to_string())?;
+    assert_eq!(name.into_value(), "山田太郎");
+
+    assert!(Column::new("山田太郎".to_string()).is_ok());
+    assert!(Column::new("山田太郎です".to_string()).is_err());
+
+    assert!(Reading::new("ヤマダタロウ".to_string()).is_ok());
+    assert!(Reading::new("やまだたろう".to_string()).is_err());
+    Ok(())
+}
+
 #[derive(Debug, PartialEq)]
 struct Hello;
 impl LengthDefinition for Hello {
@@ -785,3 +916,281 @@ fn if_else_example() -> Result<(), Error<i8>> {
 
     Ok(())
 }
+
+#[test]
+fn error_tree_example() -> anyhow::Result<()> {
+    type Age = RangeU8<18, 80>;
+
+    let error = Age::new(80).unwrap_err();
+
+    let actual = json!(error.tree());
+    let expected = json! {{
+        "rule": "And",
+        "actual": "80",
+        "message": "the value must be less than 80, but received 80",
+        "children": [{
+            "rule": "LessRuleU8",
+            "expected": "less than 80",
+            "actual": "80",
+            "message": "the value must be less than 80, but received 80"
+        }]
+    }};
+    assert_eq!(actual, expected);
+
+    // the rejected value can still be taken back
+    assert_eq!(error.into_value(), 80);
+    Ok(())
+}
+
+#[derive(Debug, Refined)]
+#[refined(rule = StartBeforeEndRule, deserialize)]
+struct Period {
+    start: RangeU8<0, 24>,
+    end: RangeU8<0, 24>,
+}
+
+struct StartBeforeEndRule;
+
+impl Rule for StartBeforeEndRule {
+    type Item = Period;
+
+    fn validate(target: Self::Item) -> refined_type::Result<Self::Item> {
+        if target.start.value() <= target.end.value() {
+            Ok(target)
+        } else {
+            Err(Error::new(target, "start must not be after end"))
+        }
+    }
+}
+
+#[test]
+fn derive_example() -> anyhow::Result<()> {
+    let period = Period::validate(9, 17)?;
+    assert_eq!(period.start.into_value(), 9);
+
+    // every invalid field is reported with its name
+    let error = Period::validate(24, 25).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "start: the value must be less than 24, but received 24, end: the value must be less than 24, but received 25"
+    );
+
+    let error = Period::validate(17, 9).unwrap_err();
+    assert_eq!(error.to_string(), "start must not be after end");
+
+    let json = json! {{ "start": 17, "end": 9 }}.to_string();
+    assert!(serde_json::from_str::<Period>(&json).is_err());
+    Ok(())
+}
+
+type Username = Refined<And![NonEmptyStringRule, LengthLessStringRule<9>]>;
+
+#[test]
+fn json_schema_example() {
+    assert_eq!(
+        Username::json_schema(),
+        json!({
+            "type": "string",
+            "minLength": 1,
+            "maxLength": 8,
+            "description": "not empty and length less than 9"
+        })
+    );
+
+    assert_eq!(
+        RangeU8::<1, 10>::json_schema(),
+        json!({
+            "type": "integer",
+            "minimum": 1,
+            "exclusiveMaximum": 10,
+            "description": "(equal to 1 or greater than 1) and less than 10"
+        })
+    );
+}
+
+#[cfg(feature = "schemars")]
+#[derive(schemars::JsonSchema)]
+#[allow(dead_code)]
+struct Member {
+    name: NonEmptyString,
+    age: MinMaxU8<18, 80>,
+    friends: NonEmptyVec<String>,
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn schemars_example() {
+    let schema = schemars::schema_for!(Member);
+    let properties = &schema.as_value()["properties"];
+    assert_eq!(properties["name"]["minLength"], json!(1));
+    assert_eq!(properties["age"]["minimum"], json!(18));
+    assert_eq!(properties["age"]["maximum"], json!(80));
+    assert_eq!(properties["friends"]["minItems"], json!(1));
+}
+
+define_rule![pub Port, u16, |port| *port != 0, "the port must not be zero, but received {value}"];
+
+define_rule![
+    pub Slug,
+    String,
+    |slug| match slug.chars().find(|c| !c.is_ascii_lowercase() && *c != '-') {
+        Some(c) => Err(format!("{c:?} is not allowed")),
+        None => Ok(()),
+    },
+    "{value} is not a slug: {reason}"
+];
+
+#[test]
+fn define_rule_example() -> anyhow::Result<()> {
+    let port = Port::new(8080)?;
+    assert_eq!(port.into_value(), 8080);
+    assert_eq!(
+        Port::new(0).unwrap_err().to_string(),
+        "the port must not be zero, but received 0"
+    );
+
+    let slug = Slug::new("hello-world".to_string())?;
+    assert_eq!(slug.into_value(), "hello-world");
+    assert_eq!(
+        Slug::new("Hello".to_string()).unwrap_err().to_string(),
+        "\"Hello\" is not a slug: 'H' is not allowed"
+    );
+    Ok(())
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_example {
+    use super::PortRule;
+    use proptest::prelude::*;
+    use refined_type::rule::{
+        filtered_strategy, Bounds, ForAllVec, ForAllVecRule, LengthEqualRule, LengthLessStringRule,
+        MinMaxRuleI32, NonEmptyStringRule, RangeRuleU8, RangeU8, RuleStrategy,
+    };
+    use refined_type::testing::{assert_rejects, RuleViolation};
+    use refined_type::{And, Refined};
+
+    impl RuleStrategy for PortRule {
+        fn strategy_within(_bounds: &Bounds<u16>) -> BoxedStrategy<u16> {
+            filtered_strategy::<Self>(any::<u16>())
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn proptest_example(
+            age in any::<RangeU8<18, 80>>(),
+            tags in any::<ForAllVec<NonEmptyStringRule>>(),
+            ports in any::<Refined<And![LengthEqualRule<3, Vec<u16>>, ForAllVecRule<PortRule>]>>(),
+        ) {
+            prop_assert!((18..80).contains(age.value()));
+            prop_assert!(tags.iter().all(|tag| !tag.is_empty()));
+            prop_assert_eq!(ports.len(), 3);
+            prop_assert!(ports.iter().all(|port| *port != 0));
+        }
+
+        #[test]
+        fn violation_example(
+            age in RangeRuleU8::<18, 80>::violation(),
+            name in LengthLessStringRule::<8>::boundary_violation(),
+        ) {
+            prop_assert!(RangeU8::<18, 80>::new(age).is_err());
+            prop_assert_eq!(name.chars().count(), 8);
+        }
+    }
+
+    #[test]
+    fn assert_rejects_example() {
+        assert_rejects::<MinMaxRuleI32<-10, 10>>();
+        assert_rejects::<ForAllVecRule<NonEmptyStringRule>>();
+    }
+}
+
+// [0.0, 0.5)
+type Ratio = RangeF64<0, 1, 2>;
+
+#[test]
+fn float_example() -> anyhow::Result<()> {
+    let ratio = Ratio::new(0.25)?;
+    assert_eq!(ratio.into_value(), 0.25);
+
+    assert!(Ratio::new(0.5).is_err());
+    assert!(Ratio::new(f64::NAN).is_err());
+
+    let probability = UnitIntervalF64::new(1.0)?;
+    assert_eq!(probability.into_value(), 1.0);
+
+    assert!(FiniteF64::new(f64::INFINITY).is_err());
+    assert!(PositiveF64::new(0.0).is_err());
+    Ok(())
+}
+
+#[test]
+fn literal_example() {
+    let port = refined!(RangeU16<1, 65535>, 8080);
+    assert_eq!(port.into_value(), 8080);
+
+    let key = refined!(NonEmptyString, "timeout");
+    assert_eq!(key.into_value(), "timeout");
+
+    // does not compile: `0` does not satisfy the rule of `RangeU16<1, 65535>`
+    // let port = refined!(RangeU16<1, 65535>, 0);
+}
+
+#[test]
+fn arithmetic_example() -> anyhow::Result<()> {
+    let quota = RangeU8::<0, 100>::new(90)?;
+    assert!(quota.checked_add(10).is_err());
+
+    let count = GreaterU8::<0>::new(250)?.saturating_add(10)?;
+    assert_eq!(count.into_value(), 255);
+
+    let even: EvenU16 = OddU16::new(3)? + OddU16::new(5)?;
+    assert_eq!(even.into_value(), 8);
+    Ok(())
+}
+
+#[test]
+fn refined_ref_example() {
+    let names = ["john".to_string(), "".to_string()];
+
+    // the error borrows the value, so it is not `'static`
+    let name = RefinedRef::<NonEmptyStringRule>::new(&names[0]).unwrap();
+    assert_eq!(name.value(), "john");
+
+    let error = RefinedRef::<NonEmptyStringRule>::new(&names[1]).unwrap_err();
+    assert_eq!(error.into_value(), "");
+
+    // an owned `Refined` is made only when it is needed
+    let name: NonEmptyString = name.to_refined();
+    assert_eq!(name.into_value(), "john");
+}
+
+#[test]
+fn refined_str_example() -> anyhow::Result<()> {
+    fn greet(name: &RefinedStr<NonEmptyStringRule>) -> String {
+        format!("Hello, {}!", name.to_uppercase())
+    }
+
+    let line = "john,taro";
+    for field in line.split(',') {
+        let name = RefinedStr::<NonEmptyStringRule>::new(field).unwrap();
+        assert!(greet(name).starts_with("Hello"));
+    }
+    assert!(RefinedStr::<NonEmptyStringRule>::new("").is_err());
+
+    let owned = NonEmptyString::new("hanako".to_string())?;
+    assert_eq!(greet(owned.as_refined_str()), "Hello, HANAKO!");
+    Ok(())
+}
+
+#[test]
+fn standard_traits_example() -> anyhow::Result<()> {
+    let mut ages = HashMap::new();
+    ages.insert(NonEmptyString::new("john".to_string())?, 20);
+
+    assert_eq!(ages.get("john"), Some(&20));
+
+    let name = NonEmptyString::new("taro".to_string())?;
+    assert!(name.clone().starts_with("ta"));
+    Ok(())
+}
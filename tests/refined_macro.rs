@@ -0,0 +1,54 @@
+use refined_type::refined;
+use refined_type::rule::composer::Not;
+use refined_type::rule::{
+    EqualI32, EvenU16, LengthEqualString, LengthLessStr, NonEmptyStr, NonEmptyString, OddRuleI8,
+    RangeI64, RangeU128, RangeU8,
+};
+use refined_type::Refined;
+
+const DEFAULT_PORT: u16 = 8080;
+
+#[test]
+fn test_refined_int_literal() {
+    let level: RangeU8<1, 10> = refined!(RangeU8<1, 10>, 5);
+    assert_eq!(level.into_value(), 5);
+
+    let port = refined!(EvenU16, 8080);
+    assert_eq!(port.into_value(), DEFAULT_PORT);
+
+    let answer = refined!(EqualI32<42>, 42);
+    assert_eq!(answer.into_value(), 42);
+}
+
+#[test]
+fn test_refined_negative_literal() {
+    let celsius = refined!(RangeI64<-273, 1000>, -273);
+    assert_eq!(celsius.into_value(), -273);
+
+    let even = refined!(Refined<Not<OddRuleI8>>, -4);
+    assert_eq!(even.into_value(), -4);
+}
+
+#[test]
+fn test_refined_large_literal() {
+    let large = refined!(
+        RangeU128<170141183460469231731687303715884105728, 340282366920938463463374607431768211455>,
+        300000000000000000000000000000000000000
+    );
+    assert_eq!(large.into_value(), 300000000000000000000000000000000000000);
+}
+
+#[test]
+fn test_refined_str_literal() {
+    let name = refined!(NonEmptyString, "hello");
+    assert_eq!(name.into_value(), "hello");
+
+    let name = refined!(NonEmptyStr, "hello");
+    assert_eq!(name.into_value(), "hello");
+
+    let code = refined!(LengthEqualString<3>, "日本語");
+    assert_eq!(code.into_value(), "日本語");
+
+    let key = refined!(LengthLessStr<8>, "key");
+    assert_eq!(key.into_value(), "key");
+}